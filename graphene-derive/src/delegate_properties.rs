@@ -0,0 +1,181 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+	spanned::Spanned, Error, FnArg, Ident, ItemTrait, Pat, PatIdent, Result, TraitItem, Visibility,
+};
+
+/// Creates the implementation of the given trait item that delegates to the
+/// ensured graph, or `None` if the trait's default should be used.
+///
+/// `target` is the trait as implemented by the ensured graph, and `mutable` is
+/// set if the item needs mutable access to it.
+fn delegate_item(
+	item: &TraitItem,
+	target: &TokenStream,
+	mutable: &mut bool,
+) -> Result<Option<TokenStream>>
+{
+	match item
+	{
+		TraitItem::Fn(f) if f.default.is_none() =>
+		{
+			let mut sig = f.sig.clone();
+			let graph = match sig.receiver()
+			{
+				Some(r) if r.reference.is_some() && r.colon_token.is_none() =>
+				{
+					if r.mutability.is_some()
+					{
+						*mutable = true;
+						quote!(<$delegate as ::graphene::core::GraphDerefMut>::graph_mut(
+							&mut self$($delegate_to)+
+						))
+					}
+					else
+					{
+						quote!(<$delegate as ::graphene::core::GraphDeref>::graph(&self$($delegate_to)+))
+					}
+				},
+				_ =>
+				{
+					return Err(Error::new_spanned(
+						&f.sig,
+						"only methods taking `&self` or `&mut self` can be delegated",
+					))
+				},
+			};
+
+			// Name any argument that isn't just an identifier, so it can be
+			// passed on
+			let mut args = Vec::new();
+			for (idx, arg) in sig.inputs.iter_mut().enumerate()
+			{
+				if let FnArg::Typed(arg) = arg
+				{
+					let ident = match &*arg.pat
+					{
+						Pat::Ident(PatIdent {
+							ident,
+							subpat: None,
+							..
+						}) => ident.clone(),
+						_ => format_ident!("arg_{}", idx),
+					};
+					*arg.pat = Pat::Ident(PatIdent {
+						attrs: Vec::new(),
+						by_ref: None,
+						mutability: None,
+						ident: ident.clone(),
+						subpat: None,
+					});
+					args.push(ident);
+				}
+			}
+
+			let ident = &sig.ident;
+			Ok(Some(quote! {
+				#sig
+				{
+					<#target>::#ident(#graph #(, #args)*)
+				}
+			}))
+		},
+		TraitItem::Fn(_) => Ok(None),
+		TraitItem::Type(ty) if ty.generics.params.is_empty() =>
+		{
+			let ident = &ty.ident;
+			Ok(Some(quote!(type #ident = <#target>::#ident;)))
+		},
+		TraitItem::Const(c) if c.default.is_none() =>
+		{
+			let (ident, ty) = (&c.ident, &c.ty);
+			Ok(Some(quote!(const #ident: #ty = <#target>::#ident;)))
+		},
+		TraitItem::Const(_) => Ok(None),
+		_ =>
+		{
+			Err(Error::new(
+				item.span(),
+				"only methods, associated types without generics, and associated constants can be \
+				 delegated",
+			))
+		},
+	}
+}
+
+pub(crate) fn expand(attr: TokenStream, item: ItemTrait) -> Result<TokenStream>
+{
+	if !attr.is_empty()
+	{
+		return Err(Error::new_spanned(
+			attr,
+			"`delegate_properties` doesn't take any arguments",
+		));
+	}
+
+	let trait_ident = &item.ident;
+	let (_, trait_generics, _) = item.generics.split_for_impl();
+	let trait_params = item.generics.params.iter();
+	let target = quote! {
+		<$delegate as ::graphene::core::GraphDeref>::Graph as $($trait)* #trait_generics
+	};
+
+	let mut mutable = false;
+	let mut items = Vec::new();
+	for trait_item in &item.items
+	{
+		items.extend(delegate_item(trait_item, &target, &mut mutable)?);
+	}
+	let deref = if mutable
+	{
+		quote!(::graphene::core::GraphDerefMut)
+	}
+	else
+	{
+		quote!(::graphene::core::GraphDeref)
+	};
+
+	// The macro is named after the trait, so ensurers can find it using the
+	// trait's path
+	let macro_ident = Ident::new(
+		&format!("__graphene_delegate_{}", trait_ident),
+		trait_ident.span(),
+	);
+	let vis = &item.vis;
+	let export = matches!(vis, Visibility::Public(_)).then(|| quote!(#[macro_export]));
+
+	// Graphene's ensurers wouldn't check changes made through the trait
+	let builtin = (!mutable)
+		.then(|| quote!(::graphene::delegate_to_builtin_ensurers! { #trait_ident #trait_ident }));
+
+	Ok(quote! {
+		#item
+
+		#[doc(hidden)]
+		#export
+		macro_rules! #macro_ident {
+			{
+				@trait [ $($trait:tt)* ]
+				@struct [ $struct:ty ]
+				@generics [ $($generics:tt)* ]
+				@delegate [ $delegate:ty ]
+				@delegate_to [ $($delegate_to:tt)+ ]
+				@bounds [ $($bounds:tt)* ]
+			} => {
+				impl<$($generics)* #(#trait_params,)*> $($trait)* #trait_generics for $struct
+				where
+					$delegate: #deref,
+					<$delegate as ::graphene::core::GraphDeref>::Graph: $($trait)* #trait_generics,
+					$($bounds)*
+				{
+					#(#items)*
+				}
+			};
+		}
+
+		#[doc(hidden)]
+		#vis use #macro_ident as #trait_ident;
+
+		#builtin
+	})
+}
@@ -0,0 +1,237 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	Attribute, Data, DeriveInput, Error, Fields, GenericParam, Ident, Index, Member, Path, Result,
+	Type, WherePredicate,
+};
+
+/// The options given to the struct in `#[ensurer(..)]` attributes.
+#[derive(Default)]
+struct Options
+{
+	exclude: Vec<Ident>,
+	delegate: Vec<Path>,
+}
+
+impl Options
+{
+	fn parse(attrs: &[Attribute]) -> Result<Self>
+	{
+		let mut options = Self::default();
+		for attr in attrs.iter().filter(|a| a.path().is_ident("ensurer"))
+		{
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("exclude")
+				{
+					meta.parse_nested_meta(|m| {
+						options.exclude.push(m.path.require_ident()?.clone());
+						Ok(())
+					})
+				}
+				else if meta.path.is_ident("delegate")
+				{
+					meta.parse_nested_meta(|m| {
+						options.delegate.push(m.path);
+						Ok(())
+					})
+				}
+				else
+				{
+					Err(meta.error("expected `exclude(..)` or `delegate(..)`"))
+				}
+			})?;
+		}
+		Ok(options)
+	}
+}
+
+/// A field of the struct the ensurer delegates to or gets its payload from.
+struct Field
+{
+	member: Member,
+	ty: Type,
+}
+
+/// Finds the ensured graph and the payload among the fields, returning them
+/// and the number of fields.
+fn find_fields(input: &DeriveInput) -> Result<(Field, Option<Field>, usize)>
+{
+	let Data::Struct(data) = &input.data
+	else
+	{
+		return Err(Error::new_spanned(
+			&input.ident,
+			"`Ensurer` can only be derived for structs",
+		));
+	};
+
+	let mut ensured = None;
+	let mut payload = None;
+	for (idx, field) in data.fields.iter().enumerate()
+	{
+		let member = match &field.ident
+		{
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(Index::from(idx)),
+		};
+		for attr in field.attrs.iter().filter(|a| a.path().is_ident("ensurer"))
+		{
+			attr.parse_nested_meta(|meta| {
+				let slot = if meta.path.is_ident("ensured")
+				{
+					&mut ensured
+				}
+				else if meta.path.is_ident("payload")
+				{
+					&mut payload
+				}
+				else
+				{
+					return Err(meta.error("expected `ensured` or `payload`"));
+				};
+				if slot.is_some()
+				{
+					return Err(meta.error("only one field can be marked with this"));
+				}
+				*slot = Some(Field {
+					member: member.clone(),
+					ty: field.ty.clone(),
+				});
+				Ok(())
+			})?;
+		}
+	}
+
+	let ensured = match ensured
+	{
+		Some(ensured) => ensured,
+		None =>
+		{
+			let field = data.fields.iter().next().ok_or_else(|| {
+				Error::new_spanned(&input.ident, "an ensurer must have a field to ensure")
+			})?;
+			Field {
+				member: field
+					.ident
+					.clone()
+					.map_or(Member::Unnamed(Index::from(0)), Member::Named),
+				ty: field.ty.clone(),
+			}
+		},
+	};
+	Ok((ensured, payload, data.fields.len()))
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream>
+{
+	let options = Options::parse(&input.attrs)?;
+	let (ensured, payload, field_count) = find_fields(&input)?;
+
+	let is_tuple =
+		matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Unnamed(_)));
+	let derives_ensure = !options.exclude.iter().any(|e| e == "Ensure");
+	if derives_ensure && (!is_tuple || field_count != 1 + payload.is_some() as usize)
+	{
+		return Err(Error::new_spanned(
+			&input.ident,
+			"`Ensure` can only be derived for tuple structs with only the ensured graph and \
+			 payload, use `#[ensurer(exclude(Ensure))]` and implement it instead",
+		));
+	}
+	if let Some(payload) = &payload
+	{
+		if derives_ensure && payload.member != Member::Unnamed(Index::from(1))
+		{
+			return Err(Error::new_spanned(
+				&payload.ty,
+				"to derive `Ensure`, the payload must be the second field",
+			));
+		}
+	}
+
+	// The generic parameters, with their bounds moved to the where clause
+	let mut types = Vec::new();
+	let mut consts = Vec::new();
+	let mut params = Vec::new();
+	let mut bounds: Vec<WherePredicate> = Vec::new();
+	for param in &input.generics.params
+	{
+		match param
+		{
+			GenericParam::Type(ty) =>
+			{
+				let ident = &ty.ident;
+				types.push(ident.clone());
+				params.push(quote!(#ident));
+				if !ty.bounds.is_empty()
+				{
+					let ty_bounds = &ty.bounds;
+					bounds.push(syn::parse_quote!(#ident: #ty_bounds));
+				}
+			},
+			GenericParam::Const(c) =>
+			{
+				let (ident, ty) = (&c.ident, &c.ty);
+				consts.push(quote!(const #ident: #ty));
+				params.push(quote!(const #ident: #ty));
+			},
+			GenericParam::Lifetime(l) =>
+			{
+				return Err(Error::new_spanned(
+					l,
+					"`Ensurer` can't be derived for structs with lifetime parameters",
+				));
+			},
+		}
+	}
+	if types.is_empty() && !consts.is_empty()
+	{
+		return Err(Error::new_spanned(
+			&input.generics,
+			"`Ensurer` can't be derived for structs with only const parameters",
+		));
+	}
+	if let Some(where_clause) = &input.generics.where_clause
+	{
+		bounds.extend(where_clause.predicates.iter().cloned());
+	}
+
+	let ident = &input.ident;
+	let (_, ty_generics, _) = input.generics.split_for_impl();
+	let struct_ty = quote!(#ident #ty_generics);
+
+	let use_generics = (!types.is_empty()).then(|| {
+		let consts = (!consts.is_empty()).then(|| quote!(; #(#consts),*));
+		quote!(use<#(#types),* #consts>)
+	});
+	let exclude = (!options.exclude.is_empty()).then(|| {
+		let exclude = &options.exclude;
+		quote!(: #(#exclude),*)
+	});
+	let (ensured_member, ensured_ty) = (&ensured.member, &ensured.ty);
+	let payload = payload.map(|Field { member, ty }| quote!(as (self.#member) : #ty));
+	let where_clause = (!bounds.is_empty()).then(|| quote!(where #(#bounds),*));
+
+	let delegations = options.delegate.iter().map(|path| {
+		quote! {
+			#path! {
+				@trait [ #path ]
+				@struct [ #struct_ty ]
+				@generics [ #(#params,)* ]
+				@delegate [ #ensured_ty ]
+				@delegate_to [ .#ensured_member ]
+				@bounds [ #(#bounds,)* ]
+			}
+		}
+	});
+
+	Ok(quote! {
+		::graphene::impl_ensurer! {
+			#use_generics #struct_ty #exclude
+			as (self.#ensured_member) : #ensured_ty
+			#payload
+			#where_clause
+		}
+		#(#delegations)*
+	})
+}
@@ -0,0 +1,72 @@
+//! Derive macros for defining ensurers and properties for
+//! [graphene](https://docs.rs/graphene).
+//!
+//! The macros are re-exported by `graphene::core` and should be used from
+//! there.
+//! The code they generate refers to the `graphene` crate by name, so it must
+//! not be renamed by the crate using them.
+
+mod delegate_properties;
+mod ensurer;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemTrait};
+
+/// Implements everything an ensurer needs, delegating to the graph it wraps.
+///
+/// The graph, all of graphene's properties, and the mutation traits are
+/// delegated to the ensured graph like
+/// [impl_ensurer](https://docs.rs/graphene/latest/graphene/macro.impl_ensurer.html)
+/// does, which the derived code expands to.
+///
+/// The ensured graph is the first field of the struct, unless another field
+/// is marked with `#[ensurer(ensured)]`. A field marked with
+/// `#[ensurer(payload)]` is the payload of the ensurer.
+///
+/// The struct itself takes these options in an `#[ensurer(..)]` attribute:
+///
+/// - `exclude(..)`: Graphene traits that should not be delegated, usually
+///   because the ensurer implements them itself. E.g. `Ensure`, or `RemoveEdge`
+///   if removing an edge could break the ensured property.
+/// - `delegate(..)`: Paths to additional property traits to delegate. Each
+///   trait must be marked with
+///   [delegate_properties](macro@crate::delegate_properties).
+///
+/// The `Ensure` trait is only derived for tuple structs with no other fields
+/// than the ensured graph and the payload.
+#[proc_macro_derive(Ensurer, attributes(ensurer))]
+pub fn derive_ensurer(input: TokenStream) -> TokenStream
+{
+	let input = parse_macro_input!(input as DeriveInput);
+	ensurer::expand(input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Makes a property trait delegatable through ensurers deriving
+/// [Ensurer](derive@crate::Ensurer).
+///
+/// Methods taking `&self` or `&mut self` without a default implementation,
+/// associated types, and associated constants without a default are
+/// delegated to the ensured graph. Methods with default implementations use
+/// them.
+///
+/// Ensurers then delegate the property if it is given in their
+/// `#[ensurer(delegate(..))]` attribute, using the same path to the trait.
+/// For traits that aren't `pub`, the ensurers must be in the same crate.
+///
+/// Graphene's own ensurers, like `ConnectedGraph`, delegate the property
+/// without being told, unless it has methods taking `&mut self`. Those could
+/// change the graph without the ensurer checking the change, so such traits
+/// must be implemented for graphene's ensurers by hand, if at all.
+/// The trait therefore can't otherwise be implemented for graphene's
+/// ensurers.
+#[proc_macro_attribute]
+pub fn delegate_properties(attr: TokenStream, item: TokenStream) -> TokenStream
+{
+	let attr = proc_macro2::TokenStream::from(attr);
+	let item = parse_macro_input!(item as ItemTrait);
+	delegate_properties::expand(attr, item)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
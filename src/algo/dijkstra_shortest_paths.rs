@@ -1,14 +1,21 @@
 use crate::{
 	algo::retain::{Retainable, UnretainedIterator},
-	core::{property::VertexIn, Edge, Graph},
+	core::{
+		property::{NonNegativeWeights, VertexIn},
+		Edge,
+	},
 };
-use num_traits::{PrimInt, Unsigned, Zero};
-use std::borrow::Borrow;
+use num_traits::Zero;
+use std::{borrow::Borrow, cmp::Ordering};
 /// [Dijkstra's shortest paths algorithm](https://mathworld.wolfram.com/DijkstrasAlgorithm.html)
+///
+/// Edge weights can be any type that can be added and ordered, as long as
+/// the graph guarantees they are
+/// [non-negative](../core/property/trait.NonNegativeWeights.html).
 pub struct DijkstraShortestPaths<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	visited: Vec<G::Vertex>,
 	// We keep it sorted with the lowest weight at the end for efficiency.
@@ -17,8 +24,8 @@ where
 
 impl<G> DijkstraShortestPaths<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	pub fn new(graph: &G) -> Self
 	where
@@ -57,7 +64,8 @@ where
 				self.queue.push((new_weight, (v, sink)));
 			}
 		}
-		self.queue.sort_by(|(w1, _), (w2, _)| w2.cmp(w1));
+		self.queue
+			.sort_by(|(w1, _), (w2, _)| w2.partial_cmp(w1).unwrap_or(Ordering::Equal));
 	}
 
 	/// Returns the vertices reachable from the designated vertex and the
@@ -96,8 +104,8 @@ where
 
 impl<G> UnretainedIterator<G> for DijkstraShortestPaths<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	type Item = (G::Vertex, G::Vertex, G::EdgeWeight);
 
@@ -0,0 +1,129 @@
+use crate::core::{property::EdgeIndexed, Directedness, Graph};
+use std::{collections::HashMap, hash::Hash};
+
+/// A walk through a graph, given by the vertices visited in order and the ids
+/// of the edges taken between them.
+pub type EulerWalk<G> = (Vec<<G as Graph>::Vertex>, Vec<<G as EdgeIndexed>::EdgeId>);
+
+/// Returns an Euler circuit of the given graph, or `None` if it has none.
+///
+/// An Euler circuit is a closed walk that uses every edge exactly once.
+/// It is returned as the vertices visited, starting and ending at the same
+/// vertex, and the ids of the edges taken between them.
+/// Parallel edges are each taken once, and isolated vertices are ignored.
+/// A graph without edges has an empty circuit.
+///
+/// Uses Hierholzer's algorithm, which runs in linear time.
+pub fn euler_circuit<G: EdgeIndexed>(graph: &G) -> Option<EulerWalk<G>>
+where
+	G::Vertex: Hash,
+{
+	euler_walk(graph, true)
+}
+
+/// Returns an Euler trail of the given graph, or `None` if it has none.
+///
+/// An Euler trail is a walk that uses every edge exactly once, but may end at
+/// a different vertex than it started at.
+/// It is returned as the vertices visited and the ids of the edges taken
+/// between them.
+/// If the graph has an Euler circuit, that is returned.
+///
+/// Uses Hierholzer's algorithm, which runs in linear time.
+pub fn euler_trail<G: EdgeIndexed>(graph: &G) -> Option<EulerWalk<G>>
+where
+	G::Vertex: Hash,
+{
+	euler_walk(graph, false)
+}
+
+fn euler_walk<G: EdgeIndexed>(graph: &G, closed: bool) -> Option<EulerWalk<G>>
+where
+	G::Vertex: Hash,
+{
+	let directed = G::Directedness::directed();
+	let vertices: Vec<_> = graph.all_vertices().collect();
+	let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+	// The edges each vertex can leave through, and where they lead
+	let mut leaving = vec![Vec::new(); vertices.len()];
+	// For directed graphs, the number of outgoing minus incoming edges.
+	// For undirected graphs, the degree.
+	let mut balance = vec![0isize; vertices.len()];
+	let mut edge_count = 0;
+	for id in graph.all_edge_ids()
+	{
+		let (source, sink) = graph.edge_endpoints(id)?;
+		let (source, sink) = (index[&source], index[&sink]);
+		leaving[source].push((edge_count, sink, id));
+		if directed
+		{
+			balance[source] += 1;
+			balance[sink] -= 1;
+		}
+		else
+		{
+			leaving[sink].push((edge_count, source, id));
+			balance[source] += 1;
+			balance[sink] += 1;
+		}
+		edge_count += 1;
+	}
+
+	// The walk must start where there is an extra edge leaving, if anywhere
+	let unbalanced: Vec<_> = if directed
+	{
+		(0..vertices.len()).filter(|v| balance[*v] != 0).collect()
+	}
+	else
+	{
+		(0..vertices.len())
+			.filter(|v| balance[*v] % 2 != 0)
+			.collect()
+	};
+	let start = match unbalanced[..]
+	{
+		[] => (0..vertices.len()).find(|v| !leaving[*v].is_empty()),
+		[v1, _] if !closed && !directed => Some(v1),
+		[v1, v2] if !closed && balance[v1] == 1 && balance[v2] == -1 => Some(v1),
+		[v1, v2] if !closed && balance[v1] == -1 && balance[v2] == 1 => Some(v2),
+		_ => return None,
+	};
+	let Some(start) = start
+	else
+	{
+		return Some((Vec::new(), Vec::new()));
+	};
+
+	// Follow unused edges until stuck, then backtrack, adding to the walk
+	let mut used = vec![false; edge_count];
+	let mut next = vec![0; vertices.len()];
+	let mut stack = vec![(start, None)];
+	let mut walk_vertices = Vec::with_capacity(edge_count + 1);
+	let mut walk_edges = Vec::with_capacity(edge_count);
+	while let Some(&(v, edge)) = stack.last()
+	{
+		while leaving[v].get(next[v]).is_some_and(|(e, _, _)| used[*e])
+		{
+			next[v] += 1;
+		}
+		if let Some(&(e, w, id)) = leaving[v].get(next[v])
+		{
+			used[e] = true;
+			stack.push((w, Some(id)));
+		}
+		else
+		{
+			stack.pop();
+			walk_vertices.push(vertices[v]);
+			walk_edges.extend(edge);
+		}
+	}
+
+	// If some edges weren't reached, they aren't connected to the rest
+	(walk_edges.len() == edge_count).then(|| {
+		walk_vertices.reverse();
+		walk_edges.reverse();
+		(walk_vertices, walk_edges)
+	})
+}
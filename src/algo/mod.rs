@@ -1,11 +1,13 @@
 //! A collection of graph algorithm implementations.
 
 mod dijkstra_shortest_paths;
+mod euler;
+mod planarity;
 mod retain;
 pub mod search;
 mod tarjan_scc;
 
-pub use self::{dijkstra_shortest_paths::*, retain::*, tarjan_scc::*};
+pub use self::{dijkstra_shortest_paths::*, euler::*, planarity::*, retain::*, tarjan_scc::*};
 use crate::{
 	algo::search::new_search,
 	core::{property::VertexInGraph, Ensure, Graph},
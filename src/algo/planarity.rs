@@ -0,0 +1,569 @@
+use crate::core::Graph;
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+/// Returns a planar embedding of the given graph, or `None` if the graph
+/// isn't planar.
+///
+/// The embedding gives the neighbors of each vertex in clockwise order around
+/// it in a drawing of the graph in the plane where no edges cross.
+/// Edge directions, parallel edges, and loops don't affect planarity, so they
+/// are ignored and each neighbor is given once.
+///
+/// Uses the left-right planarity test by Brandes, which itself runs in linear
+/// time. Placing each neighbor in the embedding searches the neighbors placed
+/// before it, so building the embedding takes `O(n^2)` time for `n` vertices
+/// in the worst case.
+/// The edges are collected using
+/// [edges_incident_on](crate::core::Graph::edges_incident_on), which for
+/// graphs that don't implement it directly, like
+/// [AdjListGraph](crate::common::AdjListGraph), goes through every vertex and
+/// so also takes `O(n^2)` time in total.
+pub fn planar_embedding<G: Graph>(graph: &G) -> Option<HashMap<G::Vertex, Vec<G::Vertex>>>
+where
+	G::Vertex: Hash,
+{
+	planar_embedding_with(graph, None)
+}
+
+/// Like [`planar_embedding`], but as if the given edge was also in the graph.
+pub(crate) fn planar_embedding_with<G: Graph>(
+	graph: &G,
+	extra_edge: Option<(G::Vertex, G::Vertex)>,
+) -> Option<HashMap<G::Vertex, Vec<G::Vertex>>>
+where
+	G::Vertex: Hash,
+{
+	let vertices: Vec<_> = graph.all_vertices().collect();
+	let index: HashMap<_, _> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+	// Each vertex's neighbors and the ids of the edges to them
+	let mut adjacency = vec![Vec::new(); vertices.len()];
+	let mut edges = HashSet::new();
+	let mut add_edge = |v1: usize, v2: usize| {
+		if v1 != v2 && edges.insert((v1.min(v2), v1.max(v2)))
+		{
+			let id = edges.len() - 1;
+			adjacency[v1].push((v2, id));
+			adjacency[v2].push((v1, id));
+		}
+	};
+	for (i, v) in vertices.iter().enumerate()
+	{
+		for (n, _) in graph.edges_incident_on(v)
+		{
+			add_edge(i, index[&n]);
+		}
+	}
+	if let Some((v1, v2)) = extra_edge
+	{
+		add_edge(index[&v1], index[&v2]);
+	}
+
+	LeftRight::new(adjacency).embedding().map(|embedding| {
+		embedding
+			.into_iter()
+			.enumerate()
+			.map(|(i, ns)| (vertices[i], ns.into_iter().map(|n| vertices[n]).collect()))
+			.collect()
+	})
+}
+
+/// Marks vertices not yet visited by the depth-first search.
+const UNVISITED: usize = usize::MAX;
+
+/// An interval of return edges, given by its lowest and highest edge.
+#[derive(Clone, Copy, Default)]
+struct Interval
+{
+	low: Option<usize>,
+	high: Option<usize>,
+}
+
+impl Interval
+{
+	fn is_empty(&self) -> bool
+	{
+		self.low.is_none() && self.high.is_none()
+	}
+
+	/// Whether the interval has a return edge higher than the lowpoint of the
+	/// given edge.
+	fn conflicting(&self, lowpt: &[usize], edge: usize) -> bool
+	{
+		self.high.is_some_and(|high| lowpt[high] > lowpt[edge])
+	}
+}
+
+/// Two intervals of return edges that must be on different sides.
+#[derive(Clone, Copy, Default)]
+struct ConflictPair
+{
+	left: Interval,
+	right: Interval,
+}
+
+impl ConflictPair
+{
+	fn swap(&mut self)
+	{
+		std::mem::swap(&mut self.left, &mut self.right);
+	}
+
+	/// The lowest lowpoint of the return edges in the pair.
+	fn lowest(&self, lowpt: &[usize]) -> usize
+	{
+		[self.left.low, self.right.low]
+			.into_iter()
+			.flatten()
+			.map(|e| lowpt[e])
+			.min()
+			.unwrap_or(UNVISITED)
+	}
+}
+
+/// The state of the left-right planarity test.
+///
+/// Vertices and edges are given by their index.
+/// Edges are oriented by a depth-first search, after which each edge is
+/// either a tree edge or a back edge to an ancestor.
+struct LeftRight
+{
+	/// The neighbors of each vertex and the edges to them.
+	adjacency: Vec<Vec<(usize, usize)>>,
+	/// The vertices each depth-first search started at.
+	roots: Vec<usize>,
+	height: Vec<usize>,
+	parent_edge: Vec<Option<usize>>,
+	/// The oriented edges going out of each vertex.
+	outgoing: Vec<Vec<usize>>,
+
+	source: Vec<usize>,
+	sink: Vec<usize>,
+	oriented: Vec<bool>,
+	lowpt: Vec<usize>,
+	lowpt2: Vec<usize>,
+	nesting_depth: Vec<isize>,
+	lowpt_edge: Vec<Option<usize>>,
+	reference: Vec<Option<usize>>,
+	side: Vec<isize>,
+	stack_bottom: Vec<usize>,
+
+	stack: Vec<ConflictPair>,
+}
+
+impl LeftRight
+{
+	fn new(adjacency: Vec<Vec<(usize, usize)>>) -> Self
+	{
+		let vertex_count = adjacency.len();
+		let edge_count = adjacency.iter().map(Vec::len).sum::<usize>() / 2;
+		Self {
+			adjacency,
+			roots: Vec::new(),
+			height: vec![UNVISITED; vertex_count],
+			parent_edge: vec![None; vertex_count],
+			outgoing: vec![Vec::new(); vertex_count],
+			source: vec![0; edge_count],
+			sink: vec![0; edge_count],
+			oriented: vec![false; edge_count],
+			lowpt: vec![0; edge_count],
+			lowpt2: vec![0; edge_count],
+			nesting_depth: vec![0; edge_count],
+			lowpt_edge: vec![None; edge_count],
+			reference: vec![None; edge_count],
+			side: vec![1; edge_count],
+			stack_bottom: vec![0; edge_count],
+			stack: Vec::new(),
+		}
+	}
+
+	/// Returns the clockwise order of neighbors around each vertex, or `None`
+	/// if the graph isn't planar.
+	fn embedding(mut self) -> Option<Vec<Vec<usize>>>
+	{
+		let vertex_count = self.adjacency.len();
+		if vertex_count > 2 && self.source.len() > 3 * vertex_count - 6
+		{
+			return None;
+		}
+		self.orient();
+		self.test().then(|| self.embed())
+	}
+
+	/// Orients the edges using depth-first searches, computing the lowpoints
+	/// and nesting depths of the edges.
+	fn orient(&mut self)
+	{
+		let mut next = vec![0; self.adjacency.len()];
+		let mut descended = vec![false; self.source.len()];
+
+		for root in 0..self.adjacency.len()
+		{
+			if self.height[root] != UNVISITED
+			{
+				continue;
+			}
+			self.height[root] = 0;
+			self.roots.push(root);
+
+			let mut dfs = vec![root];
+			while let Some(v) = dfs.pop()
+			{
+				let e = self.parent_edge[v];
+				while let Some(&(w, vw)) = self.adjacency[v].get(next[v])
+				{
+					if !descended[vw]
+					{
+						if self.oriented[vw]
+						{
+							next[v] += 1;
+							continue;
+						}
+						self.oriented[vw] = true;
+						self.source[vw] = v;
+						self.sink[vw] = w;
+						self.outgoing[v].push(vw);
+						self.lowpt[vw] = self.height[v];
+						self.lowpt2[vw] = self.height[v];
+
+						if self.height[w] == UNVISITED
+						{
+							// Tree edge, finish it after visiting w
+							self.parent_edge[w] = Some(vw);
+							self.height[w] = self.height[v] + 1;
+							descended[vw] = true;
+							dfs.push(v);
+							dfs.push(w);
+							break;
+						}
+						// Back edge
+						self.lowpt[vw] = self.height[w];
+					}
+
+					// Edges with a lower lowpoint are nested inside the others, while
+					// chordal edges must come after the rest with the same lowpoint
+					self.nesting_depth[vw] = 2 * self.lowpt[vw] as isize;
+					if self.lowpt2[vw] < self.height[v]
+					{
+						self.nesting_depth[vw] += 1;
+					}
+
+					if let Some(e) = e
+					{
+						if self.lowpt[vw] < self.lowpt[e]
+						{
+							self.lowpt2[e] = self.lowpt[e].min(self.lowpt2[vw]);
+							self.lowpt[e] = self.lowpt[vw];
+						}
+						else if self.lowpt[vw] > self.lowpt[e]
+						{
+							self.lowpt2[e] = self.lowpt2[e].min(self.lowpt[vw]);
+						}
+						else
+						{
+							self.lowpt2[e] = self.lowpt2[e].min(self.lowpt2[vw]);
+						}
+					}
+					next[v] += 1;
+				}
+			}
+		}
+	}
+
+	/// Assigns the back edges to sides, returning whether it succeeded, i.e.
+	/// whether the graph is planar.
+	fn test(&mut self) -> bool
+	{
+		let nesting_depth = &self.nesting_depth;
+		for out in self.outgoing.iter_mut()
+		{
+			out.sort_by_key(|e| nesting_depth[*e]);
+		}
+
+		let mut next = vec![0; self.adjacency.len()];
+		let mut descended = vec![false; self.source.len()];
+
+		for root_idx in 0..self.roots.len()
+		{
+			let mut dfs = vec![self.roots[root_idx]];
+			while let Some(v) = dfs.pop()
+			{
+				let e = self.parent_edge[v];
+				let mut finished = true;
+				while let Some(&ei) = self.outgoing[v].get(next[v])
+				{
+					if !descended[ei]
+					{
+						self.stack_bottom[ei] = self.stack.len();
+						if self.parent_edge[self.sink[ei]] == Some(ei)
+						{
+							// Tree edge, finish it after visiting its sink
+							descended[ei] = true;
+							dfs.push(v);
+							dfs.push(self.sink[ei]);
+							finished = false;
+							break;
+						}
+						// Back edge
+						self.lowpt_edge[ei] = Some(ei);
+						self.stack.push(ConflictPair {
+							left: Interval::default(),
+							right: Interval {
+								low: Some(ei),
+								high: Some(ei),
+							},
+						});
+					}
+
+					// Integrate the return edges of ei
+					if let Some(e) = e.filter(|_| self.lowpt[ei] < self.height[v])
+					{
+						if next[v] == 0
+						{
+							self.lowpt_edge[e] = self.lowpt_edge[ei];
+						}
+						else if !self.add_constraints(ei, e)
+						{
+							return false;
+						}
+					}
+					next[v] += 1;
+				}
+
+				if let Some(e) = e.filter(|_| finished)
+				{
+					self.remove_back_edges(e);
+				}
+			}
+		}
+		true
+	}
+
+	/// Adds the constraints that the return edges of `ei` put on the edges
+	/// before it out of the sink of `e`.
+	fn add_constraints(&mut self, ei: usize, e: usize) -> bool
+	{
+		let mut p = ConflictPair::default();
+
+		// Merge the return edges of ei into the right of p
+		while let Some(mut q) = self.stack.pop()
+		{
+			if !q.left.is_empty()
+			{
+				q.swap();
+			}
+			if !q.left.is_empty()
+			{
+				return false;
+			}
+			if q.right
+				.low
+				.is_some_and(|low| self.lowpt[low] > self.lowpt[e])
+			{
+				if p.right.is_empty()
+				{
+					p.right = q.right;
+				}
+				else if let Some(low) = p.right.low
+				{
+					self.reference[low] = q.right.high;
+				}
+				p.right.low = q.right.low;
+			}
+			else if let Some(low) = q.right.low
+			{
+				self.reference[low] = self.lowpt_edge[e];
+			}
+			if self.stack.len() <= self.stack_bottom[ei]
+			{
+				break;
+			}
+		}
+
+		// Merge the conflicting return edges of the previous edges into the left
+		// of p
+		while let Some(mut q) = self.stack.pop()
+		{
+			if !(q.left.conflicting(&self.lowpt, ei) || q.right.conflicting(&self.lowpt, ei))
+			{
+				self.stack.push(q);
+				break;
+			}
+			if q.right.conflicting(&self.lowpt, ei)
+			{
+				q.swap();
+			}
+			if q.right.conflicting(&self.lowpt, ei)
+			{
+				return false;
+			}
+			if let Some(low) = p.right.low
+			{
+				self.reference[low] = q.right.high;
+			}
+			if q.right.low.is_some()
+			{
+				p.right.low = q.right.low;
+			}
+			if p.left.is_empty()
+			{
+				p.left = q.left;
+			}
+			else if let Some(low) = p.left.low
+			{
+				self.reference[low] = q.left.high;
+			}
+			p.left.low = q.left.low;
+		}
+
+		if !(p.left.is_empty() && p.right.is_empty())
+		{
+			self.stack.push(p);
+		}
+		true
+	}
+
+	/// Removes the back edges ending at the source of the given tree edge,
+	/// which is being backtracked over.
+	fn remove_back_edges(&mut self, e: usize)
+	{
+		let u = self.source[e];
+
+		// Drop the conflict pairs with only return edges to u
+		while let Some(p) = self.stack.pop()
+		{
+			if p.lowest(&self.lowpt) != self.height[u]
+			{
+				self.stack.push(p);
+				break;
+			}
+			if let Some(low) = p.left.low
+			{
+				self.side[low] = -1;
+			}
+		}
+
+		// Trim the return edges to u from the next conflict pair
+		if let Some(mut p) = self.stack.pop()
+		{
+			while let Some(high) = p.left.high.filter(|high| self.sink[*high] == u)
+			{
+				p.left.high = self.reference[high];
+			}
+			if let Some(low) = p.left.low.filter(|_| p.left.high.is_none())
+			{
+				self.reference[low] = p.right.low;
+				self.side[low] = -1;
+				p.left.low = None;
+			}
+
+			while let Some(high) = p.right.high.filter(|high| self.sink[*high] == u)
+			{
+				p.right.high = self.reference[high];
+			}
+			if let Some(low) = p.right.low.filter(|_| p.right.high.is_none())
+			{
+				self.reference[low] = p.left.low;
+				self.side[low] = -1;
+				p.right.low = None;
+			}
+			self.stack.push(p);
+		}
+
+		// e is on the side of its highest return edge
+		if self.lowpt[e] < self.height[u]
+		{
+			if let Some(top) = self.stack.last()
+			{
+				self.reference[e] = match (top.left.high, top.right.high)
+				{
+					(Some(hl), Some(hr)) if self.lowpt[hl] > self.lowpt[hr] => Some(hl),
+					(Some(hl), None) => Some(hl),
+					(_, hr) => hr,
+				};
+			}
+		}
+	}
+
+	/// Returns the final side of the given edge, resolving the sides relative
+	/// to other edges.
+	fn sign(&mut self, e: usize) -> isize
+	{
+		let mut relative = Vec::new();
+		let mut last = e;
+		while let Some(r) = self.reference[last]
+		{
+			relative.push(last);
+			last = r;
+		}
+		for edge in relative.into_iter().rev()
+		{
+			self.side[edge] *= self.side[last];
+			self.reference[edge] = None;
+			last = edge;
+		}
+		self.side[e]
+	}
+
+	/// Builds the embedding from the sides of the edges.
+	fn embed(mut self) -> Vec<Vec<usize>>
+	{
+		for e in 0..self.source.len()
+		{
+			self.nesting_depth[e] *= self.sign(e);
+		}
+		let nesting_depth = &self.nesting_depth;
+		for out in self.outgoing.iter_mut()
+		{
+			out.sort_by_key(|e| nesting_depth[*e]);
+		}
+
+		let mut embedding: Vec<Vec<_>> = self
+			.outgoing
+			.iter()
+			.map(|out| out.iter().map(|e| self.sink[*e]).collect())
+			.collect();
+		let mut left_ref = vec![0; self.adjacency.len()];
+		let mut right_ref = vec![0; self.adjacency.len()];
+		let mut next = vec![0; self.adjacency.len()];
+
+		for &root in self.roots.iter()
+		{
+			let mut dfs = vec![root];
+			while let Some(v) = dfs.pop()
+			{
+				while let Some(&ei) = self.outgoing[v].get(next[v])
+				{
+					next[v] += 1;
+					let w = self.sink[ei];
+					if self.parent_edge[w] == Some(ei)
+					{
+						embedding[w].insert(0, v);
+						left_ref[v] = w;
+						right_ref[v] = w;
+						dfs.push(v);
+						dfs.push(w);
+						break;
+					}
+					else if self.side[ei] == 1
+					{
+						// Directly after right_ref[w]
+						let pos = embedding[w].iter().position(|n| *n == right_ref[w]);
+						embedding[w].insert(pos.map_or(0, |pos| pos + 1), v);
+					}
+					else
+					{
+						// Directly before left_ref[w]
+						let pos = embedding[w].iter().position(|n| *n == left_ref[w]);
+						embedding[w].insert(pos.unwrap_or(0), v);
+						left_ref[w] = v;
+					}
+				}
+			}
+		}
+		embedding
+	}
+}
@@ -2,7 +2,7 @@ use crate::{
 	algo::UnretainedIterator,
 	core::{
 		property::{
-			Acyclic, AcyclicGraph, EdgeCount, HasVertex, NoLoops, Rooted, Tree, Unique,
+			Acyclic, AcyclicGraph, EdgeCount, HasVertex, NoLoops, Rooted, RootedTree, Tree, Unique,
 			VertexCount, VertexIn, Weak, WeakGraph,
 		},
 		Directed, Ensure, Graph,
@@ -192,7 +192,7 @@ where
 	/// search root.
 	pub fn predecessor_tree(
 		&self,
-	) -> impl '_ + Tree<Vertex = G::Vertex, Directedness = Directed> + Rooted + VertexCount + EdgeCount
+	) -> impl '_ + RootedTree<Vertex = G::Vertex, Directedness = Directed> + VertexCount + EdgeCount
 	{
 		PredTree::new(&self.predecessor)
 	}
@@ -295,6 +295,17 @@ impl<'a, V: Copy + Eq> Rooted for PredTree<'a, V>
 	}
 }
 
+impl<'a, V: Copy + Eq> RootedTree for PredTree<'a, V>
+{
+	fn parent(&self, v: impl Borrow<Self::Vertex>) -> Option<Self::Vertex>
+	{
+		self.0
+			.iter()
+			.find(|(v2, _)| v2 == v.borrow())
+			.and_then(|(_, p)| *p)
+	}
+}
+
 base_graph! {
 	use<'a, V> PredTree<'a, V> where V: Copy + Eq
 }
@@ -1,24 +1,24 @@
 use crate::{
 	algo::{retain::UnretainedIterator, DijkstraShortestPaths},
-	core::{property::VertexIn, Graph},
+	core::property::{NonNegativeWeights, VertexIn},
 };
-use num_traits::{PrimInt, Unsigned};
+use num_traits::Zero;
 
 /// Shortest-Path-First search
 ///
 /// next() doesn't return the starting vertex.
 pub struct Spfs<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	dijk: DijkstraShortestPaths<G>,
 }
 
 impl<G> Spfs<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	pub fn new(graph: &G) -> Self
 	where
@@ -32,8 +32,8 @@ where
 
 impl<G> UnretainedIterator<G> for Spfs<G>
 where
-	G: Graph,
-	G::EdgeWeight: PrimInt + Unsigned,
+	G: NonNegativeWeights,
+	G::EdgeWeight: Copy + PartialOrd + Zero,
 {
 	type Item = G::Vertex;
 
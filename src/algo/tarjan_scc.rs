@@ -260,10 +260,9 @@ macro_rules! next_scc_impl {
 						scc.expand(stack.pop().unwrap().0).unwrap();
 					}
 
-					return Some(
-						ConnectedGraph::guard(scc)
-							.expect("Tarjans algorithm produced non-strongly-connected subgraph"),
-					);
+					return Some(ConnectedGraph::guard(scc).unwrap_or_else(|_| {
+						panic!("Tarjans algorithm produced non-strongly-connected subgraph")
+					}));
 					// return Some(ConnectedGraph::new(scc));
 				}
 				// Vertex is part of SCC but not root, keep it on stack.
@@ -1,8 +1,11 @@
 use crate::{
 	common::AdjListGraph,
 	core::{
-		property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
-		Directedness, Graph, GraphMut,
+		property::{
+			AddEdge, AddEdgeIndexed, EdgeCount, EdgeIndexed, NewVertex, RemoveEdge,
+			RemoveEdgeIndexed, RemoveVertex, VertexCount,
+		},
+		Directedness, Graph, GraphError, GraphMut,
 	},
 };
 use std::borrow::Borrow;
@@ -38,7 +41,7 @@ where
 			.get(source)
 			.into_iter()
 			.flat_map(move |(_, edges)| {
-				edges.iter().filter_map(move |(si, w)| {
+				edges.iter().filter_map(move |(si, w, _)| {
 					if *si == sink
 					{
 						Some(w)
@@ -54,7 +57,7 @@ where
 					.get(sink)
 					.into_iter()
 					.flat_map(move |(_, edges)| {
-						edges.iter().filter_map(move |(si, w)| {
+						edges.iter().filter_map(move |(si, w, _)| {
 							if !Self::Directedness::directed() && *si != sink && *si == source
 							{
 								Some(w)
@@ -67,6 +70,11 @@ where
 					}),
 			)
 	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices.get(*v.borrow()).map(|(w, _)| w)
+	}
 }
 
 impl<Vw, Ew, D> GraphMut for AdjListGraph<Vw, Ew, D>
@@ -112,7 +120,7 @@ where
 			.flat_map(|(sink_first, edges)| {
 				edges
 					.iter_mut()
-					.map(move |(si, weight)| (sink_first, si, weight))
+					.map(move |(si, weight, _)| (sink_first, si, weight))
 			})
 			.filter_map(move |(sink_first, si, weight)| {
 				if sink_first
@@ -129,13 +137,22 @@ where
 				None
 			})
 	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices.get_mut(*v.borrow()).map(|(w, _)| w)
+	}
 }
 
 impl<Vw, Ew, D> NewVertex for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let new_v = self.vertices.len();
 		self.vertices.push((w, Vec::new()));
@@ -146,7 +163,10 @@ impl<Vw, Ew, D> RemoveVertex for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if *v.borrow() < self.vertices.len()
 		{
@@ -162,11 +182,26 @@ where
 					{}
 				}
 			}
-			Ok(self.vertices.remove(*v.borrow()).0)
+
+			// The later vertices move down by one, so the sinks and the sources
+			// of the remaining edges must follow
+			let v = *v.borrow();
+			for (si, _, _) in self
+				.vertices
+				.iter_mut()
+				.flat_map(|(_, edges)| edges.iter_mut())
+			{
+				*si -= (*si > v) as usize;
+			}
+			for so in self.edge_slots.iter_mut().filter_map(|(so, _)| so.as_mut())
+			{
+				*so -= (*so > v) as usize;
+			}
+			Ok(self.vertices.remove(v).0)
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingVertex(*v.borrow()))
 		}
 	}
 }
@@ -180,20 +215,14 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
-		let len = self.vertices.len();
-		if *source.borrow() < len && *sink.borrow() < len
-		{
-			self.vertices[*source.borrow()]
-				.1
-				.push((*sink.borrow(), weight));
-			Ok(())
-		}
-		else
-		{
-			Err(())
-		}
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		let slot = self.take_edge_slot(*source.borrow());
+		self.vertices[*source.borrow()]
+			.1
+			.push((*sink.borrow(), weight, slot));
+		Ok(())
 	}
 }
 
@@ -206,45 +235,127 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
-		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		let found = self
+			.vertices
+			.iter()
+			.enumerate()
+			.flat_map(|(so_i, (_, edges))| {
+				edges
+					.iter()
+					.enumerate()
+					.map(move |(si_i, (si, w, _))| (so_i, si_i, si, w))
+			})
+			.find(|(so_i, _, si, w)| {
+				((so_i == source.borrow() && *si == sink.borrow())
+					|| (!Self::Directedness::directed()
+						&& (so_i == sink.borrow() && *si == source.borrow())))
+					&& f(w)
+			});
+		if let Some((so_i, si_i, _, _)) = found
 		{
-			let found = self
-				.vertices
-				.iter()
-				.enumerate()
-				.flat_map(|(so_i, (_, edges))| {
-					edges
-						.iter()
-						.enumerate()
-						.map(move |(si_i, (si, w))| (so_i, si_i, si, w))
-				})
-				.find(|(so_i, _, si, w)| {
-					((so_i == source.borrow() && *si == sink.borrow())
-						|| (!Self::Directedness::directed()
-							&& (so_i == sink.borrow() && *si == source.borrow())))
-						&& f(w)
-				});
-			if let Some((so_i, si_i, _, _)) = found
-			{
-				let (_, w) = self.vertices[so_i].1.remove(si_i);
-				Ok(w)
-			}
-			else
-			{
-				Err(())
-			}
+			let (_, w, slot) = self.vertices[so_i].1.remove(si_i);
+			self.free_edge_slot(slot);
+			Ok(w)
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingEdge(*source.borrow(), *sink.borrow()))
 		}
 	}
 }
 
+impl<Vw, Ew, D> EdgeIndexed for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// The slot of the edge and the number of times the slot was freed
+	/// before the edge was given it.
+	type EdgeId = (usize, usize);
+
+	fn all_edge_ids(&self) -> impl Iterator<Item = Self::EdgeId>
+	{
+		self.edge_slots
+			.iter()
+			.enumerate()
+			.filter_map(|(slot, (so, freed))| so.map(|_| (slot, *freed)))
+	}
+
+	fn edge_endpoints(&self, id: impl Borrow<Self::EdgeId>)
+		-> Option<(Self::Vertex, Self::Vertex)>
+	{
+		self.edge_position(*id.borrow())
+			.map(|(so, idx)| (so, self.vertices[so].1[idx].0))
+	}
+
+	fn edge_weight(&self, id: impl Borrow<Self::EdgeId>) -> Option<Self::EdgeWeightRef<'_>>
+	{
+		self.edge_position(*id.borrow())
+			.map(|(so, idx)| &self.vertices[so].1[idx].1)
+	}
+
+	fn edge_ids_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeId>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		let ids_from = move |so: usize, si: usize| {
+			self.vertices
+				.get(so)
+				.into_iter()
+				.flat_map(|(_, edges)| edges.iter())
+				.filter(move |(si2, _, _)| *si2 == si)
+				.map(|(_, _, slot)| (*slot, self.edge_slots[*slot].1))
+		};
+
+		let reverse = !D::directed() && source != sink;
+		ids_from(source, sink).chain(ids_from(sink, source).filter(move |_| reverse))
+	}
+}
+
+impl<Vw, Ew, D> AddEdgeIndexed for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn add_edge_weighted_indexed(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<Self::EdgeId, GraphError<Self::Vertex>>
+	{
+		let source = *source.borrow();
+		self.add_edge_weighted(source, sink, weight)?;
+		let (_, _, slot) = self.vertices[source].1.last().unwrap();
+		Ok((*slot, self.edge_slots[*slot].1))
+	}
+}
+
+impl<Vw, Ew, D> RemoveEdgeIndexed for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn remove_edge_by_id(
+		&mut self,
+		id: impl Borrow<Self::EdgeId>,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	{
+		let (so, idx) = self
+			.edge_position(*id.borrow())
+			.ok_or(GraphError::MissingEdgeId)?;
+		let (_, w, slot) = self.vertices[so].1.remove(idx);
+		self.free_edge_slot(slot);
+		Ok(w)
+	}
+}
+
 impl<Vw, Ew, D> VertexCount for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
@@ -1,8 +1,19 @@
 mod impl_graph;
+mod stable;
 
+pub use self::stable::*;
 use crate::core::{Directed, Directedness};
 use std::marker::PhantomData;
 
+type EdgeEntry<Ew> = (
+	// The index of the sink vertex
+	usize,
+	// The weight of the edge
+	Ew,
+	// The index of the edge's slot in `edge_slots`
+	usize,
+);
+
 /// A graph using the adjacency list representation.
 ///
 /// It accepts the following generic parameters:
@@ -21,13 +32,15 @@ where
 		// The weight of the vertex
 		Vw,
 		// A list outgoing edges from this vertex
-		Vec<(
-			// The index of the sink vertex
-			usize,
-			// The weight of the edge
-			Ew,
-		)>,
+		Vec<EdgeEntry<Ew>>,
 	)>,
+	/// The slot of each edge, giving the edge's source, or `None` if the slot
+	/// is free, and how many times the slot has been freed.
+	/// An edge's id is its slot and that count, so ids of removed edges aren't
+	/// valid for later edges given the same slot.
+	edge_slots: Vec<(Option<usize>, usize)>,
+	/// The indices of free slots in `edge_slots`.
+	free: Vec<usize>,
 	phantom: PhantomData<D>,
 }
 
@@ -40,9 +53,52 @@ where
 	{
 		Self {
 			vertices: Vec::new(),
+			edge_slots: Vec::new(),
+			free: Vec::new(),
 			phantom: PhantomData,
 		}
 	}
+
+	/// Gives a slot to a new edge with the given source, returning the
+	/// index of the slot.
+	fn take_edge_slot(&mut self, source: usize) -> usize
+	{
+		if let Some(slot) = self.free.pop()
+		{
+			self.edge_slots[slot].0 = Some(source);
+			slot
+		}
+		else
+		{
+			self.edge_slots.push((Some(source), 0));
+			self.edge_slots.len() - 1
+		}
+	}
+
+	/// Frees the slot of a removed edge.
+	fn free_edge_slot(&mut self, slot: usize)
+	{
+		self.edge_slots[slot] = (None, self.edge_slots[slot].1 + 1);
+		self.free.push(slot);
+	}
+
+	/// Returns the source of the edge with the given id and its index in the
+	/// source's list of edges, or `None` if no edge has the id.
+	fn edge_position(&self, (slot, freed): (usize, usize)) -> Option<(usize, usize)>
+	{
+		match self.edge_slots.get(slot)
+		{
+			Some((Some(so), f)) if *f == freed =>
+			{
+				let idx = self.vertices[*so]
+					.1
+					.iter()
+					.position(|(_, _, s)| *s == slot)?;
+				Some((*so, idx))
+			},
+			_ => None,
+		}
+	}
 }
 
 impl<Vw, Ew, D> Default for AdjListGraph<Vw, Ew, D>
@@ -0,0 +1,354 @@
+use crate::core::{
+	property::{
+		AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, StableRemoveVertex, VertexCount,
+	},
+	Directed, Directedness, Graph, GraphError, GraphMut,
+};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A vertex in a [StableAdjListGraph](struct.StableAdjListGraph.html).
+type StableEntry<Vw, Ew> = (
+	// The weight of the vertex
+	Vw,
+	// A list outgoing edges from this vertex
+	Vec<(
+		// The index of the sink vertex
+		usize,
+		// The weight of the edge
+		Ew,
+	)>,
+);
+
+/// A graph using the adjacency list representation, whose vertex ids remain
+/// valid when other vertices are removed.
+///
+/// Unlike [AdjListGraph](struct.AdjListGraph.html), removing a vertex doesn't
+/// shift the ids of the vertices after it. Instead, the removed vertex
+/// leaves a tombstone, and its id is never given to a new vertex.
+/// The tombstones can be cleared using [`compact`](#method.compact),
+/// which does change vertex ids.
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+#[derive(Clone, Debug)]
+pub struct StableAdjListGraph<Vw, Ew, D = Directed>
+where
+	D: Directedness,
+{
+	/// Adjacency list representation of the graph.
+	/// Each index in vec is a vertex. Removed vertices are `None`.
+	vertices: Vec<Option<StableEntry<Vw, Ew>>>,
+	/// The number of vertices that haven't been removed.
+	vertex_count: usize,
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D> StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// Constructs a new, empty `StableAdjListGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertices: Vec::new(),
+			vertex_count: 0,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Removes the tombstones left by removed vertices, giving the remaining
+	/// vertices new ids.
+	///
+	/// The relative order of the remaining vertices is kept.
+	///
+	/// Returns the remapping of the ids, where the value at each index is the
+	/// new id of the vertex with that id before compacting.
+	/// If the index is not a vertex (i.e. it had been removed) the value is
+	/// `None`.
+	pub fn compact(&mut self) -> Vec<Option<usize>>
+	{
+		let mut next = 0;
+		let remap: Vec<_> = self
+			.vertices
+			.iter()
+			.map(|v| {
+				v.as_ref().map(|_| {
+					next += 1;
+					next - 1
+				})
+			})
+			.collect();
+
+		self.vertices.retain(|v| v.is_some());
+		for (_, edges) in self.vertices.iter_mut().flatten()
+		{
+			for (si, _) in edges.iter_mut()
+			{
+				*si = remap[*si].unwrap();
+			}
+		}
+		remap
+	}
+}
+
+impl<Vw, Ew, D> Default for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<Vw, Ew, D> Graph for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type EdgeWeightRef<'a>
+		= &'a Self::EdgeWeight
+	where
+		Self: 'a;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.vertices
+			.iter()
+			.enumerate()
+			.filter_map(|(v, entry)| entry.as_ref().map(|(w, _)| (v, w)))
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		let edges_of = move |v: usize| {
+			self.vertices
+				.get(v)
+				.into_iter()
+				.flatten()
+				.flat_map(|(_, edges)| edges.iter())
+		};
+
+		edges_of(source)
+			.filter_map(move |(si, w)| (*si == sink).then_some(w))
+			.chain(edges_of(sink).filter_map(move |(si, w)| {
+				(!D::directed() && sink != source && *si == source).then_some(w)
+			}))
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices
+			.get(*v.borrow())
+			.and_then(|entry| entry.as_ref().map(|(w, _)| w))
+	}
+}
+
+impl<Vw, Ew, D> GraphMut for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		self.vertices
+			.iter_mut()
+			.enumerate()
+			.filter_map(|(v, entry)| entry.as_mut().map(|(w, _)| (v, w)))
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+
+		self.vertices
+			.iter_mut()
+			.enumerate()
+			.filter_map(move |(so, entry)| {
+				// Which sink we are looking for in this vertex's edges
+				let wanted = if so == source
+				{
+					sink
+				}
+				else if !D::directed() && so == sink
+				{
+					source
+				}
+				else
+				{
+					return None;
+				};
+				entry.as_mut().map(move |(_, edges)| (wanted, edges))
+			})
+			.flat_map(|(wanted, edges)| {
+				edges
+					.iter_mut()
+					.filter_map(move |(si, w)| (*si == wanted).then_some(w))
+			})
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices
+			.get_mut(*v.borrow())
+			.and_then(|entry| entry.as_mut().map(|(w, _)| w))
+	}
+}
+
+impl<Vw, Ew, D> NewVertex for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let new_v = self.vertices.len();
+		self.vertices.push(Some((w, Vec::new())));
+		self.vertex_count += 1;
+		Ok(new_v)
+	}
+}
+
+impl<Vw, Ew, D> RemoveVertex for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		let (w, _) = self
+			.vertices
+			.get_mut(v)
+			.and_then(Option::take)
+			.ok_or(GraphError::MissingVertex(v))?;
+
+		for (_, edges) in self.vertices.iter_mut().flatten()
+		{
+			edges.retain(|(si, _)| *si != v);
+		}
+		self.vertex_count -= 1;
+		Ok(w)
+	}
+}
+
+impl<Vw, Ew, D> StableRemoveVertex for StableAdjListGraph<Vw, Ew, D> where D: Directedness {}
+
+impl<Vw, Ew, D> AddEdge for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		if !self.contains_vertex(sink.borrow())
+		{
+			return Err(GraphError::MissingVertex(*sink.borrow()));
+		}
+		if let Some(Some((_, edges))) = self.vertices.get_mut(*source.borrow())
+		{
+			edges.push((*sink.borrow(), weight));
+			Ok(())
+		}
+		else
+		{
+			Err(GraphError::MissingVertex(*source.borrow()))
+		}
+	}
+}
+
+impl<Vw, Ew, D> RemoveEdge for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		GraphError::check_vertices(self, [source, sink])?;
+
+		let mut candidates = vec![(source, sink)];
+		if !D::directed() && source != sink
+		{
+			candidates.push((sink, source));
+		}
+		for (so, si) in candidates
+		{
+			if let Some(Some((_, edges))) = self.vertices.get_mut(so)
+			{
+				if let Some(idx) = edges.iter().position(|(s, w)| *s == si && f(w))
+				{
+					return Ok(edges.remove(idx).1);
+				}
+			}
+		}
+		Err(GraphError::MissingEdge(source, sink))
+	}
+}
+
+impl<Vw, Ew, D> VertexCount for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertex_count
+	}
+}
+
+impl<Vw, Ew, D> EdgeCount for StableAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.vertices
+			.iter()
+			.flatten()
+			.fold(0, |count, (_, edges)| count + edges.len())
+	}
+}
+
+base_graph! {
+	use<Vw, Ew, D> StableAdjListGraph<Vw, Ew, D>
+	where D: Directedness,
+}
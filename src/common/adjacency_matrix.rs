@@ -0,0 +1,343 @@
+use crate::core::{
+	property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, Unique, VertexCount},
+	Directed, Directedness, Graph, GraphError, GraphMut,
+};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A graph using the adjacency matrix representation.
+///
+/// Each pair of vertices has a cell in the matrix holding the edges between
+/// them, making looking up the edges between two vertices an O(1) operation.
+/// For undirected graphs, only the cell where the source is not greater than
+/// the sink is used.
+///
+/// By default, the graph is [Unique](../core/property/trait.Unique.html):
+/// adding an edge between two vertices that already have an edge
+/// (in the same direction, if directed) fails.
+/// If `MULTI` is true, any number of edges is allowed between two vertices.
+///
+/// Adding or removing vertices costs O(V), and removing a vertex shifts the
+/// indices of all vertices after it, like
+/// [AdjListGraph](struct.AdjListGraph.html).
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+/// - `MULTI`: Whether more than one edge may connect the same two vertices.
+///   Defaults to false.
+#[derive(Clone, Debug)]
+pub struct AdjMatrixGraph<Vw, Ew, D = Directed, const MULTI: bool = false>
+where
+	D: Directedness,
+{
+	/// Adjacency matrix representation of the graph.
+	/// Each index in vec is a vertex.
+	vertices: Vec<(
+		// The weight of the vertex
+		Vw,
+		// The row of the matrix for edges sourced in this vertex.
+		// Each index is the sink vertex.
+		Vec<Vec<Ew>>,
+	)>,
+	/// The number of edges in the graph.
+	edge_count: usize,
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D, const MULTI: bool> AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	/// Constructs a new, empty `AdjMatrixGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertices: Vec::new(),
+			edge_count: 0,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns the matrix position of the edges between the given vertices.
+	fn position(source: usize, sink: usize) -> (usize, usize)
+	{
+		if !D::directed() && source > sink
+		{
+			(sink, source)
+		}
+		else
+		{
+			(source, sink)
+		}
+	}
+
+	/// Returns the edges between the given vertices, if both are in the graph.
+	fn cell(&self, source: usize, sink: usize) -> Option<&Vec<Ew>>
+	{
+		let (so, si) = Self::position(source, sink);
+		self.vertices.get(so).and_then(|(_, row)| row.get(si))
+	}
+
+	/// Returns the edges between the given vertices, if both are in the graph.
+	fn cell_mut(&mut self, source: usize, sink: usize) -> Option<&mut Vec<Ew>>
+	{
+		let (so, si) = Self::position(source, sink);
+		self.vertices
+			.get_mut(so)
+			.and_then(|(_, row)| row.get_mut(si))
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> Default for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> Graph for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type EdgeWeightRef<'a>
+		= &'a Self::EdgeWeight
+	where
+		Self: 'a;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.vertices.iter().enumerate().map(|(v, (w, _))| (v, w))
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		self.cell(*source.borrow(), *sink.borrow())
+			.into_iter()
+			.flatten()
+	}
+
+	fn all_edges(
+		&self,
+	) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.vertices.iter().enumerate().flat_map(|(so, (_, row))| {
+			// Undirected edges are only in the upper triangle
+			let start = if D::directed() { 0 } else { so };
+			row.iter()
+				.enumerate()
+				.skip(start)
+				.flat_map(move |(si, edges)| edges.iter().map(move |w| (so, si, w)))
+		})
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices.get(*v.borrow()).map(|(w, _)| w)
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		(0..self.vertices.len())
+			.flat_map(move |si| self.cell(v, si).into_iter().flatten().map(move |w| (si, w)))
+	}
+
+	fn edges_sinked_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		(0..self.vertices.len())
+			.flat_map(move |so| self.cell(so, v).into_iter().flatten().map(move |w| (so, w)))
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> GraphMut for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		self.vertices
+			.iter_mut()
+			.enumerate()
+			.map(|(v, (w, _))| (v, w))
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		self.cell_mut(*source.borrow(), *sink.borrow())
+			.into_iter()
+			.flatten()
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices.get_mut(*v.borrow()).map(|(w, _)| w)
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> NewVertex for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let new_v = self.vertices.len();
+		for (_, row) in self.vertices.iter_mut()
+		{
+			row.push(Vec::new());
+		}
+		self.vertices
+			.push((w, (0..=new_v).map(|_| Vec::new()).collect()));
+		Ok(new_v)
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> RemoveVertex for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		if v < self.vertices.len()
+		{
+			// For undirected graphs, the edges sourced in the vertex are all its edges
+			let incoming = if D::directed()
+			{
+				self.edges_sinked_in(v).filter(|(so, _)| *so != v).count()
+			}
+			else
+			{
+				0
+			};
+			self.edge_count -= self.edges_sourced_in(v).count() + incoming;
+
+			for (_, row) in self.vertices.iter_mut()
+			{
+				row.remove(v);
+			}
+			Ok(self.vertices.remove(v).0)
+		}
+		else
+		{
+			Err(GraphError::MissingVertex(v))
+		}
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> AddEdge for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		match self.cell_mut(source, sink)
+		{
+			Some(edges) if MULTI || edges.is_empty() =>
+			{
+				edges.push(weight);
+				self.edge_count += 1;
+				Ok(())
+			},
+			_ => Err(GraphError::Unsupported),
+		}
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> RemoveEdge for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		let edges = self
+			.cell_mut(source, sink)
+			.ok_or(GraphError::MissingEdge(source, sink))?;
+		let idx = edges
+			.iter()
+			.position(f)
+			.ok_or(GraphError::MissingEdge(source, sink))?;
+		let w = edges.remove(idx);
+		self.edge_count -= 1;
+		Ok(w)
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> VertexCount for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertices.len()
+	}
+}
+
+impl<Vw, Ew, D, const MULTI: bool> EdgeCount for AdjMatrixGraph<Vw, Ew, D, MULTI>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.edge_count
+	}
+}
+
+impl<Vw, Ew, D> Unique for AdjMatrixGraph<Vw, Ew, D, false> where D: Directedness {}
+
+base_graph! {
+	use<Vw, Ew, D; const MULTI: bool> AdjMatrixGraph<Vw, Ew, D, MULTI>
+	where D: Directedness,
+}
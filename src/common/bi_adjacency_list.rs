@@ -0,0 +1,444 @@
+use crate::core::{
+	property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
+	Directed, Directedness, Graph, GraphError, GraphMut,
+};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A graph using an adjacency list representation that stores both the
+/// outgoing and incoming edges of each vertex.
+///
+/// All edges are kept in one list, with each vertex storing the indices of the
+/// edges sourced and sinked in it. This lets all the neighborhood methods of
+/// [Graph](../core/trait.Graph.html) (e.g.
+/// [edges_sinked_in](../core/trait.Graph.html#method.edges_sinked_in))
+/// run in time proportional to the degree of the vertex, instead of the number
+/// of vertices in the graph.
+///
+/// Like [AdjListGraph](struct.AdjListGraph.html), removing a vertex shifts
+/// the indices of all vertices after it.
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+#[derive(Clone, Debug)]
+pub struct BiAdjListGraph<Vw, Ew, D = Directed>
+where
+	D: Directedness,
+{
+	/// The vertices of the graph.
+	/// Each index in vec is a vertex.
+	vertices: Vec<(
+		// The weight of the vertex
+		Vw,
+		// The indices of the edges sourced in this vertex
+		Vec<usize>,
+		// The indices of the edges sinked in this vertex
+		Vec<usize>,
+	)>,
+	/// The edges of the graph as (source, sink, weight).
+	/// Removed edges are `None` until their index is reused.
+	edges: Vec<Option<(usize, usize, Ew)>>,
+	/// The indices of removed edges in `edges`.
+	free: Vec<usize>,
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D> BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// Constructs a new, empty `BiAdjListGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertices: Vec::new(),
+			edges: Vec::new(),
+			free: Vec::new(),
+			phantom: PhantomData,
+		}
+	}
+
+	fn edge(&self, idx: usize) -> &(usize, usize, Ew)
+	{
+		self.edges[idx].as_ref().unwrap()
+	}
+
+	/// The indices of the edges sourced in the given vertex.
+	fn outgoing(&self, v: usize) -> &[usize]
+	{
+		self.vertices.get(v).map_or(&[], |(_, out, _)| out)
+	}
+
+	/// The indices of the edges sinked in the given vertex.
+	fn incoming(&self, v: usize) -> &[usize]
+	{
+		self.vertices.get(v).map_or(&[], |(_, _, inc)| inc)
+	}
+
+	/// Returns the neighbor and index of every edge incident on the given
+	/// vertex, counting loops once.
+	fn incident(&self, v: usize) -> impl Iterator<Item = (usize, usize)> + '_
+	{
+		self.outgoing(v)
+			.iter()
+			.map(move |&idx| (self.edge(idx).1, idx))
+			.chain(self.incoming(v).iter().filter_map(move |&idx| {
+				let (so, si, _) = self.edge(idx);
+				(so != si).then_some((*so, idx))
+			}))
+	}
+
+	/// Returns the indices of the edges between the given vertices.
+	fn between(&self, source: usize, sink: usize) -> impl Iterator<Item = usize> + '_
+	{
+		// Scan the shortest list that contains all the edges
+		let (outgoing, incoming) = if D::directed()
+		{
+			if self.outgoing(source).len() <= self.incoming(sink).len()
+			{
+				(self.outgoing(source), &[][..])
+			}
+			else
+			{
+				(&[][..], self.incoming(sink))
+			}
+		}
+		else
+		{
+			let degree = |v| self.outgoing(v).len() + self.incoming(v).len();
+			let v = if degree(source) <= degree(sink)
+			{
+				source
+			}
+			else
+			{
+				sink
+			};
+			(self.outgoing(v), self.incoming(v))
+		};
+
+		outgoing
+			.iter()
+			.chain(incoming.iter().filter(move |&&idx| {
+				// In undirected graphs, loops are also in the outgoing list
+				let (so, si, _) = self.edge(idx);
+				D::directed() || so != si
+			}))
+			.copied()
+			.filter(move |&idx| {
+				let (so, si, _) = self.edge(idx);
+				(*so == source && *si == sink) || (!D::directed() && *so == sink && *si == source)
+			})
+	}
+
+	/// Removes the edge with the given index, returning its weight.
+	fn remove_edge_at(&mut self, idx: usize) -> Ew
+	{
+		let (so, si, w) = self.edges[idx].take().unwrap();
+		let out = &mut self.vertices[so].1;
+		out.remove(out.iter().position(|&i| i == idx).unwrap());
+		let inc = &mut self.vertices[si].2;
+		inc.remove(inc.iter().position(|&i| i == idx).unwrap());
+		self.free.push(idx);
+		w
+	}
+}
+
+impl<Vw, Ew, D> Default for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<Vw, Ew, D> Graph for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type EdgeWeightRef<'a>
+		= &'a Self::EdgeWeight
+	where
+		Self: 'a;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.vertices
+			.iter()
+			.enumerate()
+			.map(|(v, (w, _, _))| (v, w))
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		self.between(*source.borrow(), *sink.borrow())
+			.map(|idx| &self.edge(idx).2)
+	}
+
+	fn all_edges(
+		&self,
+	) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.edges.iter().flatten().map(|(so, si, w)| (*so, *si, w))
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices.get(*v.borrow()).map(|(w, _, _)| w)
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		*v.borrow() < self.vertices.len()
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		let incoming = if D::directed()
+		{
+			&[][..]
+		}
+		else
+		{
+			self.incoming(v)
+		};
+
+		self.outgoing(v)
+			.iter()
+			.map(move |&idx| (self.edge(idx).1, &self.edge(idx).2))
+			.chain(incoming.iter().filter_map(move |&idx| {
+				let (so, si, w) = self.edge(idx);
+				(so != si).then_some((*so, w))
+			}))
+	}
+
+	fn edges_sinked_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		let outgoing = if D::directed()
+		{
+			&[][..]
+		}
+		else
+		{
+			self.outgoing(v)
+		};
+
+		self.incoming(v)
+			.iter()
+			.map(move |&idx| (self.edge(idx).0, &self.edge(idx).2))
+			.chain(outgoing.iter().filter_map(move |&idx| {
+				let (so, si, w) = self.edge(idx);
+				(so != si).then_some((*si, w))
+			}))
+	}
+
+	fn edges_incident_on(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.incident(*v.borrow())
+			.map(|(other, idx)| (other, &self.edge(idx).2))
+	}
+
+	fn vertex_neighbors(&self, v: impl Borrow<Self::Vertex>) -> impl Iterator<Item = Self::Vertex>
+	{
+		let mut neighbors: Vec<_> = self.incident(*v.borrow()).map(|(n, _)| n).collect();
+		neighbors.sort_unstable();
+		neighbors.dedup();
+		neighbors.into_iter()
+	}
+}
+
+impl<Vw, Ew, D> GraphMut for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		self.vertices
+			.iter_mut()
+			.enumerate()
+			.map(|(v, (w, _, _))| (v, w))
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		let mut found: Vec<_> = self.between(*source.borrow(), *sink.borrow()).collect();
+		found.sort_unstable();
+
+		// Split off each found edge in order, so they can all be borrowed mutably
+		let mut rest = &mut self.edges[..];
+		let mut offset = 0;
+		found.into_iter().map(move |idx| {
+			let (_, tail) = std::mem::take(&mut rest).split_at_mut(idx - offset);
+			let (edge, tail) = tail.split_first_mut().unwrap();
+			rest = tail;
+			offset = idx + 1;
+			&mut edge.as_mut().unwrap().2
+		})
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices.get_mut(*v.borrow()).map(|(w, _, _)| w)
+	}
+}
+
+impl<Vw, Ew, D> NewVertex for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let new_v = self.vertices.len();
+		self.vertices.push((w, Vec::new(), Vec::new()));
+		Ok(new_v)
+	}
+}
+
+impl<Vw, Ew, D> RemoveVertex for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		if v < self.vertices.len()
+		{
+			let incident: Vec<_> = self.incident(v).map(|(_, idx)| idx).collect();
+			for idx in incident
+			{
+				self.remove_edge_at(idx);
+			}
+
+			// Shift the vertices after the removed one
+			for (so, si, _) in self.edges.iter_mut().flatten()
+			{
+				*so -= (*so > v) as usize;
+				*si -= (*si > v) as usize;
+			}
+			Ok(self.vertices.remove(v).0)
+		}
+		else
+		{
+			Err(GraphError::MissingVertex(v))
+		}
+	}
+}
+
+impl<Vw, Ew, D> AddEdge for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		GraphError::check_vertices(self, [source, sink])?;
+		let edge = Some((source, sink, weight));
+		let idx = if let Some(idx) = self.free.pop()
+		{
+			self.edges[idx] = edge;
+			idx
+		}
+		else
+		{
+			self.edges.push(edge);
+			self.edges.len() - 1
+		};
+		self.vertices[source].1.push(idx);
+		self.vertices[sink].2.push(idx);
+		Ok(())
+	}
+}
+
+impl<Vw, Ew, D> RemoveEdge for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		let found = self.between(source, sink).find(|&idx| f(&self.edge(idx).2));
+
+		found
+			.map(|idx| self.remove_edge_at(idx))
+			.ok_or(GraphError::MissingEdge(source, sink))
+	}
+}
+
+impl<Vw, Ew, D> VertexCount for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertices.len()
+	}
+}
+
+impl<Vw, Ew, D> EdgeCount for BiAdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.edges.len() - self.free.len()
+	}
+}
+
+base_graph! {
+	use<Vw, Ew, D> BiAdjListGraph<Vw, Ew, D>
+	where D: Directedness,
+}
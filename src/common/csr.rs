@@ -0,0 +1,366 @@
+use crate::core::{
+	property::{EdgeCount, VertexCount},
+	Directed, Directedness, Graph, GraphMut,
+};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, marker::PhantomData, ops::Range};
+
+/// A read-only graph using the [compressed sparse row](https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format))
+/// representation.
+///
+/// All edges are stored in flat arrays, sorted by their source and then by
+/// their sink. The outgoing edges of vertex `v` are at the indices
+/// `offsets[v]..offsets[v+1]` of `sinks` and `edge_weights`.
+/// The incoming edges are indexed the same way, using `in_offsets` and
+/// `in_edges`, the latter containing the indices of the edges in `sinks`.
+///
+/// This layout makes the graph compact and cache-friendly, at the cost of not
+/// supporting the addition or removal of vertices and edges.
+/// Vertex and edge weights can still be mutated through
+/// [`GraphMut`](../core/trait.GraphMut.html).
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+#[derive(Clone, Debug)]
+pub struct CsrGraph<Vw, Ew, D = Directed>
+where
+	D: Directedness,
+{
+	/// The weight of each vertex. Each index is a vertex.
+	vertex_weights: Vec<Vw>,
+	/// Where each vertex's outgoing edges start in `sinks`.
+	/// Has one more element than there are vertices.
+	offsets: Vec<usize>,
+	/// The sink of each edge.
+	sinks: Vec<usize>,
+	/// The weight of each edge.
+	edge_weights: Vec<Ew>,
+	/// Where each vertex's incoming edges start in `in_edges`.
+	/// Has one more element than there are vertices.
+	in_offsets: Vec<usize>,
+	/// The index (in `sinks`) of each incoming edge.
+	in_edges: Vec<usize>,
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D> CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// Constructs a new, empty `CsrGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertex_weights: Vec::new(),
+			offsets: vec![0],
+			sinks: Vec::new(),
+			edge_weights: Vec::new(),
+			in_offsets: vec![0],
+			in_edges: Vec::new(),
+			phantom: PhantomData,
+		}
+	}
+
+	/// Constructs a copy of the given graph.
+	///
+	/// The vertices of the new graph are numbered in the order they are
+	/// returned by the given graph's
+	/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices).
+	/// I.e. the first vertex returned becomes `0`, the second `1`, etc.
+	pub fn from_graph<G>(graph: &G) -> Self
+	where
+		G: Graph<VertexWeight = Vw, EdgeWeight = Ew, Directedness = D>,
+		G::Vertex: Hash,
+		Vw: Clone,
+		Ew: Clone,
+	{
+		let mut vertex_weights = Vec::new();
+		let mut ids = HashMap::new();
+		for (v, w) in graph.all_vertices_weighted()
+		{
+			ids.insert(v, vertex_weights.len());
+			vertex_weights.push(w.clone());
+		}
+
+		let mut edges: Vec<_> = graph
+			.all_edges()
+			.map(|(so, si, w)| (ids[&so], ids[&si], (*w).clone()))
+			.collect();
+		edges.sort_by_key(|(so, si, _)| (*so, *si));
+
+		let v_count = vertex_weights.len();
+		let mut offsets = vec![0; v_count + 1];
+		let mut in_offsets = vec![0; v_count + 1];
+		for (so, si, _) in edges.iter()
+		{
+			offsets[so + 1] += 1;
+			in_offsets[si + 1] += 1;
+		}
+		for v in 0..v_count
+		{
+			offsets[v + 1] += offsets[v];
+			in_offsets[v + 1] += in_offsets[v];
+		}
+
+		// Since the edges are sorted by source, each vertex's incoming edges
+		// end up sorted by source too.
+		let mut in_edges = vec![0; edges.len()];
+		let mut next_in = in_offsets.clone();
+		let mut sinks = Vec::with_capacity(edges.len());
+		let mut edge_weights = Vec::with_capacity(edges.len());
+		for (idx, (_, si, w)) in edges.into_iter().enumerate()
+		{
+			in_edges[next_in[si]] = idx;
+			next_in[si] += 1;
+			sinks.push(si);
+			edge_weights.push(w);
+		}
+
+		Self {
+			vertex_weights,
+			offsets,
+			sinks,
+			edge_weights,
+			in_offsets,
+			in_edges,
+			phantom: PhantomData,
+		}
+	}
+
+	/// The indices of the outgoing edges of the given vertex.
+	///
+	/// Empty if the vertex isn't in the graph.
+	fn out_range(&self, v: usize) -> Range<usize>
+	{
+		if v < self.vertex_weights.len()
+		{
+			self.offsets[v]..self.offsets[v + 1]
+		}
+		else
+		{
+			0..0
+		}
+	}
+
+	/// The indices in `in_edges` of the incoming edges of the given vertex.
+	///
+	/// Empty if the vertex isn't in the graph.
+	fn in_range(&self, v: usize) -> Range<usize>
+	{
+		if v < self.vertex_weights.len()
+		{
+			self.in_offsets[v]..self.in_offsets[v + 1]
+		}
+		else
+		{
+			0..0
+		}
+	}
+
+	/// The indices of the edges sourced in `source` and sinked in `sink`.
+	fn range_between(&self, source: usize, sink: usize) -> Range<usize>
+	{
+		let range = self.out_range(source);
+		let row = &self.sinks[range.clone()];
+		(range.start + row.partition_point(|&si| si < sink))
+			..(range.start + row.partition_point(|&si| si <= sink))
+	}
+
+	/// The source of the edge with the given index.
+	fn source_of(&self, edge: usize) -> usize
+	{
+		self.offsets.partition_point(|&o| o <= edge) - 1
+	}
+}
+
+impl<Vw, Ew, D> Default for CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<Vw, Ew, D> Graph for CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type EdgeWeightRef<'a>
+		= &'a Self::EdgeWeight
+	where
+		Self: 'a;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.vertex_weights.iter().enumerate()
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		let reverse = if !D::directed() && source != sink
+		{
+			self.range_between(sink, source)
+		}
+		else
+		{
+			0..0
+		};
+
+		self.edge_weights[self.range_between(source, sink)]
+			.iter()
+			.chain(self.edge_weights[reverse].iter())
+	}
+
+	fn all_edges(
+		&self,
+	) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		(0..self.vertex_weights.len()).flat_map(move |so| {
+			self.out_range(so)
+				.map(move |idx| (so, self.sinks[idx], &self.edge_weights[idx]))
+		})
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertex_weights.get(*v.borrow())
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		let incoming = if D::directed()
+		{
+			0..0
+		}
+		else
+		{
+			self.in_range(v)
+		};
+
+		self.out_range(v)
+			.map(move |idx| (self.sinks[idx], &self.edge_weights[idx]))
+			.chain(incoming.filter_map(move |in_idx| {
+				let idx = self.in_edges[in_idx];
+				let source = self.source_of(idx);
+				// Loops have already been returned as outgoing edges
+				(source != v).then(|| (source, &self.edge_weights[idx]))
+			}))
+	}
+
+	fn edges_sinked_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		let v = *v.borrow();
+		let outgoing = if D::directed()
+		{
+			0..0
+		}
+		else
+		{
+			self.out_range(v)
+		};
+
+		self.in_range(v)
+			.map(move |in_idx| {
+				let idx = self.in_edges[in_idx];
+				(self.source_of(idx), &self.edge_weights[idx])
+			})
+			.chain(outgoing.filter_map(move |idx| {
+				let sink = self.sinks[idx];
+				// Loops have already been returned as incoming edges
+				(sink != v).then(|| (sink, &self.edge_weights[idx]))
+			}))
+	}
+}
+
+impl<Vw, Ew, D> GraphMut for CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		self.vertex_weights.iter_mut().enumerate()
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		let forward = self.range_between(source, sink);
+		let reverse = if !D::directed() && source != sink
+		{
+			self.range_between(sink, source)
+		}
+		else
+		{
+			forward.end..forward.end
+		};
+
+		// The two ranges are in different rows, so they never overlap.
+		let (first, second) = if forward.end <= reverse.start
+		{
+			(forward, reverse)
+		}
+		else
+		{
+			(reverse, forward)
+		};
+		let (low, high) = self.edge_weights.split_at_mut(second.start);
+		low[first].iter_mut().chain(high[..second.len()].iter_mut())
+	}
+}
+
+impl<Vw, Ew, D> VertexCount for CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertex_weights.len()
+	}
+}
+
+impl<Vw, Ew, D> EdgeCount for CsrGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.sinks.len()
+	}
+}
+
+base_graph! {
+	use<Vw, Ew, D> CsrGraph<Vw, Ew, D>
+	where D: Directedness,
+}
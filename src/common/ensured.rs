@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{NewVertex, VertexInGraph},
-	Ensure, Graph, GraphDerefMut,
+	Ensure, Graph, GraphDerefMut, GraphError,
 };
 
 pub trait Ensured: Ensure
@@ -36,13 +36,13 @@ where
 	pub fn new_vertex_weighted(
 		mut self,
 		w: <G::Graph as Graph>::VertexWeight,
-	) -> Result<VertexInGraph<G>, ()>
+	) -> Result<VertexInGraph<G>, GraphError<<G::Graph as Graph>::Vertex>>
 	{
 		let v = self.0.graph_mut().new_vertex_weighted(w)?;
 		Ok(VertexInGraph::ensure_unchecked(self.0, [v]))
 	}
 
-	pub fn new_vertex(self) -> Result<VertexInGraph<G>, ()>
+	pub fn new_vertex(self) -> Result<VertexInGraph<G>, GraphError<<G::Graph as Graph>::Vertex>>
 	where
 		<G::Graph as Graph>::VertexWeight: Default,
 	{
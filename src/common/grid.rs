@@ -0,0 +1,362 @@
+use crate::core::{
+	property::{EdgeCount, VertexCount},
+	Graph, GraphError, GraphMut, Undirected,
+};
+use std::borrow::Borrow;
+
+/// Which cells of a [GridGraph](struct.GridGraph.html) are neighbors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity
+{
+	/// Cells are neighbors if they differ by 1 in exactly one coordinate.
+	///
+	/// In 2 dimensions this is the 4-neighborhood.
+	Orthogonal,
+	/// Cells are neighbors if they differ by at most 1 in every coordinate.
+	///
+	/// In 2 dimensions this is the 8-neighborhood.
+	Full,
+}
+
+/// An undirected graph whose vertices are the cells of an N-dimensional grid.
+///
+/// Each vertex is the coordinates of a cell, and neighboring cells are
+/// connected by an edge, as given by the grid's
+/// [Connectivity](enum.Connectivity.html). Edges are not stored but computed
+/// when needed, and have no weight.
+///
+/// Cells can be blocked, which removes them (and their edges) from the graph
+/// while keeping the coordinates of the other cells unchanged.
+///
+/// If the grid wraps, cells on opposite borders are neighbors, making the
+/// grid a torus. Two cells are connected by at most one edge, even if they
+/// are neighbors in more than one way, and no cell is its own neighbor.
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights. Defaults to `()`.
+/// - `N`: The number of dimensions. Defaults to 2.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::common::{Connectivity, GridGraph};
+/// # use graphene::core::{Graph, property::{EdgeCount, VertexCount}};
+/// let mut grid = GridGraph::<()>::new([3, 3], Connectivity::Orthogonal);
+/// assert_eq!(grid.vertex_count(), 9);
+/// assert_eq!(grid.edge_count(), 12);
+///
+/// grid.block([1, 1]);
+/// assert!(!grid.contains_vertex([1, 1]));
+/// assert_eq!(grid.edge_count(), 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GridGraph<Vw = (), const N: usize = 2>
+{
+	/// The size of the grid in each dimension.
+	dimensions: [usize; N],
+	connectivity: Connectivity,
+	wrap: bool,
+	/// The weight of each cell, with the last coordinate varying fastest.
+	/// Blocked cells are `None`.
+	cells: Vec<Option<Vw>>,
+	/// The weight of every edge.
+	///
+	/// Edges aren't stored, but share this weight, so it can be borrowed
+	/// mutably. There is at most one edge between two cells, so it is never
+	/// borrowed more than once at a time.
+	edge_weight: (),
+}
+
+impl<Vw, const N: usize> GridGraph<Vw, N>
+{
+	/// Constructs a grid of the given size where no cell is blocked and the
+	/// grid doesn't wrap.
+	///
+	/// All cells get the default weight.
+	pub fn new(dimensions: [usize; N], connectivity: Connectivity) -> Self
+	where
+		Vw: Default,
+	{
+		Self::from_fn(dimensions, connectivity, |_| Vw::default())
+	}
+
+	/// Constructs a grid of the given size where no cell is blocked and the
+	/// grid doesn't wrap.
+	///
+	/// The weight of each cell is given by calling the given function with
+	/// its coordinates.
+	pub fn from_fn(
+		dimensions: [usize; N],
+		connectivity: Connectivity,
+		mut weight: impl FnMut([usize; N]) -> Vw,
+	) -> Self
+	{
+		let count = dimensions.iter().product();
+		Self {
+			dimensions,
+			connectivity,
+			wrap: false,
+			cells: (0..count)
+				.map(|idx| Some(weight(Self::coordinates(&dimensions, idx))))
+				.collect(),
+			edge_weight: (),
+		}
+	}
+
+	/// Returns the size of the grid in each dimension.
+	pub fn dimensions(&self) -> [usize; N]
+	{
+		self.dimensions
+	}
+
+	/// Returns whether cells on opposite borders of the grid are neighbors.
+	pub fn wraps(&self) -> bool
+	{
+		self.wrap
+	}
+
+	/// Sets whether cells on opposite borders of the grid are neighbors.
+	pub fn set_wrap(&mut self, wrap: bool)
+	{
+		self.wrap = wrap;
+	}
+
+	/// Blocks the given cell, removing it from the graph.
+	///
+	/// Returns the weight of the cell, or `None` if it is outside the grid or
+	/// already blocked.
+	pub fn block(&mut self, cell: impl Borrow<[usize; N]>) -> Option<Vw>
+	{
+		let idx = self.index(cell.borrow())?;
+		self.cells[idx].take()
+	}
+
+	/// Unblocks the given cell, giving it the given weight.
+	///
+	/// Fails if the cell is outside the grid or isn't blocked.
+	pub fn unblock(
+		&mut self,
+		cell: impl Borrow<[usize; N]>,
+		weight: Vw,
+	) -> Result<(), GraphError<[usize; N]>>
+	{
+		let cell = *cell.borrow();
+		match self.index(&cell).map(|idx| &mut self.cells[idx])
+		{
+			Some(entry @ None) =>
+			{
+				*entry = Some(weight);
+				Ok(())
+			},
+			Some(Some(_)) => Err(GraphError::DuplicateVertex(cell)),
+			None => Err(GraphError::MissingVertex(cell)),
+		}
+	}
+
+	/// Returns whether the given cell is blocked.
+	///
+	/// Cells outside the grid are not blocked.
+	pub fn is_blocked(&self, cell: impl Borrow<[usize; N]>) -> bool
+	{
+		self.index(cell.borrow())
+			.is_some_and(|idx| self.cells[idx].is_none())
+	}
+
+	/// Returns the index in `cells` of the given cell, if it is in the grid.
+	fn index(&self, cell: &[usize; N]) -> Option<usize>
+	{
+		cell.iter()
+			.zip(self.dimensions.iter())
+			.try_fold(0, |idx, (&c, &d)| (c < d).then_some(idx * d + c))
+	}
+
+	/// Returns the coordinates of the cell with the given index in `cells` of
+	/// a grid with the given dimensions.
+	fn coordinates(dimensions: &[usize; N], mut idx: usize) -> [usize; N]
+	{
+		let mut cell = [0; N];
+		for (c, &d) in cell.iter_mut().zip(dimensions.iter()).rev()
+		{
+			*c = idx % d;
+			idx /= d;
+		}
+		cell
+	}
+
+	/// Returns the unblocked neighbors of the given cell, each only once.
+	///
+	/// Returns nothing if the cell is blocked or not in the grid.
+	fn neighbors_of(&self, cell: &[usize; N]) -> Vec<[usize; N]>
+	{
+		let mut neighbors = Vec::new();
+		if self
+			.index(cell)
+			.and_then(|idx| self.cells[idx].as_ref())
+			.is_none()
+		{
+			return neighbors;
+		}
+
+		// Each offset is a digit in base 3 per dimension: 0 => -1, 1 => 0, 2 => +1
+		'offsets: for offset in 0..3usize.pow(N as u32)
+		{
+			let mut neighbor = *cell;
+			let mut rest = offset;
+			let mut changed = 0;
+			for (c, &d) in neighbor.iter_mut().zip(self.dimensions.iter())
+			{
+				let digit = rest % 3;
+				rest /= 3;
+				if digit == 1
+				{
+					continue;
+				}
+				changed += 1;
+				*c = match (digit, *c)
+				{
+					(0, 0) if self.wrap => d - 1,
+					(0, 0) => continue 'offsets,
+					(0, c) => c - 1,
+					(_, c) if c + 1 < d => c + 1,
+					_ if self.wrap => 0,
+					_ => continue 'offsets,
+				};
+			}
+
+			let allowed = match self.connectivity
+			{
+				Connectivity::Orthogonal => changed == 1,
+				Connectivity::Full => changed > 0,
+			};
+			if allowed
+				&& neighbor != *cell
+				&& !neighbors.contains(&neighbor)
+				&& !self.is_blocked(neighbor)
+			{
+				neighbors.push(neighbor);
+			}
+		}
+		neighbors
+	}
+}
+
+impl<Vw, const N: usize> Graph for GridGraph<Vw, N>
+{
+	type Directedness = Undirected;
+	type EdgeWeight = ();
+	type EdgeWeightRef<'a>
+		= &'a ()
+	where
+		Self: 'a;
+	type Vertex = [usize; N];
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.cells.iter().enumerate().filter_map(|(idx, w)| {
+			w.as_ref()
+				.map(|w| (Self::coordinates(&self.dimensions, idx), w))
+		})
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		self.neighbors_of(source.borrow())
+			.contains(sink.borrow())
+			.then_some(&())
+			.into_iter()
+	}
+
+	fn all_edges(
+		&self,
+	) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		// Each edge is returned from the cell with the lowest index
+		self.all_vertices().flat_map(move |v| {
+			self.neighbors_of(&v)
+				.into_iter()
+				.filter(move |n| self.index(n) > self.index(&v))
+				.map(move |n| (v, n, &()))
+		})
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.index(v.borrow())
+			.and_then(|idx| self.cells[idx].as_ref())
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.neighbors_of(v.borrow()).into_iter().map(|n| (n, &()))
+	}
+
+	fn edges_sinked_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.edges_sourced_in(v)
+	}
+}
+
+impl<Vw, const N: usize> GraphMut for GridGraph<Vw, N>
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		let dimensions = self.dimensions;
+		self.cells
+			.iter_mut()
+			.enumerate()
+			.filter_map(move |(idx, w)| {
+				w.as_mut().map(|w| (Self::coordinates(&dimensions, idx), w))
+			})
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		self.neighbors_of(source.borrow())
+			.contains(sink.borrow())
+			.then_some(&mut self.edge_weight)
+			.into_iter()
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.index(v.borrow())
+			.and_then(|idx| self.cells[idx].as_mut())
+	}
+}
+
+impl<Vw, const N: usize> VertexCount for GridGraph<Vw, N>
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.cells.iter().filter(|w| w.is_some()).count()
+	}
+}
+
+impl<Vw, const N: usize> EdgeCount for GridGraph<Vw, N>
+{
+	type Count = usize;
+}
+
+base_graph! {
+	use<Vw; const N: usize> GridGraph<Vw, N>
+}
@@ -0,0 +1,162 @@
+use crate::core::{
+	property::{HasVertex, VertexIn},
+	Directed, Graph, Owned,
+};
+use std::{
+	borrow::Borrow,
+	collections::{HashSet, VecDeque},
+	hash::Hash,
+	marker::PhantomData,
+};
+
+/// A directed graph whose edges are produced on demand by a function.
+///
+/// The graph is defined by a root vertex and a function that, given a vertex,
+/// returns the sink and weight of each edge sourced in it.
+/// The vertices of the graph are those reachable from the root.
+/// Nothing is stored, so the graph may be arbitrarily large, or even infinite.
+///
+/// The root is the graph's [VertexIn<1>](../core/property/trait.VertexIn.html),
+/// so the graph can be searched directly by e.g.
+/// [Bfs](../algo/struct.Bfs.html) or
+/// [DijkstraShortestPaths](../algo/struct.DijkstraShortestPaths.html).
+///
+/// [`edges_sourced_in`](../core/trait.Graph.html#method.edges_sourced_in)
+/// calls the function once. All other methods of
+/// [Graph](../core/trait.Graph.html) must explore the graph from the root,
+/// and therefore never finish if the graph is infinite. E.g.
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices) will
+/// keep returning vertices, while
+/// [`contains_vertex`](../core/trait.Graph.html#method.contains_vertex) will
+/// only finish if the vertex is in the graph.
+///
+/// Vertices have no weight and edge weights are returned by value.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{algo::{Bfs, Retainable}, common::ImplicitGraph};
+/// // Starting at 1, we can either add 1 or double the number.
+/// let graph = ImplicitGraph::new(1u64, |v: u64| [(v + 1, ()), (v * 2, ())]);
+///
+/// // The graph is infinite, but we only explore until we find 100.
+/// let mut bfs = Bfs::new(&graph).retain(&graph);
+/// assert!(bfs.find(|&v| v == 100).is_some());
+/// assert_eq!(bfs.algo.depth(100), 8);
+/// ```
+pub struct ImplicitGraph<V, W, F>
+{
+	root: V,
+	edges_of: F,
+	phantom: PhantomData<W>,
+}
+
+impl<V, W, F, I> ImplicitGraph<V, W, F>
+where
+	F: Fn(V) -> I,
+	I: IntoIterator<Item = (V, W)>,
+{
+	/// Constructs a graph containing the given root vertex and all vertices
+	/// reachable from it.
+	///
+	/// `edges_of` is given a vertex and must return the sink and weight of
+	/// each edge sourced in it. It should return the same edges each time
+	/// it is called with the same vertex.
+	pub fn new(root: V, edges_of: F) -> Self
+	{
+		Self {
+			root,
+			edges_of,
+			phantom: PhantomData,
+		}
+	}
+}
+
+impl<V, W, F, I> Graph for ImplicitGraph<V, W, F>
+where
+	V: Copy + Eq + Hash,
+	F: Fn(V) -> I,
+	I: IntoIterator<Item = (V, W)>,
+{
+	type Directedness = Directed;
+	type EdgeWeight = W;
+	type EdgeWeightRef<'a>
+		= Owned<W>
+	where
+		Self: 'a;
+	type Vertex = V;
+	type VertexWeight = ();
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		let mut visited = HashSet::new();
+		let mut queue = VecDeque::new();
+		visited.insert(self.root);
+		queue.push_back(self.root);
+
+		std::iter::from_fn(move || {
+			let v = queue.pop_front()?;
+			for (sink, _) in (self.edges_of)(v)
+			{
+				if visited.insert(sink)
+				{
+					queue.push_back(sink);
+				}
+			}
+			Some((v, &()))
+		})
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		let sink = *sink.borrow();
+		self.edges_sourced_in(source)
+			.filter_map(move |(si, w)| (si == sink).then_some(w))
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		(self.edges_of)(*v.borrow())
+			.into_iter()
+			.map(|(sink, w)| (sink, Owned(w)))
+	}
+}
+
+impl<V, W, F, I> HasVertex for ImplicitGraph<V, W, F>
+where
+	V: Copy + Eq + Hash,
+	F: Fn(V) -> I,
+	I: IntoIterator<Item = (V, W)>,
+{
+	fn any_vertex(&self) -> Self::Vertex
+	{
+		self.root
+	}
+}
+
+impl<V, W, F, I> VertexIn<1> for ImplicitGraph<V, W, F>
+where
+	V: Copy + Eq + Hash,
+	F: Fn(V) -> I,
+	I: IntoIterator<Item = (V, W)>,
+{
+	fn vertex_at_idx(&self, _: usize) -> Self::Vertex
+	{
+		self.root
+	}
+}
+
+base_graph! {
+	use<V, W, F, I> ImplicitGraph<V, W, F>
+	where
+		V: Copy + Eq + Hash,
+		F: Fn(V) -> I,
+		I: IntoIterator<Item = (V, W)>,
+}
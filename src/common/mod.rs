@@ -1,7 +1,17 @@
 //! Contains common graph implementations.
 
 mod adjacency_list;
+mod adjacency_matrix;
+mod bi_adjacency_list;
+mod csr;
 mod ensured;
+mod grid;
+mod implicit;
+mod persistent;
+mod shared;
 mod vertex_map;
 
-pub use self::{adjacency_list::*, ensured::*, vertex_map::*};
+pub use self::{
+	adjacency_list::*, adjacency_matrix::*, bi_adjacency_list::*, csr::*, ensured::*, grid::*,
+	implicit::*, persistent::*, shared::*, vertex_map::*,
+};
@@ -0,0 +1,591 @@
+use crate::core::{
+	property::{
+		AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, StableRemoveVertex, VertexCount,
+	},
+	Directed, Directedness, Graph, GraphError, GraphMut,
+};
+use std::{borrow::Borrow, marker::PhantomData, sync::Arc};
+
+/// The number of bits of an index used to choose a child at each level of a
+/// `PersistentVec`.
+const BITS: usize = 5;
+/// The maximum number of children of each node of a `PersistentVec`.
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+#[derive(Clone, Debug)]
+enum Node<T>
+{
+	Branch(Vec<Arc<Node<T>>>),
+	Leaf(Vec<T>),
+}
+
+/// A vector that can be cloned in constant time.
+///
+/// The elements are stored in the leaves of a tree where all leaves are at the
+/// same depth. Clones share the tree, and a node is only copied when it is
+/// changed while shared, so a change copies at most one node per level.
+#[derive(Debug)]
+struct PersistentVec<T>
+{
+	root: Arc<Node<T>>,
+	/// The number of levels of branches above the leaves.
+	height: usize,
+	len: usize,
+}
+
+impl<T> PersistentVec<T>
+{
+	fn new() -> Self
+	{
+		Self {
+			root: Arc::new(Node::Leaf(Vec::new())),
+			height: 0,
+			len: 0,
+		}
+	}
+
+	fn len(&self) -> usize
+	{
+		self.len
+	}
+
+	fn get(&self, idx: usize) -> Option<&T>
+	{
+		if idx >= self.len
+		{
+			return None;
+		}
+		let mut node = &*self.root;
+		for level in (1..=self.height).rev()
+		{
+			node = match node
+			{
+				Node::Branch(children) => &children[(idx >> (level * BITS)) & MASK],
+				Node::Leaf(_) => unreachable!(),
+			};
+		}
+		match node
+		{
+			Node::Leaf(items) => items.get(idx & MASK),
+			Node::Branch(_) => unreachable!(),
+		}
+	}
+
+	fn iter(&self) -> impl Iterator<Item = &T>
+	{
+		(0..self.len).map(|idx| self.get(idx).unwrap())
+	}
+}
+
+impl<T: Clone> PersistentVec<T>
+{
+	fn get_mut(&mut self, idx: usize) -> Option<&mut T>
+	{
+		if idx >= self.len
+		{
+			return None;
+		}
+		let mut node = Arc::make_mut(&mut self.root);
+		for level in (1..=self.height).rev()
+		{
+			node = match node
+			{
+				Node::Branch(children) =>
+				{
+					Arc::make_mut(&mut children[(idx >> (level * BITS)) & MASK])
+				},
+				Node::Leaf(_) => unreachable!(),
+			};
+		}
+		match node
+		{
+			Node::Leaf(items) => items.get_mut(idx & MASK),
+			Node::Branch(_) => unreachable!(),
+		}
+	}
+
+	fn push(&mut self, item: T)
+	{
+		// If the tree is full, it becomes the first child of a new root
+		if self.len == WIDTH << (self.height * BITS)
+		{
+			let old_root = std::mem::replace(&mut self.root, Arc::new(Node::Branch(Vec::new())));
+			self.root = Arc::new(Node::Branch(vec![old_root]));
+			self.height += 1;
+		}
+
+		let idx = self.len;
+		let mut node = Arc::make_mut(&mut self.root);
+		for level in (1..=self.height).rev()
+		{
+			node = match node
+			{
+				Node::Branch(children) =>
+				{
+					let child = (idx >> (level * BITS)) & MASK;
+					if child == children.len()
+					{
+						children.push(Arc::new(
+							if level == 1
+							{
+								Node::Leaf(Vec::new())
+							}
+							else
+							{
+								Node::Branch(Vec::new())
+							},
+						));
+					}
+					Arc::make_mut(&mut children[child])
+				},
+				Node::Leaf(_) => unreachable!(),
+			};
+		}
+		match node
+		{
+			Node::Leaf(items) => items.push(item),
+			Node::Branch(_) => unreachable!(),
+		}
+		self.len += 1;
+	}
+
+	fn iter_mut(&mut self) -> impl Iterator<Item = &mut T>
+	{
+		let mut items = Vec::with_capacity(self.len);
+		Self::collect_mut(&mut self.root, &mut items);
+		items.into_iter()
+	}
+
+	fn collect_mut<'a>(node: &'a mut Arc<Node<T>>, items: &mut Vec<&'a mut T>)
+	{
+		match Arc::make_mut(node)
+		{
+			Node::Branch(children) =>
+			{
+				for child in children.iter_mut()
+				{
+					Self::collect_mut(child, items);
+				}
+			},
+			Node::Leaf(leaf_items) => items.extend(leaf_items.iter_mut()),
+		}
+	}
+}
+
+impl<T> Clone for PersistentVec<T>
+{
+	fn clone(&self) -> Self
+	{
+		Self {
+			root: self.root.clone(),
+			height: self.height,
+			len: self.len,
+		}
+	}
+}
+
+/// A vertex in a [PersistentGraph](struct.PersistentGraph.html).
+type PersistentEntry<Vw, Ew> = (
+	// The weight of the vertex
+	Vw,
+	// A list of the edges stored in this vertex
+	Vec<(
+		// The index of the sink vertex
+		usize,
+		// The weight of the edge
+		Ew,
+	)>,
+);
+
+/// A persistent graph, where old versions remain available after changes.
+///
+/// Cloning the graph takes constant time, after which the clones share all
+/// their vertices and edges. Changing a clone only copies the parts of the
+/// graph that are changed, leaving the other clones as they were.
+/// Changing a vertex or an edge copies the vertex (with the edges stored in
+/// it) and a logarithmic number of internal nodes.
+/// Therefore, keeping many versions of a graph that differ in a few places is
+/// cheap.
+///
+/// Like [StableAdjListGraph](struct.StableAdjListGraph.html), removing a vertex
+/// doesn't change the ids of other vertices, and the id of a removed vertex is
+/// never given to a new vertex.
+///
+/// Besides the usual mutating traits, methods like
+/// [`with_edge`](#method.with_edge) return a changed version of the graph
+/// without changing the original.
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::common::PersistentGraph;
+/// # use graphene::core::{Graph, property::{EdgeCount, NewVertex}};
+/// let mut base = PersistentGraph::<(), ()>::new();
+/// let v1 = base.new_vertex().unwrap();
+/// let v2 = base.new_vertex().unwrap();
+///
+/// let branch = base.with_edge(v1, v2, ()).unwrap();
+/// assert_eq!(branch.edge_count(), 1);
+/// assert_eq!(base.edge_count(), 0);
+/// ```
+#[derive(Debug)]
+pub struct PersistentGraph<Vw, Ew, D = Directed>
+where
+	D: Directedness,
+{
+	/// Each index is a vertex. Removed vertices are `None`.
+	///
+	/// In directed graphs, edges are stored in their source. In undirected
+	/// graphs, edges are stored in the vertex with the lowest id.
+	vertices: PersistentVec<Option<Arc<PersistentEntry<Vw, Ew>>>>,
+	/// The number of vertices that haven't been removed.
+	vertex_count: usize,
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D> PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// Constructs a new, empty `PersistentGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertices: PersistentVec::new(),
+			vertex_count: 0,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns the vertex that stores the edges between the given vertices,
+	/// followed by the sink of those edges as stored.
+	fn stored_in(source: usize, sink: usize) -> (usize, usize)
+	{
+		if D::directed() || source <= sink
+		{
+			(source, sink)
+		}
+		else
+		{
+			(sink, source)
+		}
+	}
+
+	fn entry(&self, v: usize) -> Option<&PersistentEntry<Vw, Ew>>
+	{
+		self.vertices.get(v).and_then(Option::as_deref)
+	}
+}
+
+impl<Vw, Ew, D> PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn entry_mut(&mut self, v: usize) -> Option<&mut PersistentEntry<Vw, Ew>>
+	{
+		self.vertices
+			.get_mut(v)
+			.and_then(Option::as_mut)
+			.map(Arc::make_mut)
+	}
+
+	/// Returns a version of the graph with a new vertex with the given weight,
+	/// together with the new vertex.
+	pub fn with_vertex(&self, w: Vw) -> (Self, usize)
+	{
+		let mut result = self.clone();
+		let v = result.new_vertex_weighted(w).unwrap();
+		(result, v)
+	}
+
+	/// Returns a version of the graph without the given vertex and its edges.
+	///
+	/// Fails if the vertex isn't in the graph.
+	pub fn without_vertex(&self, v: impl Borrow<usize>) -> Result<Self, GraphError<usize>>
+	{
+		let mut result = self.clone();
+		result.remove_vertex(v)?;
+		Ok(result)
+	}
+
+	/// Returns a version of the graph with the given edge added.
+	///
+	/// Fails if either vertex isn't in the graph.
+	pub fn with_edge(
+		&self,
+		source: impl Borrow<usize>,
+		sink: impl Borrow<usize>,
+		weight: Ew,
+	) -> Result<Self, GraphError<usize>>
+	{
+		let mut result = self.clone();
+		result.add_edge_weighted(source, sink, weight)?;
+		Ok(result)
+	}
+
+	/// Returns a version of the graph with one edge between the given vertices
+	/// removed.
+	///
+	/// Fails if there is no such edge.
+	pub fn without_edge(
+		&self,
+		source: impl Borrow<usize>,
+		sink: impl Borrow<usize>,
+	) -> Result<Self, GraphError<usize>>
+	{
+		let mut result = self.clone();
+		result.remove_edge(source, sink)?;
+		Ok(result)
+	}
+}
+
+impl<Vw, Ew, D> Clone for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn clone(&self) -> Self
+	{
+		Self {
+			vertices: self.vertices.clone(),
+			vertex_count: self.vertex_count,
+			phantom: PhantomData,
+		}
+	}
+}
+
+impl<Vw, Ew, D> Default for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<Vw, Ew, D> Graph for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type EdgeWeightRef<'a>
+		= &'a Self::EdgeWeight
+	where
+		Self: 'a;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
+	{
+		self.vertices
+			.iter()
+			.enumerate()
+			.filter_map(|(v, entry)| entry.as_ref().map(|entry| (v, &entry.0)))
+	}
+
+	fn edges_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>
+	{
+		let (so, si) = Self::stored_in(*source.borrow(), *sink.borrow());
+		self.entry(so)
+			.into_iter()
+			.flat_map(|(_, edges)| edges.iter())
+			.filter_map(move |(s, w)| (*s == si).then_some(w))
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.entry(*v.borrow()).map(|(w, _)| w)
+	}
+}
+
+impl<Vw, Ew, D> GraphMut for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
+	{
+		self.vertices
+			.iter_mut()
+			.enumerate()
+			.filter_map(|(v, entry)| entry.as_mut().map(|entry| (v, &mut Arc::make_mut(entry).0)))
+	}
+
+	fn edges_between_mut(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = &mut Self::EdgeWeight>
+	{
+		let (so, si) = Self::stored_in(*source.borrow(), *sink.borrow());
+		self.entry_mut(so)
+			.into_iter()
+			.flat_map(|(_, edges)| edges.iter_mut())
+			.filter_map(move |(s, w)| (*s == si).then_some(w))
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.entry_mut(*v.borrow()).map(|(w, _)| w)
+	}
+}
+
+impl<Vw, Ew, D> NewVertex for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let new_v = self.vertices.len();
+		self.vertices.push(Some(Arc::new((w, Vec::new()))));
+		self.vertex_count += 1;
+		Ok(new_v)
+	}
+}
+
+impl<Vw, Ew, D> RemoveVertex for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		let entry = self
+			.vertices
+			.get_mut(v)
+			.and_then(Option::take)
+			.ok_or(GraphError::MissingVertex(v))?;
+		self.vertex_count -= 1;
+
+		// Only copy the vertices that have edges to the removed vertex
+		let sourcing: Vec<_> = self
+			.vertices
+			.iter()
+			.enumerate()
+			.filter(|(_, entry)| {
+				entry
+					.as_ref()
+					.is_some_and(|entry| entry.1.iter().any(|(si, _)| *si == v))
+			})
+			.map(|(so, _)| so)
+			.collect();
+		for so in sourcing
+		{
+			self.entry_mut(so).unwrap().1.retain(|(si, _)| *si != v);
+		}
+		Ok(Arc::unwrap_or_clone(entry).0)
+	}
+}
+
+impl<Vw, Ew, D> StableRemoveVertex for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+}
+
+impl<Vw, Ew, D> AddEdge for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		let (so, si) = Self::stored_in(*source.borrow(), *sink.borrow());
+		self.entry_mut(so).unwrap().1.push((si, weight));
+		Ok(())
+	}
+}
+
+impl<Vw, Ew, D> RemoveEdge for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		let (so, si) = Self::stored_in(source, sink);
+		// Find the edge before copying anything
+		let idx = self
+			.entry(so)
+			.and_then(|(_, edges)| edges.iter().position(|(s, w)| *s == si && f(w)))
+			.ok_or(GraphError::MissingEdge(source, sink))?;
+		Ok(self.entry_mut(so).unwrap().1.remove(idx).1)
+	}
+}
+
+impl<Vw, Ew, D> VertexCount for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertex_count
+	}
+}
+
+impl<Vw, Ew, D> EdgeCount for PersistentGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.vertices
+			.iter()
+			.flatten()
+			.fold(0, |count, entry| count + entry.1.len())
+	}
+}
+
+base_graph! {
+	use<Vw, Ew, D> PersistentGraph<Vw, Ew, D>
+	where D: Directedness,
+}
@@ -0,0 +1,247 @@
+use crate::core::{
+	property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
+	Graph, GraphError,
+};
+use delegate::delegate;
+use std::{
+	borrow::Borrow,
+	sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// A graph that many threads can read and change at the same time.
+///
+/// Each `SharedGraph` is a handle to the same graph, and a thread gets its
+/// own handle by cloning another one.
+/// A handle reads the graph through [Graph](../core/trait.Graph.html) without
+/// locking, as it reads a version of the graph that no thread changes.
+/// That version is the one the handle last saw: the graph as it was when the
+/// handle was cloned, last changed through it, or last
+/// [refreshed](#method.refresh).
+///
+/// Changes through [AddEdge](../core/property/trait.AddEdge.html),
+/// [RemoveEdge](../core/property/trait.RemoveEdge.html),
+/// [NewVertex](../core/property/trait.NewVertex.html) or
+/// [RemoveVertex](../core/property/trait.RemoveVertex.html) are made to the
+/// latest version, one thread at a time. A change copies the latest version,
+/// changes the copy, and makes it the latest version, such that readers never
+/// see a change halfway.
+/// Copying takes constant time for a
+/// [PersistentGraph](struct.PersistentGraph.html), but copies the whole graph
+/// for most other graphs.
+///
+/// If the underlying graph is an ensurer, like
+/// [ConnectedGraph](../core/property/struct.ConnectedGraph.html), all changes
+/// go through it, so its property holds for every version.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::common::{PersistentGraph, SharedGraph};
+/// # use graphene::core::{Graph, property::{AddEdge, EdgeCount, NewVertex}};
+/// let mut g = PersistentGraph::<(), ()>::new();
+/// let v1 = g.new_vertex().unwrap();
+/// let v2 = g.new_vertex().unwrap();
+/// let shared = SharedGraph::new(g);
+///
+/// std::thread::scope(|s| {
+///     let mut writer = shared.clone();
+///     s.spawn(move || writer.add_edge(v1, v2).unwrap());
+///     let reader = shared.clone();
+///     s.spawn(move || reader.edges_sourced_in(v1).count());
+/// });
+/// let mut reader = shared.clone();
+/// assert_eq!(reader.edge_count(), 0);
+/// reader.refresh();
+/// assert_eq!(reader.edge_count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct SharedGraph<G: Graph>
+{
+	/// The latest version of the graph, which is only replaced while locked.
+	latest: Arc<Mutex<Arc<G>>>,
+
+	/// The version of the graph this handle reads.
+	version: Arc<G>,
+}
+
+impl<G: Graph> SharedGraph<G>
+{
+	/// Constructs a handle to a new shared graph.
+	pub fn new(graph: G) -> Self
+	{
+		let version = Arc::new(graph);
+		Self {
+			latest: Arc::new(Mutex::new(version.clone())),
+			version,
+		}
+	}
+
+	/// Makes this handle read the latest version of the graph.
+	pub fn refresh(&mut self)
+	{
+		self.version = Self::lock(&self.latest).clone();
+	}
+
+	/// Returns the version of the graph this handle reads.
+	pub fn version(&self) -> &Arc<G>
+	{
+		&self.version
+	}
+
+	fn lock(latest: &Mutex<Arc<G>>) -> MutexGuard<'_, Arc<G>>
+	{
+		// Versions are never changed, so one that was being replaced when a
+		// thread panicked is still whole
+		latest.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+impl<G: Graph + Clone> SharedGraph<G>
+{
+	/// Makes the given change to a copy of the latest version, which becomes
+	/// the latest version if the change succeeds.
+	fn change<T>(
+		&mut self,
+		f: impl FnOnce(&mut G) -> Result<T, GraphError<G::Vertex>>,
+	) -> Result<T, GraphError<G::Vertex>>
+	{
+		let mut latest = Self::lock(&self.latest);
+		let mut graph = G::clone(&latest);
+		let result = f(&mut graph)?;
+		*latest = Arc::new(graph);
+		self.version = latest.clone();
+		Ok(result)
+	}
+}
+
+impl<G: Graph> Clone for SharedGraph<G>
+{
+	fn clone(&self) -> Self
+	{
+		Self {
+			latest: self.latest.clone(),
+			version: self.version.clone(),
+		}
+	}
+}
+
+impl<G: Graph> Graph for SharedGraph<G>
+{
+	type Directedness = G::Directedness;
+	type EdgeWeight = G::EdgeWeight;
+	type EdgeWeightRef<'a>
+		= G::EdgeWeightRef<'a>
+	where
+		Self: 'a;
+	type Vertex = G::Vertex;
+	type VertexWeight = G::VertexWeight;
+
+	delegate! {
+		to self.version {
+			fn all_vertices_weighted(
+				&self,
+			) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>;
+
+			fn edges_between(
+				&self,
+				source: impl Borrow<Self::Vertex>,
+				sink: impl Borrow<Self::Vertex>,
+			) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>;
+
+			fn all_edges(
+				&self,
+			) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+			fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>;
+
+			fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool;
+
+			fn edges_sourced_in(
+				&self,
+				v: impl Borrow<Self::Vertex>,
+			) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+			fn edges_sinked_in(
+				&self,
+				v: impl Borrow<Self::Vertex>,
+			) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+			fn edges_incident_on(
+				&self,
+				v: impl Borrow<Self::Vertex>,
+			) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+		}
+	}
+}
+
+impl<G: NewVertex + Clone> NewVertex for SharedGraph<G>
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		self.change(|g| g.new_vertex_weighted(w))
+	}
+}
+
+impl<G: RemoveVertex + Clone> RemoveVertex for SharedGraph<G>
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		self.change(|g| g.remove_vertex(v))
+	}
+}
+
+impl<G: AddEdge + Clone> AddEdge for SharedGraph<G>
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		self.change(|g| g.add_edge_weighted(source, sink, weight))
+	}
+}
+
+impl<G: RemoveEdge + Clone> RemoveEdge for SharedGraph<G>
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		self.change(|g| g.remove_edge_where_weight(source, sink, f))
+	}
+}
+
+impl<G: VertexCount> VertexCount for SharedGraph<G>
+{
+	type Count = G::Count;
+
+	delegate! {
+		to self.version {
+			fn vertex_count(&self) -> Self::Count;
+		}
+	}
+}
+
+impl<G: EdgeCount> EdgeCount for SharedGraph<G>
+{
+	type Count = G::Count;
+
+	delegate! {
+		to self.version {
+			fn edge_count(&self) -> Self::Count;
+		}
+	}
+}
@@ -1,6 +1,8 @@
 use crate::core::{
-	property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, VertexCount},
-	Graph, GraphMut,
+	property::{
+		AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, StableRemoveVertex, VertexCount,
+	},
+	Graph, GraphError, GraphMut,
 };
 use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::empty};
 
@@ -14,18 +16,27 @@ use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::empty};
 /// as a backing graph.
 ///
 /// It is backed by an underlying graph (G), with a map from the arbitrary
-/// vertices to vertices of the underlying graph.
+/// vertices to vertices of the underlying graph and a reverse map back.
+/// Vertices are returned in the order they were added.
 ///
-/// Does not implement [`RemoveVertex`] because doing so does not guarantee that
-/// the vertices stay the same in the underlying graph.
+/// Implements [`RemoveVertex`] only if the underlying graph implements
+/// [`StableRemoveVertex`], as otherwise removing a vertex could change the
+/// other vertices in the underlying graph.
 #[derive(Clone, Debug)]
 pub struct VertexMapGraph<V: Copy + Eq + Hash, G: Graph>
 {
-	pub graph: G,
-	pub map: HashMap<V, G::Vertex>,
+	graph: G,
+	/// Maps each vertex to its vertex in the underlying graph.
+	map: HashMap<V, G::Vertex>,
+	/// Maps each vertex of the underlying graph back to its vertex.
+	reverse: HashMap<G::Vertex, V>,
+	/// The vertices in the order they were added.
+	order: Vec<V>,
 }
 
 impl<V: Copy + Eq + Hash, G: Graph> VertexMapGraph<V, G>
+where
+	G::Vertex: Hash,
 {
 	pub fn new() -> Self
 	where
@@ -34,21 +45,38 @@ impl<V: Copy + Eq + Hash, G: Graph> VertexMapGraph<V, G>
 		Self {
 			graph: G::default(),
 			map: HashMap::new(),
+			reverse: HashMap::new(),
+			order: Vec::new(),
 		}
 	}
 
 	/// Inserts the given vertex into the graph with the given weight
-	pub fn add_vertex_weighted(&mut self, v: V, w: <Self as Graph>::VertexWeight) -> Result<(), ()>
+	///
+	/// Fails if the vertex is already in the graph.
+	pub fn add_vertex_weighted(
+		&mut self,
+		v: V,
+		w: <Self as Graph>::VertexWeight,
+	) -> Result<(), GraphError<V>>
 	where
 		G: NewVertex,
 	{
-		let new_v = self.graph.new_vertex_weighted(w)?;
-		assert!(self.map.insert(v, new_v).is_none());
+		if self.map.contains_key(&v)
+		{
+			return Err(GraphError::DuplicateVertex(v));
+		}
+		let new_v = self
+			.graph
+			.new_vertex_weighted(w)
+			.map_err(|err| self.map_backing_error(err))?;
+		self.map.insert(v, new_v);
+		self.reverse.insert(new_v, v);
+		self.order.push(v);
 		Ok(())
 	}
 
 	/// Inserts the given vertex into the graph
-	pub fn add_vertex(&mut self, v: V) -> Result<(), ()>
+	pub fn add_vertex(&mut self, v: V) -> Result<(), GraphError<V>>
 	where
 		G: NewVertex,
 		G::VertexWeight: Default,
@@ -56,18 +84,65 @@ impl<V: Copy + Eq + Hash, G: Graph> VertexMapGraph<V, G>
 		self.add_vertex_weighted(v, Default::default())
 	}
 
-	fn get_backing_vertices<const NR: usize>(&self, vs: [&V; NR]) -> Result<[G::Vertex; NR], ()>
+	/// Returns the underlying graph.
+	pub fn backing_graph(&self) -> &G
+	{
+		&self.graph
+	}
+
+	/// Returns the underlying graph mutably.
+	///
+	/// Its weights and edges may be changed freely, but vertices must not be
+	/// added, removed or renumbered through it, as they would not be mapped.
+	pub fn backing_graph_mut(&mut self) -> &mut G
+	{
+		&mut self.graph
+	}
+
+	/// Returns the map from each vertex to its vertex in the underlying graph.
+	pub fn map(&self) -> &HashMap<V, G::Vertex>
+	{
+		&self.map
+	}
+
+	/// Returns the vertex in the underlying graph of the given vertex.
+	pub fn backing_vertex(&self, v: impl Borrow<V>) -> Option<G::Vertex>
+	{
+		self.map.get(v.borrow()).copied()
+	}
+
+	/// Returns the vertex of the given vertex in the underlying graph.
+	pub fn mapped_vertex(&self, b: impl Borrow<G::Vertex>) -> Option<V>
+	{
+		self.reverse.get(b.borrow()).copied()
+	}
+}
+
+impl<V: Copy + Eq + Hash, G: Graph> VertexMapGraph<V, G>
+{
+	fn get_backing_vertices<const NR: usize>(
+		&self,
+		vs: [&V; NR],
+	) -> Result<[G::Vertex; NR], GraphError<V>>
 	{
-		let accesses = vs.map(|v| self.map.get(v));
-		if accesses.iter().any(|v| v.is_none())
+		if let Some(v) = vs.iter().find(|v| !self.map.contains_key(v))
 		{
-			Err(())
+			Err(GraphError::MissingVertex(**v))
 		}
 		else
 		{
-			Ok(accesses.map(|v| *v.unwrap()))
+			Ok(vs.map(|v| self.map[v]))
 		}
 	}
+
+	/// Converts an error from the underlying graph to be in terms of this
+	/// graph's vertices.
+	fn map_backing_error(&self, err: GraphError<G::Vertex>) -> GraphError<V>
+	where
+		G::Vertex: Hash,
+	{
+		err.map_vertices(|b| self.reverse[&b])
+	}
 }
 
 impl<V: Copy + Eq + Hash, G: Graph> Graph for VertexMapGraph<V, G>
@@ -83,9 +158,9 @@ impl<V: Copy + Eq + Hash, G: Graph> Graph for VertexMapGraph<V, G>
 
 	fn all_vertices_weighted(&self) -> impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>
 	{
-		self.map
+		self.order
 			.iter()
-			.map(|(v, b)| (*v, self.graph.vertex_weight(b).unwrap()))
+			.map(|v| (*v, self.graph.vertex_weight(self.map[v]).unwrap()))
 	}
 
 	fn edges_between(
@@ -109,22 +184,19 @@ impl<V: Copy + Eq + Hash, G: Graph> Graph for VertexMapGraph<V, G>
 }
 
 impl<V: Copy + Eq + Hash, G: GraphMut> GraphMut for VertexMapGraph<V, G>
+where
+	G::Vertex: Hash,
 {
 	fn all_vertices_weighted_mut(
 		&mut self,
 	) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>
 	{
-		self.graph.all_vertices_weighted_mut().map(|(b, w)| {
-			(
-				*self
-					.map
-					.iter()
-					.find(|(_, b2)| **b2 == b)
-					.map(|(v, _)| v)
-					.unwrap(),
-				w,
-			)
-		})
+		// Gather the weights first, so they can be returned in insertion order
+		let mut weights: HashMap<_, _> = self.graph.all_vertices_weighted_mut().collect();
+		let map = &self.map;
+		self.order
+			.iter()
+			.map(move |v| (*v, weights.remove(&map[v]).unwrap()))
 	}
 
 	fn edges_between_mut(
@@ -147,29 +219,63 @@ impl<V: Copy + Eq + Hash, G: GraphMut> GraphMut for VertexMapGraph<V, G>
 	}
 }
 
+impl<V: Copy + Eq + Hash, G: StableRemoveVertex> RemoveVertex for VertexMapGraph<V, G>
+where
+	G::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		let [backing_v] = self.get_backing_vertices([&v])?;
+		let w = self
+			.graph
+			.remove_vertex(backing_v)
+			.map_err(|err| self.map_backing_error(err))?;
+
+		self.map.remove(&v);
+		self.reverse.remove(&backing_v);
+		self.order.retain(|v2| *v2 != v);
+		Ok(w)
+	}
+}
+
+impl<V: Copy + Eq + Hash, G: StableRemoveVertex> StableRemoveVertex for VertexMapGraph<V, G> where
+	G::Vertex: Hash
+{
+}
+
 impl<V: Copy + Eq + Hash, G: AddEdge> AddEdge for VertexMapGraph<V, G>
+where
+	G::Vertex: Hash,
 {
 	fn add_edge_weighted(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		let [backing_so, backing_si] =
 			self.get_backing_vertices([source.borrow(), sink.borrow()])?;
-		self.graph.add_edge_weighted(backing_so, backing_si, weight)
+		self.graph
+			.add_edge_weighted(backing_so, backing_si, weight)
+			.map_err(|err| self.map_backing_error(err))
 	}
 }
 
 impl<V: Copy + Eq + Hash, G: RemoveEdge> RemoveEdge for VertexMapGraph<V, G>
+where
+	G::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -178,6 +284,7 @@ impl<V: Copy + Eq + Hash, G: RemoveEdge> RemoveEdge for VertexMapGraph<V, G>
 
 		self.graph
 			.remove_edge_where_weight(backing_so, backing_si, f)
+			.map_err(|err| self.map_backing_error(err))
 	}
 }
 
@@ -1,5 +1,5 @@
 use crate::core::{Graph, GraphDeref};
-use std::ops::Deref;
+use std::{any::type_name, fmt, ops::Deref};
 
 pub trait Payload<B>
 {
@@ -51,7 +51,7 @@ impl<A, B> Payload<B> for (A, B)
 /// it possible.)
 pub trait BaseGraph: Sized + GraphDeref
 {
-	fn ensure_all<G>(self, p: G::Payload) -> Result<G, ()>
+	fn ensure_all<G>(self, p: G::Payload) -> Result<G, EnsureError<<G::Graph as Graph>::Vertex>>
 	where
 		G: Ensure<Base = Self>,
 	{
@@ -60,7 +60,7 @@ pub trait BaseGraph: Sized + GraphDeref
 }
 pub trait BaseGraphGuard: BaseGraph
 {
-	fn guard_all<G>(self) -> Result<G, ()>
+	fn guard_all<G>(self) -> Result<G, EnsureError<<G::Graph as Graph>::Vertex>>
 	where
 		G: Ensure<Base = Self>,
 		G::Payload: Payload<(), Item = ()>,
@@ -84,25 +84,47 @@ pub trait Ensure: ReleasePayload
 		p: &<Self::Payload as Payload<<Self::Ensured as ReleasePayload>::Payload>>::Item,
 	) -> bool;
 
-	fn ensure(
-		c: Self::Ensured,
-		p: <Self::Payload as Payload<<Self::Ensured as ReleasePayload>::Payload>>::Item,
-	) -> Result<Self, ()>
+	/// Checks whether the property can be ensured like
+	/// [can_ensure](Self::can_ensure), but explains why when it can't.
+	///
+	/// By default, the violation is
+	/// [Unspecified](Violation::Unspecified). Ensurers that can find a
+	/// witness of the violation should override this method.
+	fn validate(
+		c: &Self::Ensured,
+		p: &<Self::Payload as Payload<<Self::Ensured as ReleasePayload>::Payload>>::Item,
+	) -> Result<(), Violation<<Self::Graph as Graph>::Vertex>>
 	{
-		if Self::can_ensure(&c, &p)
+		if Self::can_ensure(c, p)
 		{
-			Ok(Self::ensure_unchecked(c, p))
+			Ok(())
 		}
 		else
 		{
-			Err(())
+			Err(Violation::Unspecified)
+		}
+	}
+
+	fn ensure(
+		c: Self::Ensured,
+		p: <Self::Payload as Payload<<Self::Ensured as ReleasePayload>::Payload>>::Item,
+	) -> Result<Self, EnsureError<<Self::Graph as Graph>::Vertex>>
+	{
+		match Self::validate(&c, &p)
+		{
+			Ok(()) => Ok(Self::ensure_unchecked(c, p)),
+			Err(violation) => Err(EnsureError::new::<Self>(violation)),
 		}
 	}
 
-	fn ensure_all(g: Self::Base, p: Self::Payload) -> Result<Self, ()>
+	fn ensure_all(
+		g: Self::Base,
+		p: Self::Payload,
+	) -> Result<Self, EnsureError<<Self::Graph as Graph>::Vertex>>
 	{
 		let (p, rest) = p.split();
-		Self::ensure(Self::Ensured::ensure_all(g, rest)?, p)
+		let ensured = Self::Ensured::ensure_all(g, rest).map_err(EnsureError::nested)?;
+		Self::ensure(ensured, p)
 	}
 }
 pub trait Guard: Ensure
@@ -118,11 +140,11 @@ where
 	{
 		<Self as Ensure>::can_ensure(c, &())
 	}
-	fn guard(c: Self::Ensured) -> Result<Self, ()>
+	fn guard(c: Self::Ensured) -> Result<Self, EnsureError<<Self::Graph as Graph>::Vertex>>
 	{
 		<Self as Ensure>::ensure(c, ())
 	}
-	fn guard_all(g: Self::Base) -> Result<Self, ()>
+	fn guard_all(g: Self::Base) -> Result<Self, EnsureError<<Self::Graph as Graph>::Vertex>>
 	where
 		<Self as ReleasePayload>::Payload: Payload<(), Item = ()>,
 	{
@@ -144,7 +166,10 @@ pub trait ReleasePayload: Sized + GraphDeref
 	type Base: BaseGraph;
 
 	/// The inner ensurer being further ensured.
-	type Ensured: Ensure<Base = Self::Base>;
+	///
+	/// It must have the same vertices, such that errors from ensuring it can
+	/// be reported in terms of this graph's vertices.
+	type Ensured: Ensure<Base = Self::Base, Graph: Graph<Vertex = <Self::Graph as Graph>::Vertex>>;
 
 	/// The payload used to ensure this property holds.
 	type Payload: Payload<<Self::Ensured as ReleasePayload>::Payload>;
@@ -211,7 +236,10 @@ impl<B: BaseGraph> Ensure for B
 		true
 	}
 
-	fn ensure_all(g: Self::Base, _: Self::Payload) -> Result<Self, ()>
+	fn ensure_all(
+		g: Self::Base,
+		_: Self::Payload,
+	) -> Result<Self, EnsureError<<Self::Graph as Graph>::Vertex>>
 	{
 		Ensure::ensure(g, ())
 	}
@@ -238,3 +266,131 @@ impl<E: Ensure> Guard for E where
 {
 }
 impl<E: ReleasePayload> Release for E {}
+
+/// Why a graph doesn't have a property, with a witness where possible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Violation<V>
+{
+	/// The graph has no vertices.
+	Empty,
+
+	/// The vertex isn't in the graph.
+	MissingVertex(V),
+
+	/// The vertex is designated by the ensurer, so it must stay in the graph.
+	Designated(V),
+
+	/// The vertex has a loop.
+	Loop(V),
+
+	/// The vertex doesn't have a loop.
+	MissingLoop(V),
+
+	/// There are multiple edges from the first vertex to the second.
+	DuplicateEdge(V, V),
+
+	/// There is no path from the first vertex to the second.
+	Unreachable(V, V),
+
+	/// The vertices form a cycle, in order, with an edge from the last vertex
+	/// back to the first.
+	Cycle(Vec<V>),
+
+	/// The edge from the first vertex to the second has a disallowed weight.
+	EdgeWeight(V, V),
+
+	/// The vertex has a disallowed number of incident edges.
+	Degree(V),
+
+	/// The property doesn't hold, but no further explanation is given.
+	Unspecified,
+}
+
+impl<V> Violation<V>
+{
+	/// Converts the vertices of the witness using the given function.
+	pub fn map_vertices<W>(self, mut f: impl FnMut(V) -> W) -> Violation<W>
+	{
+		match self
+		{
+			Self::Empty => Violation::Empty,
+			Self::MissingVertex(v) => Violation::MissingVertex(f(v)),
+			Self::Designated(v) => Violation::Designated(f(v)),
+			Self::Loop(v) => Violation::Loop(f(v)),
+			Self::MissingLoop(v) => Violation::MissingLoop(f(v)),
+			Self::DuplicateEdge(so, si) => Violation::DuplicateEdge(f(so), f(si)),
+			Self::Unreachable(v1, v2) => Violation::Unreachable(f(v1), f(v2)),
+			Self::Cycle(vs) => Violation::Cycle(vs.into_iter().map(f).collect()),
+			Self::EdgeWeight(so, si) => Violation::EdgeWeight(f(so), f(si)),
+			Self::Degree(v) => Violation::Degree(f(v)),
+			Self::Unspecified => Violation::Unspecified,
+		}
+	}
+}
+
+/// The error returned when a property could not be ensured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnsureError<V>
+{
+	/// The type name of the ensurer that rejected the graph.
+	pub ensurer: &'static str,
+
+	/// How many layers inside the ensurer being ensured the rejecting ensurer
+	/// is.
+	///
+	/// When ensuring a single layer this is always 0. When ensuring multiple
+	/// layers using [ensure_all](Ensure::ensure_all), 1 means the layer
+	/// directly inside the outermost one failed, 2 the layer inside that, etc.
+	pub layer: usize,
+
+	/// Why the property doesn't hold.
+	pub violation: Violation<V>,
+}
+
+impl<V> EnsureError<V>
+{
+	/// Creates an error for the given ensurer rejecting a graph directly.
+	pub fn new<E>(violation: Violation<V>) -> Self
+	{
+		Self {
+			ensurer: type_name::<E>(),
+			layer: 0,
+			violation,
+		}
+	}
+
+	/// Converts the vertices of the violation using the given function.
+	pub fn map_vertices<W>(self, f: impl FnMut(V) -> W) -> EnsureError<W>
+	{
+		EnsureError {
+			ensurer: self.ensurer,
+			layer: self.layer,
+			violation: self.violation.map_vertices(f),
+		}
+	}
+
+	/// Returns this error as reported by the ensurer wrapping the one that
+	/// failed.
+	pub fn nested(self) -> Self
+	{
+		Self {
+			layer: self.layer + 1,
+			..self
+		}
+	}
+}
+
+impl<V: fmt::Debug> fmt::Display for EnsureError<V>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(
+			f,
+			"'{}' (layer {}) could not be ensured: {:?}",
+			self.ensurer, self.layer, self.violation
+		)
+	}
+}
+
+impl<V: fmt::Debug> std::error::Error for EnsureError<V> {}
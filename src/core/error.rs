@@ -0,0 +1,96 @@
+use crate::core::{EnsureError, Graph, Violation};
+use std::fmt;
+
+/// The error returned when a graph could not be changed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GraphError<V>
+{
+	/// The vertex isn't in the graph.
+	MissingVertex(V),
+
+	/// The vertex is already in the graph.
+	DuplicateVertex(V),
+
+	/// There is no matching edge from the first vertex to the second.
+	MissingEdge(V, V),
+
+	/// There is no edge with the given id.
+	MissingEdgeId,
+
+	/// The graph can't represent the change, e.g. a second edge between two
+	/// vertices in a graph that only has room for one.
+	Unsupported,
+
+	/// An ensurer rejected the change, since the graph would no longer have
+	/// its property afterwards.
+	Rejected(EnsureError<V>),
+}
+
+impl<V> GraphError<V>
+{
+	/// Creates an error for the given ensurer rejecting a change because the
+	/// result would have the given violation.
+	pub fn rejected<E>(violation: Violation<V>) -> Self
+	{
+		Self::Rejected(EnsureError::new::<E>(violation))
+	}
+
+	/// Converts the vertices of the error using the given function.
+	pub fn map_vertices<W>(self, mut f: impl FnMut(V) -> W) -> GraphError<W>
+	{
+		match self
+		{
+			Self::MissingVertex(v) => GraphError::MissingVertex(f(v)),
+			Self::DuplicateVertex(v) => GraphError::DuplicateVertex(f(v)),
+			Self::MissingEdge(so, si) => GraphError::MissingEdge(f(so), f(si)),
+			Self::MissingEdgeId => GraphError::MissingEdgeId,
+			Self::Unsupported => GraphError::Unsupported,
+			Self::Rejected(err) => GraphError::Rejected(err.map_vertices(f)),
+		}
+	}
+
+	/// Fails with [MissingVertex](Self::MissingVertex) for the first of the
+	/// given vertices that isn't in the graph.
+	pub(crate) fn check_vertices<G>(
+		graph: &G,
+		vertices: impl IntoIterator<Item = V>,
+	) -> Result<(), Self>
+	where
+		G: Graph<Vertex = V> + ?Sized,
+	{
+		vertices
+			.into_iter()
+			.find(|v| !graph.contains_vertex(v))
+			.map_or(Ok(()), |v| Err(Self::MissingVertex(v)))
+	}
+}
+
+impl<V> From<EnsureError<V>> for GraphError<V>
+{
+	fn from(err: EnsureError<V>) -> Self
+	{
+		Self::Rejected(err)
+	}
+}
+
+impl<V: fmt::Debug> fmt::Display for GraphError<V>
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::MissingVertex(v) => write!(f, "vertex {:?} is not in the graph", v),
+			Self::DuplicateVertex(v) => write!(f, "vertex {:?} is already in the graph", v),
+			Self::MissingEdge(so, si) =>
+			{
+				write!(f, "no edge from {:?} to {:?} in the graph", so, si)
+			},
+			Self::MissingEdgeId => write!(f, "no edge with the given id in the graph"),
+			Self::Unsupported => write!(f, "the graph cannot represent the change"),
+			Self::Rejected(err) => write!(f, "change rejected: {}", err),
+		}
+	}
+}
+
+impl<V: fmt::Debug> std::error::Error for GraphError<V> {}
@@ -4,10 +4,14 @@ mod deref_graph;
 mod directedness;
 mod edge;
 mod ensure;
+mod error;
 mod graph;
 mod maybe_owned;
 #[macro_use]
 pub mod property;
 pub mod proxy;
 
-pub use self::{deref_graph::*, directedness::*, edge::*, ensure::*, graph::*, maybe_owned::*};
+pub use self::{
+	deref_graph::*, directedness::*, edge::*, ensure::*, error::*, graph::*, maybe_owned::*,
+};
+pub use graphene_derive::{delegate_properties, Ensurer};
@@ -2,7 +2,8 @@ use crate::{
 	algo::{path_exists, search::Dfs, Retainable},
 	core::{
 		property::{AddEdge, NoLoops, VertexInGraph},
-		Directedness, Ensure, Graph, GraphDerefMut,
+		proxy::EdgeProxyGraph,
+		Directedness, Ensure, Graph, GraphDerefMut, GraphError, Violation,
 	},
 };
 use std::borrow::Borrow;
@@ -20,13 +21,20 @@ impl<C: Ensure> Ensure for AcyclicGraph<C>
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
 	{
-		fn on_visit<G: Graph>(_: &G, v: G::Vertex, payload: &mut (Vec<G::Vertex>, &mut bool))
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		type Payload<'a, V> = (Vec<V>, &'a mut Option<Vec<V>>);
+
+		fn on_visit<G: Graph>(_: &G, v: G::Vertex, payload: &mut Payload<G::Vertex>)
 		{
 			payload.0.push(v);
 		}
-		fn on_exit<G: Graph>(_: &G, _: G::Vertex, (stack, _): &mut (Vec<G::Vertex>, &mut bool))
+		fn on_exit<G: Graph>(_: &G, _: G::Vertex, (stack, _): &mut Payload<G::Vertex>)
 		{
 			stack.pop();
 		}
@@ -35,36 +43,29 @@ impl<C: Ensure> Ensure for AcyclicGraph<C>
 			source: G::Vertex,
 			sink: G::Vertex,
 			_: &G::EdgeWeight,
-			payload: &mut (Vec<G::Vertex>, &mut bool),
+			(stack, cycle): &mut Payload<G::Vertex>,
 		)
 		{
-			if G::Directedness::directed()
+			// Check whether the second to last element is the same as the sink
+			// (the last element is the same as source, since on_visit is called
+			// before exploration)
+			if !G::Directedness::directed()
+				&& stack.len() >= 2
+				&& stack[stack.len() - 2] == sink
+				&& graph.edges_between(source, sink).nth(1).is_none()
 			{
-				*payload.1 |= payload.0.contains(&sink);
+				// This is the only edge to the direct predecessor, which
+				// therefore isn't a cycle
+				return;
 			}
-			else
+
+			// The path from the sink on the stack and back to it is a cycle
+			if let Some(idx) = stack.iter().position(|v| *v == sink)
 			{
-				// Check whether the second to last element is the same as the sink
-				// (the last element is the same as source, since on_visit is called
-				// before exploration)
-				if payload.0.len() >= 2
-					&& payload
-						.0
-						.get(payload.0.len() - 2)
-						.map_or(false, |&v| v == sink)
-				{
-					// This is an edge to the direct predecessor
-					// Therefore, only counts as a cycle if there
-					// are multiple edge between these two
-					*payload.1 |= graph.edges_between(source, sink).nth(1).is_some();
-				}
-				else
-				{
-					*payload.1 |= payload.0.contains(&sink);
-				}
+				cycle.get_or_insert_with(|| stack[idx..].to_vec());
 			}
 		}
-		let mut result = false;
+		let mut cycle = None;
 		let mut done = Vec::new();
 
 		// Ensure we have explored all the graph
@@ -74,7 +75,7 @@ impl<C: Ensure> Ensure for AcyclicGraph<C>
 			{
 				done.push(v); // not returned by the dfs
 				let g = VertexInGraph::ensure_unchecked(c.graph(), [v]);
-				let dfs = Dfs::new(&g, on_visit, on_exit, on_explore, (Vec::new(), &mut result))
+				let dfs = Dfs::new(&g, on_visit, on_exit, on_explore, (Vec::new(), &mut cycle))
 					.retain(&g);
 
 				dfs.for_each(|v| {
@@ -85,7 +86,7 @@ impl<C: Ensure> Ensure for AcyclicGraph<C>
 				});
 			}
 		}
-		!result
+		cycle.map_or(Ok(()), |cycle| Err(Violation::Cycle(cycle)))
 	}
 }
 
@@ -98,7 +99,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if !path_exists(self, sink.borrow(), source.borrow())
 		{
@@ -106,7 +107,12 @@ where
 		}
 		else
 		{
-			Err(())
+			// Every cycle after adding the edge goes through it, so any one will do
+			let mut proxy = EdgeProxyGraph::new(self.0.graph());
+			proxy.add_edge(source.borrow(), sink.borrow())?;
+			let violation =
+				AcyclicGraph::validate(&proxy, &()).map_or_else(|v| v, |_| Violation::Unspecified);
+			Err(GraphError::rejected::<Self>(violation))
 		}
 	}
 }
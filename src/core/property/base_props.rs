@@ -1,4 +1,4 @@
-use crate::core::{Directedness, Graph};
+use crate::core::{Directedness, Graph, GraphError};
 use num_traits::{One, PrimInt, Unsigned, Zero};
 use std::borrow::Borrow;
 
@@ -7,14 +7,17 @@ pub trait NewVertex: Graph
 {
 	/// Adds a new vertex with the given weight to the graph.
 	/// Returns the id of the new vertex.
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>;
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>;
 
 	// Optional methods
 
 	/// Adds a new vertex to the graph.
 	/// Returns the id of the new vertex.
 	/// The weight of the vertex is the default.
-	fn new_vertex(&mut self) -> Result<Self::Vertex, ()>
+	fn new_vertex(&mut self) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	where
 		Self::VertexWeight: Default,
 	{
@@ -30,9 +33,22 @@ pub trait RemoveVertex: Graph
 	/// Removes the given vertex from the graph, returning its weight.
 	/// If the vertex still has edges incident on it, they are also removed,
 	/// dropping their weights.
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>;
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>;
 }
 
+/// A graph where removing a vertex doesn't invalidate any other vertex.
+///
+/// After [`remove_vertex`](RemoveVertex::remove_vertex), all other vertices
+/// keep their ids, and ids of removed vertices aren't reused.
+///
+/// This only holds until the graph renumbers its vertices on request, e.g.
+/// using [`StableAdjListGraph::compact`](crate::common::StableAdjListGraph::compact),
+/// after which ids may be reused.
+pub trait StableRemoveVertex: RemoveVertex {}
+
 pub trait AddEdge: Graph
 {
 	/// Adds a copy of the given edge to the graph
@@ -41,7 +57,7 @@ pub trait AddEdge: Graph
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>;
+	) -> Result<(), GraphError<Self::Vertex>>;
 
 	// Optional methods
 
@@ -68,7 +84,7 @@ pub trait AddEdge: Graph
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	where
 		Self::EdgeWeight: Default,
 	{
@@ -83,7 +99,7 @@ pub trait RemoveEdge: Graph
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool;
 
@@ -109,7 +125,7 @@ pub trait RemoveEdge: Graph
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	{
 		self.remove_edge_where_weight(source, sink, |_| true)
 	}
@@ -138,28 +154,25 @@ pub trait EdgeCount: Graph
 {
 	type Count: PrimInt + Unsigned;
 
-	/// Returns the number of vertices in the graph.
+	/// Returns the number of edges in the graph.
 	fn edge_count(&self) -> Self::Count
 	{
 		let mut count = Self::Count::zero();
 		let mut inc = || count = count + Self::Count::one();
 		let verts: Vec<_> = self.all_vertices().collect();
 
-		let mut iter = verts.iter();
-		let mut rest_iter = iter.clone();
-		while let Some(v) = iter.next()
+		for (idx, v) in verts.iter().enumerate()
 		{
-			for v2 in rest_iter
+			for v2 in verts[idx..].iter()
 			{
 				self.edges_between(v.borrow(), v2.borrow())
 					.for_each(|_| inc());
-				if Self::Directedness::directed()
+				if Self::Directedness::directed() && v != v2
 				{
 					self.edges_between(v2.borrow(), v.borrow())
 						.for_each(|_| inc());
 				}
 			}
-			rest_iter = iter.clone();
 		}
 		count
 	}
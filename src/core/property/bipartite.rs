@@ -0,0 +1,285 @@
+use crate::core::{
+	property::{AddEdge, NewVertex, NoLoops, RemoveVertex, StableRemoveVertex},
+	proxy::EdgeProxyGraph,
+	Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use std::{
+	borrow::Borrow,
+	collections::{HashMap, HashSet},
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
+};
+
+/// A bipartite graph.
+///
+/// The vertices of a bipartite graph are split into two sides, left and right,
+/// such that every edge connects a vertex on the left to a vertex on the
+/// right.
+/// For directed graphs, edges may go in either direction between the sides.
+pub trait Bipartite: NoLoops
+{
+	/// Returns whether the given vertex is on the left side of the graph.
+	///
+	/// All other vertices of the graph are on the right side.
+	fn is_left(&self, v: impl Borrow<Self::Vertex>) -> bool;
+
+	// Optional methods
+
+	/// Returns the vertices on the left side of the graph.
+	fn left_vertices(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		self.all_vertices().filter(move |v| self.is_left(v))
+	}
+
+	/// Returns the vertices on the right side of the graph.
+	fn right_vertices(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		self.all_vertices().filter(move |v| !self.is_left(v))
+	}
+}
+
+/// Ensures the underlying graph is bipartite.
+///
+/// The sides are found when the graph is ensured, by 2-coloring it.
+/// New vertices are added to the left side, unless added using
+/// [`new_right_vertex_weighted`](#method.new_right_vertex_weighted).
+///
+/// Vertices can only be removed if the underlying graph implements
+/// [StableRemoveVertex](trait.StableRemoveVertex.html), as the sides would
+/// otherwise be lost when vertices change.
+#[derive(Clone)]
+pub struct BipartiteGraph<C: Ensure>(C, HashSet<<C::Graph as Graph>::Vertex>);
+
+impl<C: Ensure> BipartiteGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Adds a new vertex with the given weight to the right side of the graph.
+	/// Returns the id of the new vertex.
+	pub fn new_right_vertex_weighted(
+		&mut self,
+		w: <C::Graph as Graph>::VertexWeight,
+	) -> Result<<C::Graph as Graph>::Vertex, GraphError<<C::Graph as Graph>::Vertex>>
+	where
+		C: GraphDerefMut,
+		C::Graph: NewVertex,
+	{
+		self.0.graph_mut().new_vertex_weighted(w)
+	}
+}
+
+impl<C: Ensure> Debug for BipartiteGraph<C>
+where
+	C: Debug,
+	<C::Graph as Graph>::Vertex: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("BipartiteGraph")
+			.field(&self.0)
+			.field(&self.1)
+			.finish()
+	}
+}
+
+/// Returns the path from the given vertex to the vertex its coloring started
+/// from, following the vertices each was colored from.
+fn path_to_start<V: Copy + Eq + Hash>(colored: &HashMap<V, (bool, Option<V>)>, mut v: V) -> Vec<V>
+{
+	let mut path = vec![v];
+	while let Some(p) = colored[&v].1
+	{
+		path.push(p);
+		v = p;
+	}
+	path
+}
+
+/// 2-colors the given graph, returning the vertices on the left side, or a
+/// cycle with an odd number of edges if the graph has any.
+///
+/// A graph is bipartite exactly if it has no such cycle.
+/// The first vertex of each component is put on the left.
+fn two_color<G: Graph>(graph: &G) -> Result<HashSet<G::Vertex>, Vec<G::Vertex>>
+where
+	G::Vertex: Hash,
+{
+	// Whether each vertex colored so far is on the left, and the vertex it was
+	// colored from
+	let mut colored: HashMap<G::Vertex, (bool, Option<G::Vertex>)> = HashMap::new();
+	for v in graph.all_vertices()
+	{
+		if colored.contains_key(&v)
+		{
+			continue;
+		}
+		colored.insert(v, (true, None));
+		let mut stack = vec![(v, true)];
+		while let Some((v, left)) = stack.pop()
+		{
+			for (n, _) in graph.edges_incident_on(v)
+			{
+				match colored.get(&n)
+				{
+					Some((n_left, _)) if *n_left == left =>
+					{
+						// The paths from the start to the two vertices have the same parity,
+						// so joining them where they split, plus the edge between them, is
+						// an odd cycle
+						let mut v_path = path_to_start(&colored, v);
+						let mut n_path = path_to_start(&colored, n);
+						while v_path.len() > 1
+							&& n_path.len() > 1 && v_path[v_path.len() - 2]
+							== n_path[n_path.len() - 2]
+						{
+							v_path.pop();
+							n_path.pop();
+						}
+						n_path.pop();
+						v_path.extend(n_path.into_iter().rev());
+						return Err(v_path);
+					},
+					Some(_) => (),
+					None =>
+					{
+						colored.insert(n, (!left, Some(v)));
+						stack.push((n, !left));
+					},
+				}
+			}
+		}
+	}
+	Ok(colored
+		.into_iter()
+		.filter_map(|(v, (left, _))| left.then_some(v))
+		.collect())
+}
+
+impl<C: Ensure> Ensure for BipartiteGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		let left = two_color(c.graph()).unwrap_or_default();
+		Self(c, left)
+	}
+
+	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	{
+		two_color(c.graph()).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		two_color(c.graph()).map(|_| ()).map_err(Violation::Cycle)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for BipartiteGraph<C>
+where
+	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Adds the new vertex to the left side of the graph.
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_weighted(w)?;
+		self.1.insert(v);
+		Ok(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for BipartiteGraph<C>
+where
+	C::Graph: StableRemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let w = self.0.graph_mut().remove_vertex(v.borrow())?;
+		self.1.remove(v.borrow());
+		Ok(w)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for BipartiteGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// If the two vertices are on the same side but in different components,
+	/// the sink's component switches sides.
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		if self.is_left(source) != self.is_left(sink)
+		{
+			return self.0.graph_mut().add_edge_weighted(source, sink, weight);
+		}
+
+		// Find the sink's component, which must be switched if it doesn't
+		// contain the source
+		let mut component = vec![sink];
+		let mut found: HashSet<_> = component.iter().copied().collect();
+		let mut idx = 0;
+		while let Some(&v) = component.get(idx)
+		{
+			for (n, _) in self.0.graph().edges_incident_on(v)
+			{
+				if found.insert(n)
+				{
+					component.push(n);
+				}
+			}
+			idx += 1;
+		}
+		if found.contains(&source)
+		{
+			let mut proxy = EdgeProxyGraph::new(self.0.graph());
+			proxy.add_edge(source, sink)?;
+			let violation =
+				two_color(&proxy).map_or_else(Violation::Cycle, |_| Violation::Unspecified);
+			return Err(GraphError::rejected::<Self>(violation));
+		}
+
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)?;
+		for v in component
+		{
+			if !self.1.remove(&v)
+			{
+				self.1.insert(v);
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<C: Ensure> NoLoops for BipartiteGraph<C> {}
+
+impl<C: Ensure> Bipartite for BipartiteGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn is_left(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		self.1.contains(v.borrow())
+	}
+}
+
+impl_ensurer! {
+	use<C> BipartiteGraph<C>: Ensure, Bipartite, NoLoops, NewVertex, RemoveVertex, AddEdge
+	as (self.0) : C
+}
@@ -2,15 +2,21 @@ use crate::{
 	algo::{search::new_search, DijkstraShortestPaths, Retainable},
 	core::{
 		property::{
-			proxy_remove_edge_where_weight, proxy_remove_vertex, DirectedGraph, EdgeCount,
-			RemoveEdge, RemoveVertex, Unilateral, VertexIn, VertexInGraph, Weak,
+			proxy_remove_edge_where_weight, proxy_remove_vertex, AddEdge, DirectedGraph,
+			DynamicConnectivity, EdgeCount, NewLeafUndirected, NonNegativeWeights, RemoveEdge,
+			RemoveVertex, Unilateral, VertexIn, VertexInGraph, Weak,
 		},
 		proxy::ReverseGraph,
-		Ensure, Graph, GraphDerefMut, Release,
+		Directed, Directedness, Ensure, Graph, GraphDerefMut, GraphError, Release, Undirected,
+		Violation,
 	},
 };
-use num_traits::{PrimInt, Unsigned, Zero};
-use std::borrow::Borrow;
+use num_traits::Zero;
+use std::{
+	borrow::Borrow,
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
+};
 
 /// A marker trait for graphs that are connected.
 ///
@@ -21,8 +27,8 @@ pub trait Connected: Unilateral
 	/// Calculates the maximum distance between the designated vertex and any other vertex ([the eccentricity](https://mathworld.wolfram.com/GraphEccentricity.html)).
 	fn eccentricity(&self) -> Self::EdgeWeight
 	where
-		Self: EdgeCount + VertexIn<1> + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + VertexIn<1> + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		// We search for all the shortest paths, the eccentricity is the longest one
 		DijkstraShortestPaths::distances(self).fold(
@@ -43,8 +49,8 @@ pub trait Connected: Unilateral
 	/// Calculates the maximum eccentricity of the graph ([the diameter](https://mathworld.wolfram.com/GraphDiameter.html)).
 	fn diameter(&self) -> Self::EdgeWeight
 	where
-		Self: EdgeCount + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		self.all_vertices()
 			.fold(Self::EdgeWeight::zero(), |max_ecc, v| {
@@ -63,8 +69,8 @@ pub trait Connected: Unilateral
 	/// Calculates the minimum eccentricity of the graph ([the radius](https://mathworld.wolfram.com/GraphDiameter.html)).
 	fn radius(&self) -> Self::EdgeWeight
 	where
-		Self: EdgeCount + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		self.all_vertices()
 			.fold(Self::EdgeWeight::zero(), |min_ecc, v| {
@@ -83,8 +89,8 @@ pub trait Connected: Unilateral
 	/// Returns the vertices with eccentricity equal to the radius ([the centers](https://mathworld.wolfram.com/GraphCenter.html)).
 	fn centers(&self) -> impl Iterator<Item = Self::Vertex>
 	where
-		Self: EdgeCount + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		let radius = self.radius();
 		self.all_vertices()
@@ -92,8 +98,17 @@ pub trait Connected: Unilateral
 	}
 }
 
-#[derive(Clone, Debug)]
-pub struct ConnectedGraph<C: Ensure>(C);
+/// Ensures the underlying graph is connected.
+///
+/// For undirected graphs, which vertices are connected is kept track of as
+/// edges are added and removed, such that removing an edge takes amortized
+/// polylogarithmic time instead of searching the graph.
+/// See [ConnectedMutation] for details.
+///
+/// For directed graphs, removing edges or vertices searches the whole graph
+/// to check that it is still strongly connected.
+#[derive(Clone)]
+pub struct ConnectedGraph<C: Ensure>(C, DynamicConnectivity<<C::Graph as Graph>::Vertex>);
 
 impl<C: Ensure> ConnectedGraph<C>
 {
@@ -101,7 +116,15 @@ impl<C: Ensure> ConnectedGraph<C>
 	/// This method does not check for this!!
 	pub fn new(c: C) -> Self
 	{
-		Self(c)
+		Self(c, DynamicConnectivity::new())
+	}
+}
+
+impl<C: Ensure + Debug> Debug for ConnectedGraph<C>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("ConnectedGraph").field(&self.0).finish()
 	}
 }
 
@@ -109,76 +132,308 @@ impl<C: Ensure> Ensure for ConnectedGraph<C>
 {
 	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
 	{
-		Self(c)
+		Self::new(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		let g = c.graph();
-		let v_count = g.all_vertices().count();
 
-		if v_count > 0
+		if let Some(v) = g.all_vertices().next()
 		{
-			let v = g.all_vertices().next().unwrap();
 			let g = VertexInGraph::ensure_unchecked(g.release(), [v]);
-			let traverse_count = new_search(&g).retain(&g).count();
-			if (traverse_count + 1) == v_count
+			let reached: Vec<_> = new_search(&g).retain(&g).collect();
+			if let Some(u) = g.all_vertices().find(|u| *u != v && !reached.contains(u))
 			{
-				// If it's undirected, no more needs to be done
-				if let Ok(g) = DirectedGraph::ensure(g, ())
+				return Err(Violation::Unreachable(v, u));
+			}
+
+			// If it's undirected, no more needs to be done
+			if let Ok(g) = DirectedGraph::ensure(g, ())
+			{
+				let reverse = ReverseGraph::new(g);
+				let reached: Vec<_> = new_search(&reverse).retain(&reverse).collect();
+				let unreached = reverse
+					.all_vertices()
+					.find(|u| *u != v && !reached.contains(u));
+				if let Some(u) = unreached
 				{
-					let reverse = ReverseGraph::new(g);
-					if (new_search(&reverse).retain(&reverse).count() + 1) != v_count
-					{
-						return false;
-					}
+					return Err(Violation::Unreachable(u, v));
 				}
-				return true;
 			}
-			return false;
 		}
-		true
+		Ok(())
+	}
+}
+
+/// How [ConnectedGraph] checks that changes to a graph with this
+/// directedness keep it connected.
+///
+/// For [Undirected] graphs, the ensurer keeps the spanning forests of Holm, de
+/// Lichtenberg and Thorup's dynamic connectivity structure, which requires the
+/// vertices to be hashable.
+/// It is built the first time an edge or vertex is removed, which goes through
+/// the graph's edges once and takes `O(m log n)` time for `m` edges and `n`
+/// vertices. After that, adding an edge takes expected `O(log n)` time and
+/// removing one takes expected amortized `O(log² n)` time, on top of the time
+/// the underlying graph takes.
+/// Removing a vertex removes each of its edges like that, and then takes time
+/// linear in the number of vertices to check that the other vertices kept
+/// their ids. If they didn't, the structure is built again the next time it is
+/// needed.
+/// The structure is also built again if a vertex or edge turns up that it
+/// didn't see added, e.g. because it was added directly to the graph
+/// [ConnectedGraph] wraps.
+///
+/// For [Directed] graphs, removing edges or vertices searches the whole graph,
+/// as no comparable structure is known for strong connectivity.
+pub trait ConnectedMutation<V: Copy + Eq>: Directedness
+{
+	/// Adds an edge to the connected graph.
+	fn add_edge_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		weight: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<(), GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: AddEdge<Vertex = V, Directedness = Self>;
+
+	/// Removes an edge from the connected graph, unless that disconnects it.
+	fn remove_edge_where_weight<C, F>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		f: F,
+	) -> Result<<C::Graph as Graph>::EdgeWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveEdge<Vertex = V, Directedness = Self>,
+		F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool;
+
+	/// Removes a vertex from the connected graph, unless that disconnects it.
+	fn remove_vertex<C>(
+		g: &mut ConnectedGraph<C>,
+		v: V,
+	) -> Result<<C::Graph as Graph>::VertexWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveVertex<Vertex = V, Directedness = Self>;
+
+	/// Adds a new leaf to the connected tree.
+	fn new_leaf_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		parent: V,
+		w: <C::Graph as Graph>::VertexWeight,
+		e: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<V, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: NewLeafUndirected<Vertex = V, Directedness = Self>;
+}
+
+impl<V: Copy + Eq> ConnectedMutation<V> for Directed
+{
+	fn add_edge_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		weight: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<(), GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: AddEdge<Vertex = V, Directedness = Self>,
+	{
+		g.0.graph_mut().add_edge_weighted(source, sink, weight)
+	}
+
+	fn remove_edge_where_weight<C, F>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		f: F,
+	) -> Result<<C::Graph as Graph>::EdgeWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveEdge<Vertex = V, Directedness = Self>,
+		F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool,
+	{
+		proxy_remove_edge_where_weight::<ConnectedGraph<_>, _, _>(
+			g.0.graph_mut(),
+			&source,
+			&sink,
+			f,
+		)
+	}
+
+	fn remove_vertex<C>(
+		g: &mut ConnectedGraph<C>,
+		v: V,
+	) -> Result<<C::Graph as Graph>::VertexWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveVertex<Vertex = V, Directedness = Self>,
+	{
+		proxy_remove_vertex::<ConnectedGraph<_>, _>(g.0.graph_mut(), &v)
+	}
+
+	fn new_leaf_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		parent: V,
+		w: <C::Graph as Graph>::VertexWeight,
+		e: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<V, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: NewLeafUndirected<Vertex = V, Directedness = Self>,
+	{
+		g.0.graph_mut().new_leaf_weighted(parent, w, e)
+	}
+}
+
+impl<V: Copy + Eq + Hash> ConnectedMutation<V> for Undirected
+{
+	fn add_edge_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		weight: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<(), GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: AddEdge<Vertex = V, Directedness = Self>,
+	{
+		g.1.add_edge_weighted(g.0.graph_mut(), source, sink, weight)
+	}
+
+	fn remove_edge_where_weight<C, F>(
+		g: &mut ConnectedGraph<C>,
+		source: V,
+		sink: V,
+		f: F,
+	) -> Result<<C::Graph as Graph>::EdgeWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveEdge<Vertex = V, Directedness = Self>,
+		F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool,
+	{
+		g.1.remove_edge_where_weight::<ConnectedGraph<C>, _, _>(g.0.graph_mut(), source, sink, f)
+	}
+
+	fn remove_vertex<C>(
+		g: &mut ConnectedGraph<C>,
+		v: V,
+	) -> Result<<C::Graph as Graph>::VertexWeight, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: RemoveVertex<Vertex = V, Directedness = Self>,
+	{
+		g.1.remove_vertex::<ConnectedGraph<C>, _>(g.0.graph_mut(), v)
+	}
+
+	fn new_leaf_weighted<C>(
+		g: &mut ConnectedGraph<C>,
+		parent: V,
+		w: <C::Graph as Graph>::VertexWeight,
+		e: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<V, GraphError<V>>
+	where
+		C: Ensure + GraphDerefMut,
+		C::Graph: NewLeafUndirected<Vertex = V, Directedness = Self>,
+	{
+		let leaf = g.0.graph_mut().new_leaf_weighted(parent, w, e)?;
+		g.1.add_leaf(parent, leaf);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for ConnectedGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Directedness: ConnectedMutation<<C::Graph as Graph>::Vertex>,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		<Self as Graph>::Directedness::add_edge_weighted(
+			self,
+			*source.borrow(),
+			*sink.borrow(),
+			weight,
+		)
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for ConnectedGraph<C>
 where
 	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Directedness: ConnectedMutation<<C::Graph as Graph>::Vertex>,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
-		proxy_remove_vertex::<ConnectedGraph<_>, _>(self.0.graph_mut(), v.borrow())
+		<Self as Graph>::Directedness::remove_vertex(self, *v.borrow())
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveEdge for ConnectedGraph<C>
 where
 	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Directedness: ConnectedMutation<<C::Graph as Graph>::Vertex>,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
-		proxy_remove_edge_where_weight::<ConnectedGraph<_>, _, _>(
-			self.0.graph_mut(),
-			source.borrow(),
-			sink.borrow(),
+		<Self as Graph>::Directedness::remove_edge_where_weight(
+			self,
+			*source.borrow(),
+			*sink.borrow(),
 			f,
 		)
 	}
 }
 
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for ConnectedGraph<C>
+where
+	C::Graph: NewLeafUndirected,
+	<C::Graph as Graph>::Directedness: ConnectedMutation<<C::Graph as Graph>::Vertex>,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		<Self as Graph>::Directedness::new_leaf_weighted(self, *parent.borrow(), w, e)
+	}
+}
+
 impl<C: Ensure> Weak for ConnectedGraph<C> {}
 impl<C: Ensure> Unilateral for ConnectedGraph<C> {}
 impl<C: Ensure> Connected for ConnectedGraph<C> {}
 
 impl_ensurer! {
-	use<C> ConnectedGraph<C>: Ensure, Connected, Unilateral, Weak, RemoveVertex, RemoveEdge,
+	use<C> ConnectedGraph<C>: Ensure, Connected, Unilateral, Weak, AddEdge, RemoveVertex,
+	RemoveEdge, NewLeafUndirected,
 	// A new vertex wouldn't be connected to the rest of the graph
 	NewVertex
 	as (self.0) : C
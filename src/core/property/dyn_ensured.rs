@@ -0,0 +1,488 @@
+use crate::core::{
+	property::{
+		AcyclicGraph, AddEdge, BipartiteGraph, ConnectedGraph, ForestGraph, HasVertexGraph,
+		NewLeafDirected, NewLeafUndirected, NewVertex, NoLoopsGraph, RemoveEdge, RemoveVertex,
+		TreeGraph, UniqueGraph,
+	},
+	proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
+	Directed, Ensure, EnsureError, Graph, GraphDerefMut, GraphError, Violation,
+};
+use std::{
+	borrow::Borrow,
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
+	ops::{BitOr, BitOrAssign},
+};
+
+/// A property that can be chosen at runtime for [DynEnsuredGraph].
+///
+/// Each property is checked by the `validate` of the ensurer for it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DynProperty
+{
+	/// Checked by [UniqueGraph].
+	Unique,
+	/// Checked by [NoLoopsGraph].
+	NoLoops,
+	/// Checked by [HasVertexGraph].
+	HasVertex,
+	/// Checked by [AcyclicGraph].
+	Acyclic,
+	/// Checked by [BipartiteGraph].
+	Bipartite,
+	/// Checked by [ConnectedGraph].
+	Connected,
+	/// Checked by [ForestGraph].
+	Forest,
+	/// Checked by [TreeGraph].
+	Tree,
+}
+
+impl DynProperty
+{
+	/// All the properties, in the order they are checked.
+	pub const ALL: [Self; 8] = [
+		Self::Unique,
+		Self::NoLoops,
+		Self::HasVertex,
+		Self::Acyclic,
+		Self::Bipartite,
+		Self::Connected,
+		Self::Forest,
+		Self::Tree,
+	];
+
+	fn bit(self) -> u16
+	{
+		1 << self as u16
+	}
+
+	/// Checks whether the given graph has this property, using the ensurer for
+	/// it.
+	pub fn validate<C: Ensure>(self, c: &C) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	where
+		<C::Graph as Graph>::Vertex: Hash,
+	{
+		match self
+		{
+			Self::Unique => UniqueGraph::<C>::validate(c, &()),
+			Self::NoLoops => NoLoopsGraph::<C>::validate(c, &()),
+			Self::HasVertex => HasVertexGraph::<C>::validate(c, &()),
+			Self::Acyclic => AcyclicGraph::<C>::validate(c, &()),
+			Self::Bipartite => BipartiteGraph::<C>::validate(c, &()),
+			Self::Connected => ConnectedGraph::<C>::validate(c, &()),
+			Self::Forest => ForestGraph::<C>::validate(c, &()),
+			Self::Tree => TreeGraph::<C>::validate(c, &()),
+		}
+	}
+
+	/// Creates an error for the ensurer of this property over `C` rejecting a
+	/// graph with the given violation.
+	fn error<C: Ensure>(
+		self,
+		violation: Violation<<C::Graph as Graph>::Vertex>,
+	) -> EnsureError<<C::Graph as Graph>::Vertex>
+	{
+		match self
+		{
+			Self::Unique => EnsureError::new::<UniqueGraph<C>>(violation),
+			Self::NoLoops => EnsureError::new::<NoLoopsGraph<C>>(violation),
+			Self::HasVertex => EnsureError::new::<HasVertexGraph<C>>(violation),
+			Self::Acyclic => EnsureError::new::<AcyclicGraph<C>>(violation),
+			Self::Bipartite => EnsureError::new::<BipartiteGraph<C>>(violation),
+			Self::Connected => EnsureError::new::<ConnectedGraph<C>>(violation),
+			Self::Forest => EnsureError::new::<ForestGraph<C>>(violation),
+			Self::Tree => EnsureError::new::<TreeGraph<C>>(violation),
+		}
+	}
+}
+
+impl BitOr for DynProperty
+{
+	type Output = DynProperties;
+
+	fn bitor(self, rhs: Self) -> DynProperties
+	{
+		DynProperties::from(self) | rhs
+	}
+}
+
+/// A set of [properties](DynProperty), created using `|`.
+///
+/// ```
+/// # use graphene::core::property::{DynProperties, DynProperty};
+/// let props = DynProperty::Acyclic | DynProperty::Connected | DynProperty::Unique;
+///
+/// assert!(props.contains(DynProperty::Connected));
+/// assert!(!props.contains(DynProperty::NoLoops));
+/// assert!(DynProperties::empty().is_empty());
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DynProperties(u16);
+
+impl DynProperties
+{
+	/// The set without any properties.
+	pub fn empty() -> Self
+	{
+		Self(0)
+	}
+
+	pub fn is_empty(&self) -> bool
+	{
+		self.0 == 0
+	}
+
+	pub fn contains(&self, p: DynProperty) -> bool
+	{
+		self.0 & p.bit() != 0
+	}
+
+	pub fn insert(&mut self, p: DynProperty)
+	{
+		self.0 |= p.bit();
+	}
+
+	pub fn remove(&mut self, p: DynProperty)
+	{
+		self.0 &= !p.bit();
+	}
+
+	/// Returns the properties in the set, in the order they are checked.
+	pub fn iter(&self) -> impl Iterator<Item = DynProperty>
+	{
+		let props = *self;
+		DynProperty::ALL
+			.into_iter()
+			.filter(move |p| props.contains(*p))
+	}
+
+	/// Checks the properties on the given graph in order, returning the error
+	/// of the first one that doesn't hold.
+	fn validate<C: Ensure>(&self, c: &C) -> Result<(), EnsureError<<C::Graph as Graph>::Vertex>>
+	where
+		<C::Graph as Graph>::Vertex: Hash,
+	{
+		self.iter()
+			.try_for_each(|p| p.validate(c).map_err(|v| p.error::<C>(v)))
+	}
+}
+
+impl From<DynProperty> for DynProperties
+{
+	fn from(p: DynProperty) -> Self
+	{
+		Self(p.bit())
+	}
+}
+
+impl FromIterator<DynProperty> for DynProperties
+{
+	fn from_iter<I: IntoIterator<Item = DynProperty>>(iter: I) -> Self
+	{
+		iter.into_iter().fold(Self::empty(), |props, p| props | p)
+	}
+}
+
+impl<P: Into<DynProperties>> BitOr<P> for DynProperties
+{
+	type Output = Self;
+
+	fn bitor(self, rhs: P) -> Self
+	{
+		Self(self.0 | rhs.into().0)
+	}
+}
+
+impl<P: Into<DynProperties>> BitOrAssign<P> for DynProperties
+{
+	fn bitor_assign(&mut self, rhs: P)
+	{
+		self.0 |= rhs.into().0;
+	}
+}
+
+impl Debug for DynProperties
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+/// Ensures a set of properties chosen at runtime.
+///
+/// The payload is the set of properties, which are validated by the ensurers
+/// for them. Every mutation is first tried on a proxy of the graph, and
+/// rejected if any of the properties wouldn't hold afterwards. Rejections name
+/// the ensurer of the first property that failed.
+///
+/// Unlike the static ensurers, no property traits are implemented for the
+/// properties in the set, and every mutation checks the whole graph.
+/// That includes adding leaves to a wrapped tree, and mutations through
+/// [graph_mut](crate::core::GraphDerefMut::graph_mut), which returns the
+/// ensurer itself.
+///
+/// ```
+/// # use graphene::{
+/// #   common::AdjListGraph,
+/// #   core::{property::{AddEdge, DynEnsuredGraph, DynProperty, NewVertex}, Ensure,
+/// #     Undirected},
+/// # };
+/// let mut g = AdjListGraph::<(), (), Undirected>::new();
+/// let v1 = g.new_vertex().unwrap();
+/// let v2 = g.new_vertex().unwrap();
+/// g.add_edge(v1, v2).unwrap();
+///
+/// let mut g = DynEnsuredGraph::ensure(g, DynProperty::Acyclic | DynProperty::Connected).unwrap();
+///
+/// // A new vertex would not be connected to the others
+/// assert!(g.new_vertex().is_err());
+/// // A second edge would make a cycle
+/// assert!(g.add_edge(v1, v2).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynEnsuredGraph<C: Ensure>(C, DynProperties);
+
+impl<C: Ensure> DynEnsuredGraph<C>
+{
+	/// The properties being ensured.
+	pub fn properties(&self) -> DynProperties
+	{
+		self.1
+	}
+}
+
+impl<C: Ensure> Ensure for DynEnsuredGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, p: DynProperties) -> Self
+	{
+		Self(c, p)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &DynProperties) -> bool
+	{
+		p.validate(c).is_ok()
+	}
+
+	fn validate(
+		c: &Self::Ensured,
+		p: &DynProperties,
+	) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		p.validate(c).map_err(|err| err.violation)
+	}
+
+	/// Like the default, but names the ensurer of the property that doesn't
+	/// hold.
+	fn ensure(
+		c: Self::Ensured,
+		p: DynProperties,
+	) -> Result<Self, EnsureError<<C::Graph as Graph>::Vertex>>
+	{
+		p.validate(&c)?;
+		Ok(Self::ensure_unchecked(c, p))
+	}
+}
+
+/// Checks the properties on the given proxy with vertices added or removed,
+/// converting any error to the underlying graph's vertices.
+///
+/// Vertices only in the proxy can't be converted, so violations mentioning
+/// them become [Unspecified](Violation::Unspecified).
+fn validate_proxy<P: Ensure, V: Copy + Eq + Hash>(
+	props: DynProperties,
+	proxy: &P,
+) -> Result<(), GraphError<V>>
+where
+	P::Graph: Graph<Vertex = ProxyVertex<V>>,
+{
+	props.validate(proxy).map_err(|err| {
+		let mut new = false;
+		let err = err.map_vertices(|v| {
+			match v
+			{
+				ProxyVertex::Underlying(v) => Some(v),
+				ProxyVertex::New(_) =>
+				{
+					new = true;
+					None
+				},
+			}
+		});
+		let violation = if new
+		{
+			Violation::Unspecified
+		}
+		else
+		{
+			err.violation.map_vertices(Option::unwrap)
+		};
+		GraphError::Rejected(EnsureError {
+			violation,
+			ensurer: err.ensurer,
+			layer: err.layer,
+		})
+	})
+}
+
+impl<C: Ensure> DynEnsuredGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Checks the properties with a new leaf on the given parent, with an edge
+	/// from the parent if `to_new`.
+	fn validate_new_leaf(
+		&self,
+		parent: <C::Graph as Graph>::Vertex,
+		to_new: bool,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		GraphError::check_vertices(self, [parent])?;
+		let mut vertices = VertexProxyGraph::new(self.0.graph());
+		let leaf = vertices.new_vertex().map_err(|_| GraphError::Unsupported)?;
+		let parent = ProxyVertex::Underlying(parent);
+		let (source, sink) = if to_new
+		{
+			(parent, leaf)
+		}
+		else
+		{
+			(leaf, parent)
+		};
+		let mut proxy = EdgeProxyGraph::new(&vertices);
+		proxy
+			.add_edge(source, sink)
+			.map_err(|_| GraphError::Unsupported)?;
+		validate_proxy(self.1, &proxy)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for DynEnsuredGraph<C>
+where
+	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let mut proxy = VertexProxyGraph::new(self.0.graph());
+		proxy.new_vertex().map_err(|_| GraphError::Unsupported)?;
+		validate_proxy(self.1, &proxy)?;
+		self.0.graph_mut().new_vertex_weighted(w)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for DynEnsuredGraph<C>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let mut proxy = VertexProxyGraph::new(self.0.graph());
+		proxy
+			.remove_vertex(ProxyVertex::Underlying(*v.borrow()))
+			.map_err(|_| GraphError::MissingVertex(*v.borrow()))?;
+		validate_proxy(self.1, &proxy)?;
+		self.0.graph_mut().remove_vertex(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for DynEnsuredGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let mut proxy = EdgeProxyGraph::new(self.0.graph());
+		proxy.add_edge(source.borrow(), sink.borrow())?;
+		self.1.validate(&proxy)?;
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveEdge for DynEnsuredGraph<C>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self, [source, sink])?;
+		if !self.edges_between(source, sink).any(|w| f(w.borrow()))
+		{
+			return Err(GraphError::MissingEdge(source, sink));
+		}
+
+		// The properties don't depend on edge weights, so removing any of the
+		// matching edges from the proxy is the same
+		let mut proxy = EdgeProxyGraph::new(self.0.graph());
+		proxy.remove_edge(source, sink)?;
+		self.1.validate(&proxy)?;
+		self.0.graph_mut().remove_edge_where_weight(source, sink, f)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for DynEnsuredGraph<C>
+where
+	C::Graph: NewLeafUndirected,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		self.validate_new_leaf(*parent.borrow(), true)?;
+		self.0.graph_mut().new_leaf_weighted(parent, w, e)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafDirected for DynEnsuredGraph<C>
+where
+	C::Graph: NewLeafDirected<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		self.validate_new_leaf(*parent.borrow(), to_new)?;
+		self.0.graph_mut().new_leaf_weighted(parent, to_new, w, e)
+	}
+}
+
+impl_ensurer! {
+	use<C> DynEnsuredGraph<C>: Ensure, NewVertex, RemoveVertex, AddEdge, RemoveEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+	as (self.1) : DynProperties
+}
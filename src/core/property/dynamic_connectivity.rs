@@ -0,0 +1,871 @@
+use crate::core::{
+	property::{AddEdge, RemoveEdge, RemoveVertex},
+	Directedness, Graph, GraphError, Violation,
+};
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+	mem,
+};
+
+/// Keeps track of which vertices of a graph are connected, ignoring edge
+/// directions, as edges are added and removed.
+///
+/// This is the structure of Holm, de Lichtenberg and Thorup: each edge has a
+/// level, and for each level a spanning forest of the edges of that level or
+/// higher is kept as Euler tour trees, held in treaps.
+/// When a tree edge is removed, the smaller of the two trees it leaves is
+/// searched for a replacement, level by level from the edge's own. The edges
+/// found not to be replacements move up a level, which they can only do
+/// `O(log n)` times.
+///
+/// The structure is built the first time it is needed.
+/// See [ConnectedMutation](super::ConnectedMutation) for the time each
+/// operation takes.
+#[derive(Clone)]
+pub(crate) struct DynamicConnectivity<V>(Option<Forests<V>>);
+
+/// Marks a node that represents a vertex, not an edge.
+const VERTEX: u8 = 1;
+/// Marks the node of a vertex with non-tree edges at the node's level.
+const NON_TREE: u8 = 2;
+/// Marks one of the nodes of a tree edge at the edge's own level.
+const TREE_EDGE: u8 = 4;
+/// Stands for a missing node.
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct Forests<V>
+{
+	/// The index of each vertex.
+	indices: HashMap<V, usize>,
+
+	/// The node and non-tree edges of each vertex at each level.
+	/// Levels above those of all the vertex's edges may be missing.
+	vertices: Vec<Vec<VertexLevel>>,
+
+	/// The index of the edge between each pair of vertices, given by their
+	/// indices, lowest first.
+	edge_indices: HashMap<(usize, usize), usize>,
+
+	edges: Vec<Edge>,
+
+	/// Indices in `edges` that are free to reuse.
+	free_edges: Vec<usize>,
+
+	/// The nodes of the treaps holding the Euler tours of the trees.
+	nodes: Vec<Node>,
+
+	/// Indices in `nodes` that are free to reuse.
+	free_nodes: Vec<usize>,
+
+	/// The state used to give nodes their pseudo-random priorities.
+	seed: u64,
+}
+
+#[derive(Clone, Default)]
+struct VertexLevel
+{
+	/// The vertex's node in the Euler tours of the level, if it has been
+	/// created.
+	node: Option<usize>,
+
+	/// The non-tree edges of the level that are incident on the vertex.
+	non_tree: Vec<usize>,
+}
+
+/// All the edges between two vertices.
+///
+/// Parallel edges are counted instead of kept apart, as only the last one
+/// to be removed can disconnect the vertices.
+#[derive(Clone)]
+struct Edge
+{
+	/// The indices of the vertices, lowest first.
+	ends: (usize, usize),
+
+	/// The number of parallel edges.
+	count: usize,
+
+	level: usize,
+
+	/// If this is a tree edge, its two nodes in the Euler tours of each level
+	/// up to and including its own.
+	arcs: Vec<(usize, usize)>,
+
+	/// If this is a non-tree edge, its index in the non-tree edges of each of
+	/// its ends.
+	positions: (usize, usize),
+}
+
+/// A node in a treap holding an Euler tour in order.
+///
+/// Each vertex has a node in each level, and each tree edge has two nodes in
+/// each level it is in, one for each direction.
+#[derive(Clone)]
+struct Node
+{
+	parent: usize,
+	left: usize,
+	right: usize,
+	priority: u64,
+
+	/// The index of the vertex or edge.
+	owner: usize,
+
+	/// The number of vertex nodes in the subtree.
+	vertex_count: usize,
+
+	/// The marks of the node.
+	flags: u8,
+
+	/// The marks of any node in the subtree.
+	subtree_flags: u8,
+}
+
+impl<V> DynamicConnectivity<V>
+{
+	pub(crate) fn new() -> Self
+	{
+		Self(None)
+	}
+}
+
+impl<V: Copy + Eq + Hash> DynamicConnectivity<V>
+{
+	/// Adds an edge to the graph.
+	pub(crate) fn add_edge_weighted<G>(
+		&mut self,
+		graph: &mut G,
+		source: V,
+		sink: V,
+		weight: G::EdgeWeight,
+	) -> Result<(), GraphError<V>>
+	where
+		G: AddEdge<Vertex = V> + ?Sized,
+	{
+		graph.add_edge_weighted(source, sink, weight)?;
+		// Loops never connect anything
+		if source != sink
+		{
+			self.insert(source, sink);
+		}
+		Ok(())
+	}
+
+	/// Records that a new leaf was added to the graph with an edge to the
+	/// given parent.
+	pub(crate) fn add_leaf(&mut self, parent: V, leaf: V)
+	{
+		if let Some(forests) = &mut self.0
+		{
+			forests.add_vertex(leaf);
+		}
+		self.insert(parent, leaf);
+	}
+
+	/// Records an edge between two different vertices of the graph, if the
+	/// structure is built.
+	fn insert(&mut self, source: V, sink: V)
+	{
+		if let Some(forests) = &mut self.0
+		{
+			match (forests.indices.get(&source), forests.indices.get(&sink))
+			{
+				(Some(&x), Some(&y)) => forests.insert(x, y, 1),
+				// A vertex was added without the structure seeing it
+				_ => self.0 = None,
+			}
+		}
+	}
+
+	/// Returns the structure and the indices of the given vertices, building
+	/// the structure anew if it is missing or doesn't know one of them.
+	fn forests<G>(&mut self, graph: &G, vs: &[V]) -> (&mut Forests<V>, Vec<usize>)
+	where
+		G: Graph<Vertex = V> + ?Sized,
+	{
+		let known = self.0.as_ref().and_then(|forests| {
+			vs.iter()
+				.map(|v| forests.indices.get(v).copied())
+				.collect::<Option<Vec<_>>>()
+		});
+		match known
+		{
+			Some(xs) => (self.0.as_mut().unwrap(), xs),
+			None =>
+			{
+				let forests = self.0.insert(Forests::new(graph));
+				let xs = vs
+					.iter()
+					.map(|v| forests.indices.get(v).copied())
+					.collect::<Option<_>>()
+					.expect("the vertices are in the graph");
+				(forests, xs)
+			},
+		}
+	}
+
+	/// Removes an edge from the graph if it doesn't disconnect its source and
+	/// sink, otherwise rejects it on behalf of the ensurer `E`.
+	pub(crate) fn remove_edge_where_weight<E, G, F>(
+		&mut self,
+		graph: &mut G,
+		source: V,
+		sink: V,
+		f: F,
+	) -> Result<G::EdgeWeight, GraphError<V>>
+	where
+		G: RemoveEdge<Vertex = V> + ?Sized,
+		F: Fn(&G::EdgeWeight) -> bool,
+	{
+		GraphError::check_vertices(graph, [source, sink])?;
+		if !graph.edges_between(source, sink).any(|w| f(&w))
+		{
+			return Err(GraphError::MissingEdge(source, sink));
+		}
+
+		// Loops never connect anything
+		if source != sink
+		{
+			let (forests, xs) = self.forests(graph, &[source, sink]);
+			let (x, y) = (xs[0], xs[1]);
+			match forests.remove(x, y)
+			{
+				Some(true) => (),
+				Some(false) =>
+				{
+					forests.insert(x, y, 1);
+					return Err(GraphError::rejected::<E>(Violation::Unreachable(
+						source, sink,
+					)));
+				},
+				// The edge was added without the structure seeing it
+				None =>
+				{
+					self.0 = None;
+					return self.remove_edge_where_weight::<E, G, F>(graph, source, sink, f);
+				},
+			}
+		}
+		graph.remove_edge_where_weight(source, sink, f)
+	}
+
+	/// Removes a vertex from the graph if the remaining vertices stay
+	/// connected, otherwise rejects it on behalf of the ensurer `E`.
+	pub(crate) fn remove_vertex<E, G>(
+		&mut self,
+		graph: &mut G,
+		v: V,
+	) -> Result<G::VertexWeight, GraphError<V>>
+	where
+		G: RemoveVertex<Vertex = V> + ?Sized,
+	{
+		GraphError::check_vertices(graph, [v])?;
+		let mut seen = HashSet::new();
+		let mut vs: Vec<_> = graph
+			.edges_incident_on(v)
+			.map(|(n, _)| n)
+			.filter(|n| *n != v && seen.insert(*n))
+			.collect();
+		vs.push(v);
+		let (forests, mut xs) = self.forests(graph, &vs);
+		let x = xs.pop().unwrap();
+		vs.pop();
+		let neighbors: Vec<_> = vs.into_iter().zip(xs).collect();
+
+		let mut counts = Vec::with_capacity(neighbors.len());
+		for (_, y) in &neighbors
+		{
+			match forests.remove_all(x, *y)
+			{
+				Some(count) => counts.push(count),
+				// An edge was added without the structure seeing it
+				None =>
+				{
+					self.0 = None;
+					return self.remove_vertex::<E, G>(graph, v);
+				},
+			}
+		}
+
+		// Any vertex that was connected to the rest through the vertex is
+		// connected to one of its neighbors, so they must all stay connected
+		let unreachable = neighbors.split_first().and_then(|((n0, y0), rest)| {
+			rest.iter()
+				.find(|(_, y)| !forests.connected(*y0, *y, 0))
+				.map(|(n, _)| (*n0, *n))
+		});
+		if let Some((n0, n)) = unreachable
+		{
+			for ((_, y), count) in neighbors.into_iter().zip(counts)
+			{
+				forests.insert(x, y, count);
+			}
+			return Err(GraphError::rejected::<E>(Violation::Unreachable(n0, n)));
+		}
+
+		let weight = graph.remove_vertex(v)?;
+		forests.remove_vertex(v);
+
+		// Removing a vertex may change the ids of other vertices
+		if forests.indices.len() != graph.all_vertices().count()
+			|| graph
+				.all_vertices()
+				.any(|u| !forests.indices.contains_key(&u))
+		{
+			self.0 = None;
+		}
+		Ok(weight)
+	}
+}
+
+impl<V: Copy + Eq + Hash> Forests<V>
+{
+	/// Builds the structure for the given graph, with all edges at level 0.
+	fn new<G>(graph: &G) -> Self
+	where
+		G: Graph<Vertex = V> + ?Sized,
+	{
+		let indices: HashMap<_, _> = graph
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i))
+			.collect();
+		let mut forests = Self {
+			vertices: vec![Vec::new(); indices.len()],
+			indices,
+			edge_indices: HashMap::new(),
+			edges: Vec::new(),
+			free_edges: Vec::new(),
+			nodes: Vec::new(),
+			free_nodes: Vec::new(),
+			seed: 0,
+		};
+
+		for (x, v) in graph.all_vertices().enumerate()
+		{
+			for (n, _) in graph.edges_sourced_in(v)
+			{
+				let y = forests.indices.get(&n).copied();
+				// Undirected edges are sourced in both of their vertices
+				if let Some(y) = y.filter(|y| x != *y && (G::Directedness::directed() || x < *y))
+				{
+					forests.insert(x, y, 1);
+				}
+			}
+		}
+		forests
+	}
+
+	/// Gives a new vertex of the graph an index.
+	fn add_vertex(&mut self, v: V)
+	{
+		self.indices.insert(v, self.vertices.len());
+		self.vertices.push(Vec::new());
+	}
+
+	/// Forgets the given vertex, which must have no edges.
+	fn remove_vertex(&mut self, v: V)
+	{
+		let x = self.indices.remove(&v).unwrap();
+		for level in mem::take(&mut self.vertices[x])
+		{
+			self.free_nodes.extend(level.node);
+		}
+	}
+}
+
+impl<V> Forests<V>
+{
+	/// Adds the given number of edges between the two different vertices.
+	fn insert(&mut self, x: usize, y: usize, count: usize)
+	{
+		let ends = (x.min(y), x.max(y));
+		if let Some(&e) = self.edge_indices.get(&ends)
+		{
+			self.edges[e].count += count;
+			return;
+		}
+
+		let edge = Edge {
+			ends,
+			count,
+			level: 0,
+			arcs: Vec::new(),
+			positions: (0, 0),
+		};
+		let e = if let Some(e) = self.free_edges.pop()
+		{
+			self.edges[e] = edge;
+			e
+		}
+		else
+		{
+			self.edges.push(edge);
+			self.edges.len() - 1
+		};
+		self.edge_indices.insert(ends, e);
+
+		if self.connected(x, y, 0)
+		{
+			self.add_non_tree(e, 0);
+		}
+		else
+		{
+			self.link(e, 0);
+		}
+	}
+
+	/// Removes one edge between the two vertices, returning whether they are
+	/// still connected, or `None` if there is no edge between them.
+	fn remove(&mut self, x: usize, y: usize) -> Option<bool>
+	{
+		let e = *self.edge_indices.get(&(x.min(y), x.max(y)))?;
+		if self.edges[e].count > 1
+		{
+			self.edges[e].count -= 1;
+			Some(true)
+		}
+		else
+		{
+			Some(self.delete(e))
+		}
+	}
+
+	/// Removes all edges between the two vertices, returning how many there
+	/// were, or `None` if there were none.
+	fn remove_all(&mut self, x: usize, y: usize) -> Option<usize>
+	{
+		let e = *self.edge_indices.get(&(x.min(y), x.max(y)))?;
+		let count = self.edges[e].count;
+		self.delete(e);
+		Some(count)
+	}
+
+	/// Deletes the given edge, returning whether its ends are still
+	/// connected.
+	fn delete(&mut self, e: usize) -> bool
+	{
+		let (x, y) = self.edges[e].ends;
+		self.edge_indices.remove(&(x, y));
+		self.free_edges.push(e);
+
+		if self.edges[e].arcs.is_empty()
+		{
+			self.remove_non_tree(e);
+			return true;
+		}
+
+		let level = self.edges[e].level;
+		for (a, b) in mem::take(&mut self.edges[e].arcs)
+		{
+			self.cut(a, b);
+		}
+		(0..=level).rev().any(|i| self.replace(x, y, i))
+	}
+
+	/// Looks for an edge of the given level that reconnects the trees of the
+	/// two vertices in that level, and makes it a tree edge.
+	///
+	/// The edges of the smaller tree are moved up a level, as they are still
+	/// connected in that tree.
+	fn replace(&mut self, x: usize, y: usize, level: usize) -> bool
+	{
+		let small = if self.tree_size(x, level) <= self.tree_size(y, level)
+		{
+			x
+		}
+		else
+		{
+			y
+		};
+
+		// The small tree's edges move up first, so that its non-tree edges may
+		// follow
+		while let Some(n) = self.find_flagged(self.tree_root(small, level), TREE_EDGE)
+		{
+			let e = self.nodes[n].owner;
+			self.set_flag(n, TREE_EDGE, false);
+			self.edges[e].level = level + 1;
+			self.link(e, level + 1);
+		}
+
+		while let Some(n) = self.find_flagged(self.tree_root(small, level), NON_TREE)
+		{
+			let v = self.nodes[n].owner;
+			let e = *self.vertices[v][level].non_tree.last().unwrap();
+			self.remove_non_tree(e);
+
+			let (v1, v2) = self.edges[e].ends;
+			if self.connected(v1, v2, level)
+			{
+				self.add_non_tree(e, level + 1);
+			}
+			else
+			{
+				self.edges[e].level = level;
+				for i in 0..=level
+				{
+					self.link(e, i);
+				}
+				return true;
+			}
+		}
+		false
+	}
+
+	/// Whether the two vertices are in the same tree of the given level.
+	fn connected(&self, x: usize, y: usize, level: usize) -> bool
+	{
+		x == y || {
+			let root = self.tree_root(x, level);
+			root != NIL && root == self.tree_root(y, level)
+		}
+	}
+
+	/// The number of vertices in the tree of the given level containing the
+	/// vertex.
+	fn tree_size(&self, x: usize, level: usize) -> usize
+	{
+		match self.tree_root(x, level)
+		{
+			NIL => 1,
+			root => self.nodes[root].vertex_count,
+		}
+	}
+
+	/// The root of the treap of the tree of the given level containing the
+	/// vertex, or `NIL` if the vertex has no node in the level.
+	fn tree_root(&self, x: usize, level: usize) -> usize
+	{
+		self.vertices[x]
+			.get(level)
+			.and_then(|l| l.node)
+			.map_or(NIL, |n| self.root(n))
+	}
+
+	/// Returns the node of the vertex in the given level, creating it if
+	/// needed.
+	fn vertex_node(&mut self, x: usize, level: usize) -> usize
+	{
+		if self.vertices[x].len() <= level
+		{
+			self.vertices[x].resize_with(level + 1, Default::default);
+		}
+		match self.vertices[x][level].node
+		{
+			Some(n) => n,
+			None =>
+			{
+				let n = self.new_node(x, VERTEX);
+				self.vertices[x][level].node = Some(n);
+				n
+			},
+		}
+	}
+
+	/// Makes the edge a non-tree edge of the given level.
+	fn add_non_tree(&mut self, e: usize, level: usize)
+	{
+		let (x, y) = self.edges[e].ends;
+		self.edges[e].level = level;
+		self.edges[e].positions = (
+			self.push_non_tree(x, level, e),
+			self.push_non_tree(y, level, e),
+		);
+	}
+
+	fn push_non_tree(&mut self, x: usize, level: usize, e: usize) -> usize
+	{
+		let n = self.vertex_node(x, level);
+		let non_tree = &mut self.vertices[x][level].non_tree;
+		non_tree.push(e);
+		let position = non_tree.len() - 1;
+		if position == 0
+		{
+			self.set_flag(n, NON_TREE, true);
+		}
+		position
+	}
+
+	/// Takes the non-tree edge out of the lists of its ends.
+	fn remove_non_tree(&mut self, e: usize)
+	{
+		let (x, y) = self.edges[e].ends;
+		let (px, py) = self.edges[e].positions;
+		let level = self.edges[e].level;
+		self.swap_remove_non_tree(x, level, px);
+		self.swap_remove_non_tree(y, level, py);
+	}
+
+	fn swap_remove_non_tree(&mut self, x: usize, level: usize, position: usize)
+	{
+		let non_tree = &mut self.vertices[x][level].non_tree;
+		non_tree.swap_remove(position);
+		if let Some(&moved) = non_tree.get(position)
+		{
+			let moved = &mut self.edges[moved];
+			if moved.ends.0 == x
+			{
+				moved.positions.0 = position;
+			}
+			else
+			{
+				moved.positions.1 = position;
+			}
+		}
+		else if non_tree.is_empty()
+		{
+			let n = self.vertices[x][level].node.unwrap();
+			self.set_flag(n, NON_TREE, false);
+		}
+	}
+
+	/// Joins the trees of the edge's ends in the given level using the edge.
+	///
+	/// Must be called for each level in order, from 0 up to the edge's level.
+	fn link(&mut self, e: usize, level: usize)
+	{
+		let (x, y) = self.edges[e].ends;
+		let flags = if level == self.edges[e].level
+		{
+			TREE_EDGE
+		}
+		else
+		{
+			0
+		};
+		let (xn, yn) = (self.vertex_node(x, level), self.vertex_node(y, level));
+		let (a, b) = (self.new_node(e, flags), self.new_node(e, 0));
+
+		// Starting both tours at the ends, the joined tour goes from x's tour
+		// to y's and back
+		let x_tour = self.reroot(xn);
+		let y_tour = self.reroot(yn);
+		let tour = self.merge(x_tour, a);
+		let tour = self.merge(tour, y_tour);
+		self.merge(tour, b);
+		self.edges[e].arcs.push((a, b));
+	}
+
+	/// Splits a tree in two by removing the two nodes of an edge from its
+	/// tour.
+	fn cut(&mut self, a: usize, b: usize)
+	{
+		// The tour between the two nodes is that of one of the new trees, while
+		// the parts around them make up the other's
+		let (before, rest) = self.split(a, true);
+		let (before, after) = if self.root(b) == rest
+		{
+			let (_, after) = self.split(b, false);
+			self.split(a, false);
+			self.split(b, true);
+			(before, after)
+		}
+		else
+		{
+			let (_, after) = self.split(a, false);
+			let (before, _) = self.split(b, true);
+			self.split(b, false);
+			(before, after)
+		};
+		self.merge(before, after);
+		self.free_nodes.extend([a, b]);
+	}
+
+	/// Makes the tour of the tree start at the given node, returning the root
+	/// of its treap.
+	fn reroot(&mut self, n: usize) -> usize
+	{
+		let (before, after) = self.split(n, true);
+		self.merge(after, before)
+	}
+
+	fn new_node(&mut self, owner: usize, flags: u8) -> usize
+	{
+		// SplitMix64
+		self.seed = self.seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut z = self.seed;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+		let node = Node {
+			parent: NIL,
+			left: NIL,
+			right: NIL,
+			priority: z ^ (z >> 31),
+			owner,
+			vertex_count: (flags & VERTEX != 0) as usize,
+			flags,
+			subtree_flags: flags,
+		};
+		if let Some(n) = self.free_nodes.pop()
+		{
+			self.nodes[n] = node;
+			n
+		}
+		else
+		{
+			self.nodes.push(node);
+			self.nodes.len() - 1
+		}
+	}
+
+	/// Recalculates the node's vertex count and subtree flags from its
+	/// children.
+	fn update(&mut self, n: usize)
+	{
+		let node = &self.nodes[n];
+		let mut count = (node.flags & VERTEX != 0) as usize;
+		let mut flags = node.flags;
+		for child in [node.left, node.right]
+		{
+			if child != NIL
+			{
+				count += self.nodes[child].vertex_count;
+				flags |= self.nodes[child].subtree_flags;
+			}
+		}
+		self.nodes[n].vertex_count = count;
+		self.nodes[n].subtree_flags = flags;
+	}
+
+	fn set_flag(&mut self, n: usize, flag: u8, on: bool)
+	{
+		if on
+		{
+			self.nodes[n].flags |= flag;
+		}
+		else
+		{
+			self.nodes[n].flags &= !flag;
+		}
+		let mut n = n;
+		while n != NIL
+		{
+			self.update(n);
+			n = self.nodes[n].parent;
+		}
+	}
+
+	/// Finds a node with the given flag in the treap with the given root.
+	fn find_flagged(&self, root: usize, flag: u8) -> Option<usize>
+	{
+		if root == NIL || self.nodes[root].subtree_flags & flag == 0
+		{
+			return None;
+		}
+		let mut n = root;
+		while self.nodes[n].flags & flag == 0
+		{
+			let left = self.nodes[n].left;
+			n = if left != NIL && self.nodes[left].subtree_flags & flag != 0
+			{
+				left
+			}
+			else
+			{
+				self.nodes[n].right
+			};
+		}
+		Some(n)
+	}
+
+	fn root(&self, mut n: usize) -> usize
+	{
+		while self.nodes[n].parent != NIL
+		{
+			n = self.nodes[n].parent;
+		}
+		n
+	}
+
+	/// Joins two treaps, given by their roots, such that the first's tour
+	/// comes first.
+	fn merge(&mut self, a: usize, b: usize) -> usize
+	{
+		if a == NIL
+		{
+			return b;
+		}
+		if b == NIL
+		{
+			return a;
+		}
+		if self.nodes[a].priority > self.nodes[b].priority
+		{
+			let right = self.merge(self.nodes[a].right, b);
+			self.nodes[a].right = right;
+			self.nodes[right].parent = a;
+			self.update(a);
+			a
+		}
+		else
+		{
+			let left = self.merge(a, self.nodes[b].left);
+			self.nodes[b].left = left;
+			self.nodes[left].parent = b;
+			self.update(b);
+			b
+		}
+	}
+
+	/// Splits the treap containing the node in two, returning their roots.
+	///
+	/// The node starts the second part if `before`, otherwise it ends the
+	/// first.
+	fn split(&mut self, n: usize, before: bool) -> (usize, usize)
+	{
+		let (mut left, mut right);
+		if before
+		{
+			left = mem::replace(&mut self.nodes[n].left, NIL);
+			right = n;
+		}
+		else
+		{
+			right = mem::replace(&mut self.nodes[n].right, NIL);
+			left = n;
+		}
+		for part in [left, right]
+		{
+			if part != n && part != NIL
+			{
+				self.nodes[part].parent = NIL;
+			}
+		}
+		self.update(n);
+
+		// Walking up, each ancestor and its other subtree go to the part on
+		// their side
+		let mut child = n;
+		let mut parent = mem::replace(&mut self.nodes[n].parent, NIL);
+		while parent != NIL
+		{
+			let next = mem::replace(&mut self.nodes[parent].parent, NIL);
+			if self.nodes[parent].right == child
+			{
+				self.nodes[parent].right = left;
+				if left != NIL
+				{
+					self.nodes[left].parent = parent;
+				}
+				left = parent;
+			}
+			else
+			{
+				self.nodes[parent].left = right;
+				if right != NIL
+				{
+					self.nodes[right].parent = parent;
+				}
+				right = parent;
+			}
+			self.update(parent);
+			child = parent;
+			parent = next;
+		}
+		(left, right)
+	}
+}
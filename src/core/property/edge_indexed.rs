@@ -0,0 +1,94 @@
+use crate::core::{
+	property::{AddEdge, RemoveEdge},
+	Directedness, Graph, GraphError,
+};
+use std::{borrow::Borrow, fmt::Debug};
+
+/// A graph where each edge has an id.
+///
+/// Unlike the `(source, sink, weight)` of an edge, its id tells it apart from
+/// any parallel edges, even ones with identical weights.
+///
+/// An edge's id stays valid until the edge is removed, even when other edges
+/// or vertices are removed.
+/// Methods given the id of a removed edge treat it like any other id that no
+/// edge has.
+pub trait EdgeIndexed: Graph
+{
+	type EdgeId: Copy + Eq + Debug;
+
+	/// Returns the ids of all the edges in the graph.
+	fn all_edge_ids(&self) -> impl Iterator<Item = Self::EdgeId>;
+
+	/// Returns the source and sink of the edge with the given id, or `None`
+	/// if no edge has the id.
+	///
+	/// For undirected graphs, the order of the two vertices is unspecified.
+	fn edge_endpoints(&self, id: impl Borrow<Self::EdgeId>)
+		-> Option<(Self::Vertex, Self::Vertex)>;
+
+	/// Returns the weight of the edge with the given id, or `None` if no edge
+	/// has the id.
+	fn edge_weight(&self, id: impl Borrow<Self::EdgeId>) -> Option<Self::EdgeWeightRef<'_>>;
+
+	// Optional methods
+
+	/// Returns the ids of the edges between the given vertices.
+	///
+	/// For undirected graphs, the order of the vertices doesn't matter.
+	fn edge_ids_between(
+		&self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::EdgeId>
+	{
+		let source = *source.borrow();
+		let sink = *sink.borrow();
+		self.all_edge_ids().filter(move |id| {
+			let (so, si) = self.edge_endpoints(id).unwrap();
+			(so == source && si == sink)
+				|| (!Self::Directedness::directed() && so == sink && si == source)
+		})
+	}
+}
+
+/// A graph where edges can be added, returning the id of the new edge.
+pub trait AddEdgeIndexed: EdgeIndexed + AddEdge
+{
+	/// Adds the given edge to the graph, like
+	/// [`add_edge_weighted`](AddEdge::add_edge_weighted), and returns its id.
+	fn add_edge_weighted_indexed(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<Self::EdgeId, GraphError<Self::Vertex>>;
+
+	// Optional methods
+
+	/// Adds the given edge to the graph with the default weight, and returns
+	/// its id.
+	fn add_edge_indexed(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+	) -> Result<Self::EdgeId, GraphError<Self::Vertex>>
+	where
+		Self::EdgeWeight: Default,
+	{
+		self.add_edge_weighted_indexed(source, sink, Self::EdgeWeight::default())
+	}
+}
+
+/// A graph where edges can be removed by their id.
+pub trait RemoveEdgeIndexed: EdgeIndexed + RemoveEdge
+{
+	/// Removes the edge with the given id from the graph, returning its
+	/// weight.
+	///
+	/// Fails if no edge has the id, in which case the graph is unchanged.
+	fn remove_edge_by_id(
+		&mut self,
+		id: impl Borrow<Self::EdgeId>,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>;
+}
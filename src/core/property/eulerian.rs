@@ -0,0 +1,212 @@
+use crate::core::{
+	property::{
+		proxy_remove_edge_where_weight, proxy_remove_vertex, AddEdge, RemoveEdge, RemoveVertex,
+	},
+	proxy::EdgeProxyGraph,
+	Directedness, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use duplicate::duplicate_item;
+use std::{
+	borrow::Borrow,
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+/// A graph with an Euler trail, i.e. a walk that uses every edge exactly once.
+///
+/// For undirected graphs, this means at most two vertices have odd degree.
+/// For directed graphs, this means either every vertex has as many incoming
+/// as outgoing edges, or one vertex has an extra outgoing edge and one has an
+/// extra incoming edge.
+/// In both cases, all the edges must be connected.
+///
+/// See [`euler_trail`](../../algo/fn.euler_trail.html) for finding the trail.
+pub trait SemiEulerian: Graph {}
+
+/// A graph with an Euler circuit, i.e. a closed walk that uses every edge
+/// exactly once.
+///
+/// For undirected graphs, this means every vertex has even degree.
+/// For directed graphs, this means every vertex has as many incoming as
+/// outgoing edges.
+/// In both cases, all the edges must be connected.
+///
+/// See [`euler_circuit`](../../algo/fn.euler_circuit.html) for finding the
+/// circuit.
+pub trait Eulerian: SemiEulerian {}
+
+/// Checks whether the given graph has an Euler circuit, if `closed`, or an
+/// Euler trail otherwise.
+fn validate_euler_walk<G: Graph>(graph: &G, closed: bool) -> Result<(), Violation<G::Vertex>>
+where
+	G::Vertex: Hash,
+{
+	// For directed graphs, the number of outgoing minus incoming edges.
+	// For undirected graphs, the degree.
+	let mut balance: HashMap<_, isize> = HashMap::new();
+	for (source, sink, _) in graph.all_edges()
+	{
+		*balance.entry(source).or_default() += 1;
+		*balance.entry(sink).or_default() += if G::Directedness::directed() { -1 } else { 1 };
+	}
+
+	let mut unbalanced = balance.iter().filter(|(_, b)| {
+		if G::Directedness::directed()
+		{
+			**b != 0
+		}
+		else
+		{
+			**b % 2 != 0
+		}
+	});
+	match (unbalanced.next(), unbalanced.next(), unbalanced.next())
+	{
+		(None, _, _) => (),
+		(Some((_, b1)), Some((_, b2)), None)
+			if !closed && (!G::Directedness::directed() || b1 * b2 == -1) =>
+		{},
+		(Some((v, _)), _, _) => return Err(Violation::Degree(*v)),
+	}
+
+	// Every vertex with an edge must be reachable from any other, ignoring
+	// direction
+	let mut reached = HashSet::new();
+	let mut stack: Vec<_> = balance.keys().take(1).copied().collect();
+	while let Some(v) = stack.pop()
+	{
+		if reached.insert(v)
+		{
+			stack.extend(graph.edges_incident_on(v).map(|(n, _)| n));
+		}
+	}
+
+	balance
+		.keys()
+		.find(|v| !reached.contains(v))
+		.map_or(Ok(()), |v| {
+			Err(Violation::Unreachable(*reached.iter().next().unwrap(), *v))
+		})
+}
+
+/// Ensures the underlying graph is semi-Eulerian.
+///
+/// Edges and vertices can only be added or removed if the graph stays
+/// semi-Eulerian afterwards.
+#[derive(Clone, Debug)]
+pub struct SemiEulerianGraph<C: Ensure>(C);
+
+/// Ensures the underlying graph is Eulerian.
+///
+/// Edges and vertices can only be added or removed if the graph stays
+/// Eulerian afterwards.
+#[derive(Clone, Debug)]
+pub struct EulerianGraph<C: Ensure>(C);
+
+#[duplicate_item(
+	EnsurerGraph closed; [SemiEulerianGraph] [false]; [EulerianGraph] [true];
+)]
+impl<C: Ensure> Ensure for EnsurerGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		validate_euler_walk(c.graph(), closed)
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph closed; [SemiEulerianGraph] [false]; [EulerianGraph] [true];
+)]
+impl<C: Ensure + GraphDerefMut> AddEdge for EnsurerGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let mut proxy = EdgeProxyGraph::new(self.0.graph());
+		proxy.add_edge(source.borrow(), sink.borrow())?;
+		match validate_euler_walk(&proxy, closed)
+		{
+			Ok(()) => self.0.graph_mut().add_edge_weighted(source, sink, weight),
+			Err(violation) => Err(GraphError::rejected::<Self>(violation)),
+		}
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph closed; [SemiEulerianGraph] [false]; [EulerianGraph] [true];
+)]
+impl<C: Ensure + GraphDerefMut> RemoveEdge for EnsurerGraph<C>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		proxy_remove_edge_where_weight::<EnsurerGraph<_>, _, _>(
+			self.0.graph_mut(),
+			source.borrow(),
+			sink.borrow(),
+			f,
+		)
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph closed; [SemiEulerianGraph] [false]; [EulerianGraph] [true];
+)]
+impl<C: Ensure + GraphDerefMut> RemoveVertex for EnsurerGraph<C>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		proxy_remove_vertex::<EnsurerGraph<_>, _>(self.0.graph_mut(), v.borrow())
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph closed; [SemiEulerianGraph] [false]; [EulerianGraph] [true];
+)]
+impl<C: Ensure> SemiEulerian for EnsurerGraph<C> {}
+
+impl<C: Ensure> Eulerian for EulerianGraph<C> {}
+
+impl_ensurer! {
+	use<C> SemiEulerianGraph<C>: Ensure, SemiEulerian, AddEdge, RemoveEdge, RemoveVertex
+	as (self.0) : C
+}
+
+impl_ensurer! {
+	use<C> EulerianGraph<C>: Ensure, Eulerian, SemiEulerian, AddEdge, RemoveEdge, RemoveVertex
+	as (self.0) : C
+}
@@ -0,0 +1,157 @@
+use crate::{
+	algo::{Bfs, UnretainedIterator},
+	core::{
+		property::{
+			Acyclic, AcyclicGraph, AddEdge, NoLoops, TreeGraph, Unique, UniqueGraph, VertexInGraph,
+		},
+		proxy::SubgraphProxy,
+		Ensure, Graph, GraphDerefMut, GraphError, Violation,
+	},
+};
+use duplicate::duplicate_item;
+use std::{borrow::Borrow, collections::HashSet, hash::Hash};
+
+/// A graph whose components are all [trees](trait.Tree.html).
+///
+/// Unlike trees, forests don't need to be connected, so any unique, acyclic
+/// graph is a forest.
+pub trait Forest: Unique + Acyclic
+{
+	/// Returns each tree in the forest as a subgraph.
+	fn trees(&self) -> impl Iterator<Item = TreeGraph<SubgraphProxy<&Self>>>
+	where
+		Self: Sized,
+		Self::Vertex: Hash,
+	{
+		let mut done = HashSet::new();
+		self.all_vertices().filter_map(move |v| {
+			if done.contains(&v)
+			{
+				None
+			}
+			else
+			{
+				let tree = self.component_of(v)?;
+				done.extend(tree.all_vertices());
+				Some(tree)
+			}
+		})
+	}
+
+	/// Returns the tree containing the given vertex as a subgraph, or `None`
+	/// if the vertex isn't in the forest.
+	fn component_of(&self, v: impl Borrow<Self::Vertex>) -> Option<TreeGraph<SubgraphProxy<&Self>>>
+	where
+		Self: Sized,
+		Self::Vertex: Hash,
+	{
+		let v = *v.borrow();
+		if !self.contains_vertex(v)
+		{
+			return None;
+		}
+
+		// Find all vertices reachable from the vertex, ignoring direction
+		let mut tree = SubgraphProxy::new(self);
+		let mut found = vec![v];
+		let mut visited: HashSet<_> = found.iter().copied().collect();
+		let mut idx = 0;
+		while let Some(&v) = found.get(idx)
+		{
+			tree.expand(v).unwrap();
+			for (n, _) in self.edges_incident_on(v)
+			{
+				if visited.insert(n)
+				{
+					found.push(n);
+				}
+			}
+			idx += 1;
+		}
+		Some(TreeGraph::ensure_unchecked(tree, ()))
+	}
+}
+
+/// Ensures the underlying graph is a forest.
+///
+/// Edges can only be added if they don't repeat an edge or close a cycle.
+/// In undirected graphs, that means they must join two different trees.
+#[derive(Clone, Debug)]
+pub struct ForestGraph<C: Ensure>(C);
+
+impl<C: Ensure> Ensure for ForestGraph<C>
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		UniqueGraph::<C>::validate(c, &())?;
+		AcyclicGraph::<C>::validate(c, &())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for ForestGraph<C>
+where
+	C::Graph: AddEdge,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self.0.graph(), [source, sink])?;
+		if source == sink
+		{
+			return Err(GraphError::rejected::<Self>(Violation::Loop(source)));
+		}
+		if self.edges_between(source, sink).next().is_some()
+		{
+			return Err(GraphError::rejected::<Self>(Violation::DuplicateEdge(
+				source, sink,
+			)));
+		}
+
+		// The edge would close a cycle with any path from the sink to the source
+		let g = VertexInGraph::ensure_unchecked(self.0.graph(), [sink]);
+		let mut bfs = Bfs::new(&g);
+		while let Some(v) = bfs.next(&g)
+		{
+			if v == source
+			{
+				let mut path = vec![source];
+				while let Some(p) = bfs.predecessor(*path.last().unwrap())
+				{
+					path.push(p);
+				}
+				let cycle = Some(source)
+					.into_iter()
+					.chain(path.into_iter().skip(1).rev());
+				return Err(GraphError::rejected::<Self>(Violation::Cycle(
+					cycle.collect(),
+				)));
+			}
+		}
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)
+	}
+}
+
+#[duplicate_item(
+	Prop; [Forest]; [Acyclic]; [NoLoops]; [Unique];
+)]
+impl<C: Ensure> Prop for ForestGraph<C> {}
+
+impl_ensurer! {
+	use<C> ForestGraph<C>: Ensure, Forest, Acyclic, NoLoops, Unique, AddEdge
+	as (self.0) : C
+}
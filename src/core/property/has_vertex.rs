@@ -1,4 +1,6 @@
-use crate::core::{property::RemoveVertex, Ensure, Graph, GraphDerefMut, Release};
+use crate::core::{
+	property::RemoveVertex, Ensure, Graph, GraphDerefMut, GraphError, Release, Violation,
+};
 use std::{
 	borrow::Borrow,
 	fmt::{Debug, Error, Formatter},
@@ -108,6 +110,33 @@ pub trait VertexIn<const N: usize = 1, const UNIQUE: bool = true>: HasVertex
 	fn vertex_at_idx(&self, idx: usize) -> Self::Vertex;
 }
 
+/// For specifying a number of vertices in a graph that is only known at
+/// runtime.
+///
+/// Like [VertexIn], but for when the number of vertices can't be given as a
+/// constant, e.g. the sources of a multi-source search or the terminals of a
+/// Steiner tree problem.
+///
+/// The specified vertices are ordered and indexed. There may be none, and they
+/// may contain duplicates.
+pub trait VerticesIn: Graph
+{
+	/// Returns the number of vertices specified.
+	fn designated_count(&self) -> usize;
+
+	/// Returns the I'th vertex specified in the graph.
+	///
+	/// Panics if `idx` isn't less than
+	/// [designated_count](Self::designated_count).
+	fn vertex_at_idx(&self, idx: usize) -> Self::Vertex;
+
+	/// Returns the vertices specified, in order.
+	fn designated_vertices(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		(0..self.designated_count()).map(|idx| self.vertex_at_idx(idx))
+	}
+}
+
 /// Ensures the underlying graph has at least 1 vertex.
 #[derive(Clone, Copy)]
 pub struct HasVertexGraph<C: Ensure>(C);
@@ -128,9 +157,21 @@ impl<C: Ensure> Ensure for HasVertexGraph<C>
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
 	{
-		c.graph().all_vertices().next().is_some()
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		if c.graph().all_vertices().next().is_some()
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(Violation::Empty)
+		}
 	}
 }
 
@@ -138,7 +179,10 @@ impl<C: Ensure + GraphDerefMut> RemoveVertex for HasVertexGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.all_vertices().nth(1).is_some()
 		{
@@ -146,7 +190,7 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::rejected::<Self>(Violation::Empty))
 		}
 	}
 }
@@ -193,20 +237,11 @@ impl<C: Ensure, const V: usize, const U: bool> VertexInGraph<C, V, U>
 	pub fn set_vertex(
 		&mut self,
 		replacements: impl Borrow<[<C::Graph as Graph>::Vertex; V]>,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
-		if replacements
-			.borrow()
-			.iter()
-			.all(|v| self.0.graph().contains_vertex(v))
-		{
-			self.1 = replacements.borrow().clone();
-			Ok(())
-		}
-		else
-		{
-			Err(())
-		}
+		GraphError::check_vertices(self.0.graph(), *replacements.borrow())?;
+		self.1 = *replacements.borrow();
+		Ok(())
 	}
 }
 
@@ -233,7 +268,17 @@ impl<C: Ensure, const V: usize, const U: bool> Ensure for VertexInGraph<C, V, U>
 
 	fn can_ensure(c: &Self::Ensured, p: &[<C::Graph as Graph>::Vertex; V]) -> bool
 	{
-		p.iter().all(|v| c.graph().contains_vertex(v))
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(
+		c: &Self::Ensured,
+		p: &[<C::Graph as Graph>::Vertex; V],
+	) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		p.iter()
+			.find(|v| !c.graph().contains_vertex(*v))
+			.map_or(Ok(()), |v| Err(Violation::MissingVertex(*v)))
 	}
 }
 
@@ -242,7 +287,10 @@ impl<C: Ensure + GraphDerefMut, const V: usize, const U: bool> RemoveVertex
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.1.iter().all(|v2| v2 != v.borrow())
 		{
@@ -250,7 +298,9 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::rejected::<Self>(Violation::Designated(
+				*v.borrow(),
+			)))
 		}
 	}
 }
@@ -276,3 +326,120 @@ impl_ensurer! {
 	as (self.0) : C
 	as (self.1) : [<C::Graph as Graph>::Vertex;V]
 }
+
+/// Ensures the vertices in a list are in the underlying graph.
+///
+/// Like [VertexInGraph], but the number of vertices is only known at runtime.
+/// The designated vertices cannot be removed from the graph.
+#[derive(Clone)]
+pub struct VerticesInGraph<C: Ensure>(pub C, Vec<<C::Graph as Graph>::Vertex>);
+
+impl<C: Ensure> VerticesInGraph<C>
+{
+	/// Replaces the designated vertices, if they are all in the graph.
+	pub fn set_vertices(
+		&mut self,
+		replacements: impl IntoIterator<Item = <C::Graph as Graph>::Vertex>,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		let replacements: Vec<_> = replacements.into_iter().collect();
+		GraphError::check_vertices(self.0.graph(), replacements.iter().copied())?;
+		self.1 = replacements;
+		Ok(())
+	}
+
+	/// Designates another vertex, if it is in the graph.
+	pub fn push_vertex(
+		&mut self,
+		v: impl Borrow<<C::Graph as Graph>::Vertex>,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		GraphError::check_vertices(self.0.graph(), [*v.borrow()])?;
+		self.1.push(*v.borrow());
+		Ok(())
+	}
+
+	/// Returns the designated vertices.
+	pub fn vertices(&self) -> &[<C::Graph as Graph>::Vertex]
+	{
+		&self.1
+	}
+}
+
+impl<C: Ensure> Debug for VerticesInGraph<C>
+where
+	C: Debug,
+	<C::Graph as Graph>::Vertex: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("VerticesInGraph")
+			.field(&self.0)
+			.field(&self.1)
+			.finish()
+	}
+}
+
+impl<C: Ensure> Ensure for VerticesInGraph<C>
+{
+	fn ensure_unchecked(c: Self::Ensured, v: Vec<<C::Graph as Graph>::Vertex>) -> Self
+	{
+		Self(c, v)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &Vec<<C::Graph as Graph>::Vertex>) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(
+		c: &Self::Ensured,
+		p: &Vec<<C::Graph as Graph>::Vertex>,
+	) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		p.iter()
+			.find(|v| !c.graph().contains_vertex(*v))
+			.map_or(Ok(()), |v| Err(Violation::MissingVertex(*v)))
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for VerticesInGraph<C>
+where
+	C::Graph: RemoveVertex,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		if self.1.iter().all(|v2| v2 != v.borrow())
+		{
+			self.0.graph_mut().remove_vertex(v)
+		}
+		else
+		{
+			Err(GraphError::rejected::<Self>(Violation::Designated(
+				*v.borrow(),
+			)))
+		}
+	}
+}
+
+impl<C: Ensure> VerticesIn for VerticesInGraph<C>
+{
+	fn designated_count(&self) -> usize
+	{
+		self.1.len()
+	}
+
+	fn vertex_at_idx(&self, idx: usize) -> Self::Vertex
+	{
+		self.1[idx]
+	}
+}
+
+impl_ensurer! {
+	use<C> VerticesInGraph<C>: Ensure, VerticesIn, RemoveVertex
+	as (self.0) : C
+	as (self.1) : Vec<<C::Graph as Graph>::Vertex>
+}
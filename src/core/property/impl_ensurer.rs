@@ -5,7 +5,16 @@
 ///
 /// Supported property traits:
 /// Directed, Undirected, Unique, NoLoops, Reflexive, Weak, Unilateral,
-/// Connected, Subgraph, Simple
+/// Connected, KConnected, KEdgeConnected, Subgraph, Simple, Bipartite,
+/// Planar, SemiEulerian, Eulerian, TopologicallyOrdered, Forest, MaxOutDegree,
+/// MaxInDegree, MaxDegree, RootedTree, NonNegativeWeights
+///
+/// Properties defined outside graphene can be delegated by deriving
+/// [Ensurer](crate::core::Ensurer) instead.
+///
+/// Ensurers defined in graphene must also be listed in
+/// `delegate_to_builtin_ensurers`, so they delegate properties marked with
+/// [delegate_properties](crate::core::delegate_properties).
 #[macro_export]
 macro_rules! impl_ensurer {
 	{
@@ -19,6 +28,20 @@ macro_rules! impl_ensurer {
 		$(as (self $($payload_to:tt)+) : $payload_type:ty)?
 		$(where $($bounds:tt)*)?
 	} =>{
+		impl<$($($generics,)+ $($(const $const_gen_id : $const_gen_ty,)+)?)?> $struct
+		where
+			$type_graph: $crate::core::Ensure,
+			$($($bounds)*)?
+		{
+			/// Returns the ensured graph, for delegating properties defined
+			/// outside graphene.
+			#[doc(hidden)]
+			pub fn __ensured(&self) -> &$type_graph
+			{
+				&self$($delegate)+
+			}
+		}
+
 		$crate::impl_properties!{
 			@struct [ $struct ]
 			@generic [ $($($generics)+)? ]
@@ -37,7 +60,11 @@ macro_rules! impl_ensurer {
 #[macro_export]
 macro_rules! base_graph {
 	{
-		$(use <$($generics:tt),+>)? $struct:ty
+		$(use <
+			$($generics:tt),+
+			$(; $(const $const_gen_id:ident : $const_gen_ty:ty),+)?
+		>)?
+		$struct:ty
 		$(: $($include_props:ident),+
 		as (self $($delegate:tt)+) : $delegate_type:ty)?
 		$(where $($bounds:tt)*)?
@@ -45,6 +72,7 @@ macro_rules! base_graph {
 		$crate::base_graph_inner! {
 			@struct [ $struct ]
 			@generics [ $($($generics)+)? ]
+			@const_generic [ $($( $([$const_gen_id $const_gen_ty])+ )?)? ]
 			$(
 				@delegate [ $delegate_type ]
 				@delegate_to [ $($delegate)+ ]
@@ -61,6 +89,7 @@ macro_rules! base_graph_inner {
 	{
 		@struct [  $struct:ty ]
 		@generics [ $($($generics:tt)+)? ]
+		@const_generic [ $([$const_gen_id:ident $const_gen_ty:ty])* ]
 		@delegate [ $delegate_type:ty ]
 		@delegate_to [ $($delegate:tt)+ ]
 		@include [ $($include_props:ident)+ ]
@@ -69,12 +98,13 @@ macro_rules! base_graph_inner {
 		$crate::base_graph_inner!{
 			@struct [  $struct ]
 			@generics [ $($($generics)+)? ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
 			@bounds [ $($bounds)* ]
 		}
 		$crate::impl_properties! {
 			@struct [ $struct ]
 			@generic [ $($($generics)+)? ]
-			@const_generic [ ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
 			@delegate [ $delegate_type ]
 			@delegate_to [ $($delegate)+ ]
 			@include [ $($include_props)+ ]
@@ -84,9 +114,11 @@ macro_rules! base_graph_inner {
 	{
 		@struct [  $struct:ty ]
 		@generics [ $($($generics:tt)+)? ]
+		@const_generic [ $([$const_gen_id:ident $const_gen_ty:ty])* ]
 		@bounds [ $($bounds:tt)* ]
 	} => {
-		impl$(<$($generics),+>)? $crate::core::GraphDeref for $struct
+		impl<$($($generics,)+)? $(const $const_gen_id : $const_gen_ty,)*> $crate::core::GraphDeref
+			for $struct
 		where $($bounds)*
 		{
 			type Graph = Self;
@@ -97,7 +129,8 @@ macro_rules! base_graph_inner {
 			}
 		}
 
-		impl$(<$($generics),+>)? $crate::core::GraphDerefMut for $struct
+		impl<$($($generics,)+)? $(const $const_gen_id : $const_gen_ty,)*> $crate::core::GraphDerefMut
+			for $struct
 		where $($bounds)*
 		{
 			fn graph_mut(&mut self) -> &mut Self::Graph
@@ -106,7 +139,8 @@ macro_rules! base_graph_inner {
 			}
 		}
 
-		impl$(<$($generics),+>)? $crate::core::BaseGraph for $struct
+		impl<$($($generics,)+)? $(const $const_gen_id : $const_gen_ty,)*> $crate::core::BaseGraph
+			for $struct
 		where $($bounds)*
 		{}
 	}
@@ -258,7 +292,7 @@ macro_rules! impl_properties {
 				type VertexWeight = <<$delegate_type as
 					$crate::core::GraphDeref>::Graph as $crate::core::Graph>::VertexWeight;
 
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDeref::graph(&self$($delegate)+){
 						fn all_vertices_weighted(
 							&self,
@@ -269,6 +303,43 @@ macro_rules! impl_properties {
 							source: impl std::borrow::Borrow<Self::Vertex>,
 							sink: impl std::borrow::Borrow<Self::Vertex>,
 						) -> impl Iterator<Item = Self::EdgeWeightRef<'_>>;
+
+						fn all_edges(
+							&self,
+						) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+						fn vertex_weight(
+							&self,
+							v: impl std::borrow::Borrow<Self::Vertex>,
+						) -> Option<&Self::VertexWeight>;
+
+						fn contains_vertex(&self, v: impl std::borrow::Borrow<Self::Vertex>) -> bool;
+
+						fn edges_sourced_in(
+							&self,
+							v: impl std::borrow::Borrow<Self::Vertex>,
+						) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+						fn edges_sinked_in(
+							&self,
+							v: impl std::borrow::Borrow<Self::Vertex>,
+						) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+						fn edges_incident_on(
+							&self,
+							v: impl std::borrow::Borrow<Self::Vertex>,
+						) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+
+						fn vertex_neighbors(
+							&self,
+							v: impl std::borrow::Borrow<Self::Vertex>,
+						) -> impl Iterator<Item = Self::Vertex>;
+
+						fn neighbors(
+							&self,
+							v1: impl std::borrow::Borrow<Self::Vertex>,
+							v2: impl std::borrow::Borrow<Self::Vertex>,
+						) -> bool;
 					}
 				}
 			}
@@ -290,7 +361,7 @@ macro_rules! impl_properties {
 			]
 			@trait_id GraphMut [$crate::core]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn all_vertices_weighted_mut(
 							&mut self,
@@ -322,10 +393,10 @@ macro_rules! impl_properties {
 			]
 			@trait_id NewVertex [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+){
 						fn new_vertex_weighted(&mut self, w: Self::VertexWeight)
-							-> Result<Self::Vertex, ()>;
+							-> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -347,10 +418,10 @@ macro_rules! impl_properties {
 			]
 			@trait_id RemoveVertex [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn remove_vertex(&mut self, v: impl std::borrow::Borrow<Self::Vertex>)
-							-> Result<Self::VertexWeight, ()>;
+							-> Result<Self::VertexWeight, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -372,14 +443,14 @@ macro_rules! impl_properties {
 			]
 			@trait_id AddEdge [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn add_edge_weighted(
 							&mut self,
 							source: impl std::borrow::Borrow<Self::Vertex>,
 							sink: impl std::borrow::Borrow<Self::Vertex>,
 							weight: Self::EdgeWeight,
-						) -> Result<(), ()>;
+						) -> Result<(), $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -401,14 +472,14 @@ macro_rules! impl_properties {
 			]
 			@trait_id RemoveEdge [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn remove_edge_where_weight<F>(
 							&mut self,
 							source: impl std::borrow::Borrow<Self::Vertex>,
 							sink: impl std::borrow::Borrow<Self::Vertex>,
 							f: F,
-						) -> Result<Self::EdgeWeight, ()>
+						) -> Result<Self::EdgeWeight, $crate::core::GraphError<Self::Vertex>>
 							where
 								F: Fn(&Self::EdgeWeight) -> bool;
 					}
@@ -456,6 +527,44 @@ macro_rules! impl_properties {
 			}
 		}
 
+		// EdgeIndexed
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::EdgeIndexed,
+				$($bounds)*
+			]
+			@trait_id EdgeIndexed [$crate::core::property]
+			@implement {
+				type EdgeId = <<$delegate_type as $crate::core::GraphDeref>::Graph
+					as $crate::core::property::EdgeIndexed>::EdgeId;
+
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn all_edge_ids(&self) -> impl Iterator<Item = Self::EdgeId>;
+
+						fn edge_endpoints(&self, id: impl std::borrow::Borrow<Self::EdgeId>)
+							-> Option<(Self::Vertex, Self::Vertex)>;
+
+						fn edge_weight(&self, id: impl std::borrow::Borrow<Self::EdgeId>)
+							-> Option<Self::EdgeWeightRef<'_>>;
+
+						fn edge_ids_between(
+							&self,
+							source: impl std::borrow::Borrow<Self::Vertex>,
+							sink: impl std::borrow::Borrow<Self::Vertex>,
+						) -> impl Iterator<Item = Self::EdgeId>;
+					}
+				}
+			}
+		}
+
 		// Unique
 		$crate::impl_properties!{
 			@struct [ $struct ]
@@ -473,6 +582,104 @@ macro_rules! impl_properties {
 			@implement {}
 		}
 
+		// Bipartite
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::Bipartite,
+				$($bounds)*
+			]
+			@trait_id Bipartite [$crate::core::property]
+			@implement {
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn is_left(&self, v: impl std::borrow::Borrow<Self::Vertex>) -> bool;
+					}
+				}
+			}
+		}
+
+		// Planar
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::Planar,
+				$($bounds)*
+			]
+			@trait_id Planar [$crate::core::property]
+			@implement {
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn clockwise_neighbors(&self, v: impl std::borrow::Borrow<Self::Vertex>)
+							-> impl Iterator<Item = Self::Vertex>;
+					}
+				}
+			}
+		}
+
+		// SemiEulerian
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::SemiEulerian,
+				$($bounds)*
+			]
+			@trait_id SemiEulerian [$crate::core::property]
+			@implement {}
+		}
+
+		// Eulerian
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::Eulerian,
+				$($bounds)*
+			]
+			@trait_id Eulerian [$crate::core::property]
+			@implement {}
+		}
+
+		// NonNegativeWeights
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::NonNegativeWeights,
+				$($bounds)*
+			]
+			@trait_id NonNegativeWeights [$crate::core::property]
+			@implement {}
+		}
+
 		// NoLoops
 		$crate::impl_properties!{
 			@struct [ $struct ]
@@ -560,6 +767,46 @@ macro_rules! impl_properties {
 			@implement {}
 		}
 
+		// KConnected
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [
+				$([$const_gen_id $const_gen_ty])*
+				[IMPL_PROPERTIES_KCONNECTED_K usize]
+			]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::KConnected<IMPL_PROPERTIES_KCONNECTED_K>,
+				$($bounds)*
+			]
+			@trait_id KConnected <IMPL_PROPERTIES_KCONNECTED_K> [$crate::core::property]
+			@implement {}
+		}
+
+		// KEdgeConnected
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [
+				$([$const_gen_id $const_gen_ty])*
+				[IMPL_PROPERTIES_KEDGECONNECTED_K usize]
+			]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::KEdgeConnected<IMPL_PROPERTIES_KEDGECONNECTED_K>,
+				$($bounds)*
+			]
+			@trait_id KEdgeConnected <IMPL_PROPERTIES_KEDGECONNECTED_K> [$crate::core::property]
+			@implement {}
+		}
+
 		// Subgraph
 		$crate::impl_properties!{
 			@struct [ $struct ]
@@ -575,7 +822,7 @@ macro_rules! impl_properties {
 			]
 			@trait_id Subgraph [$crate::core::property]
 			@implement {
-				delegate::delegate!{
+				$crate::__private::delegate::delegate!{
 					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
 						fn exit_edges(&self)
 							-> impl '_ + Iterator<Item=(Self::Vertex, Self::Vertex)>;
@@ -599,7 +846,7 @@ macro_rules! impl_properties {
 			]
 			@trait_id HasVertex [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
 						fn any_vertex(&self) -> Self::Vertex;
 					}
@@ -629,8 +876,32 @@ macro_rules! impl_properties {
 			@trait_id VertexIn <IMPL_PROPERTIES_VERTEXIN_N,IMPL_PROPERTIES_VERTEXIN_U>
 				[$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn vertex_at_idx(&self, idx: usize) -> Self::Vertex;
+					}
+				}
+			}
+		}
+
+		// VerticesIn
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::VerticesIn,
+				$($bounds)*
+			]
+			@trait_id VerticesIn [$crate::core::property]
+			@implement {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn designated_count(&self) -> usize;
 						fn vertex_at_idx(&self, idx: usize) -> Self::Vertex;
 					}
 				}
@@ -653,7 +924,7 @@ macro_rules! impl_properties {
 			]
 			@trait_id Rooted [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
 						fn root(&self) -> Self::Vertex;
 					}
@@ -677,10 +948,10 @@ macro_rules! impl_properties {
 			]
 			@trait_id RootedMut [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn set_root(&mut self, v: impl std::borrow::Borrow<Self::Vertex>)
-							-> std::result::Result<(), ()>;
+							-> std::result::Result<(), $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -720,6 +991,107 @@ macro_rules! impl_properties {
 			@implement {}
 		}
 
+		// TopologicallyOrdered
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::TopologicallyOrdered,
+				$($bounds)*
+			]
+			@trait_id TopologicallyOrdered [$crate::core::property]
+			@implement {
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn topological_order(&self) -> impl Iterator<Item = Self::Vertex>;
+						fn order_of(&self, v: impl std::borrow::Borrow<Self::Vertex>) -> Option<usize>;
+					}
+				}
+			}
+		}
+
+		// Forest
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::Forest,
+				$($bounds)*
+			]
+			@trait_id Forest [$crate::core::property]
+			@implement {}
+		}
+
+		// MaxOutDegree
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [
+				$([$const_gen_id $const_gen_ty])*
+				[IMPL_PROPERTIES_MAXOUTDEGREE_N usize]
+			]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::MaxOutDegree<IMPL_PROPERTIES_MAXOUTDEGREE_N>,
+				$($bounds)*
+			]
+			@trait_id MaxOutDegree <IMPL_PROPERTIES_MAXOUTDEGREE_N> [$crate::core::property]
+			@implement {}
+		}
+
+		// MaxInDegree
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [
+				$([$const_gen_id $const_gen_ty])*
+				[IMPL_PROPERTIES_MAXINDEGREE_N usize]
+			]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::MaxInDegree<IMPL_PROPERTIES_MAXINDEGREE_N>,
+				$($bounds)*
+			]
+			@trait_id MaxInDegree <IMPL_PROPERTIES_MAXINDEGREE_N> [$crate::core::property]
+			@implement {}
+		}
+
+		// MaxDegree
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [
+				$([$const_gen_id $const_gen_ty])*
+				[IMPL_PROPERTIES_MAXDEGREE_N usize]
+			]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::MaxDegree<IMPL_PROPERTIES_MAXDEGREE_N>,
+				$($bounds)*
+			]
+			@trait_id MaxDegree <IMPL_PROPERTIES_MAXDEGREE_N> [$crate::core::property]
+			@implement {}
+		}
+
 		// Tree
 		$crate::impl_properties!{
 			@struct [ $struct ]
@@ -737,6 +1109,31 @@ macro_rules! impl_properties {
 			@implement {}
 		}
 
+		// RootedTree
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@const_generic [ $([$const_gen_id $const_gen_ty])* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				$delegate_type: $crate::core::GraphDerefMut,
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::RootedTree,
+				$($bounds)*
+			]
+			@trait_id RootedTree [$crate::core::property]
+			@implement {
+				$crate::__private::delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn parent(&self, v: impl std::borrow::Borrow<Self::Vertex>)
+							-> Option<Self::Vertex>;
+					}
+				}
+			}
+		}
+
 		// NewLeafUndirected
 		$crate::impl_properties!{
 			@struct [ $struct ]
@@ -753,14 +1150,14 @@ macro_rules! impl_properties {
 			]
 			@trait_id NewLeafUndirected [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn new_leaf_weighted(
 							&mut self,
 							parent: impl std::borrow::Borrow<Self::Vertex>,
 							w: Self::VertexWeight,
 							e: Self::EdgeWeight,
-						) -> Result<Self::Vertex, ()>;
+						) -> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -782,7 +1179,7 @@ macro_rules! impl_properties {
 			]
 			@trait_id NewLeafDirected [$crate::core::property]
 			@implement {
-				delegate::delegate! {
+				$crate::__private::delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn new_leaf_weighted(
 							&mut self,
@@ -790,7 +1187,7 @@ macro_rules! impl_properties {
 							to_new: bool,
 							w: Self::VertexWeight,
 							e: Self::EdgeWeight,
-						) -> Result<Self::Vertex, ()>;
+						) -> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -807,8 +1204,8 @@ macro_rules! impl_properties {
 		@trait_id $include_props_id:ident $(<$($trait_gens:ident),+>)? [ $($include_props_path:tt)* ]
 		@implement {$($impl:tt)*}
 	} => {
-		tt_call::tt_if!{
-			condition = [{tt_equal::tt_equal}]
+		$crate::__private::tt_call::tt_if!{
+			condition = [{$crate::__private::tt_equal::tt_equal}]
 			input = [{ $include_props $include_props_id}]
 			true = [{
 				$crate::impl_properties!{
@@ -847,8 +1244,8 @@ macro_rules! impl_properties {
 		@trait_id $exclude_props_id:ident $(<$($trait_gens:ident),+>)?[ $($exclude_props_path:tt)* ]
 		@implement {$($impl:tt)*}
 	} => {
-		tt_call::tt_if!{
-			condition = [{tt_equal::tt_equal}]
+		$crate::__private::tt_call::tt_if!{
+			condition = [{$crate::__private::tt_equal::tt_equal}]
 			input = [{ $exclude_props $exclude_props_id}]
 			true = [{}]
 			false = [{
@@ -902,7 +1299,7 @@ macro_rules! make_ensurer {
 		$ensured:ident
 		, $payload:ident $($rest:tt)+
 	} => {
-		Self($ensure, $payload)
+		Self($ensured, $payload)
 	};
 	{
 		$ensured:ident
@@ -911,3 +1308,80 @@ macro_rules! make_ensurer {
 		Self($ensured)
 	}
 }
+
+/// Calls the given delegation macro, made by
+/// [delegate_properties](crate::core::delegate_properties), for each of
+/// graphene's ensurers, so they delegate the given trait.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! delegate_to_builtin_ensurers {
+	{
+		$delegation:ident $trait:ident
+	} => {
+		$crate::delegate_to_builtin_ensurers! {
+			@delegation $delegation
+			@trait $trait
+			@ensurers
+			[ [AcyclicGraph<C>] [C,] [C] [] ]
+			[ [BipartiteGraph<C>] [C,] [C] [] ]
+			[ [ConnectedGraph<C>] [C,] [C] [] ]
+			[ [DirectedGraph<C>] [C,] [C] [] ]
+			[ [DynEnsuredGraph<C>] [C,] [C] [] ]
+			[ [EulerianGraph<C>] [C,] [C] [] ]
+			[ [ForestGraph<C>] [C,] [C] [] ]
+			[ [HasVertexGraph<C>] [C,] [C] [] ]
+			[ [KConnectedGraph<C, K>] [C, const K: usize,] [C] [] ]
+			[ [KEdgeConnectedGraph<C, K>] [C, const K: usize,] [C] [] ]
+			[ [MaxDegreeGraph<C, N>] [C, const N: usize,] [C] [] ]
+			[ [MaxInDegreeGraph<C, N>] [C, const N: usize,] [C] [] ]
+			[ [MaxOutDegreeGraph<C, N>] [C, const N: usize,] [C] [] ]
+			[ [NoLoopsGraph<C>] [C,] [C] [] ]
+			[ [NonNegativeWeightsGraph<C>] [C,] [C] [
+				<C::Graph as $crate::core::Graph>::EdgeWeight:
+					PartialOrd + $crate::__private::num_traits::Zero,
+			] ]
+			[ [PlanarGraph<C>] [C,] [C] [] ]
+			[ [ReflexiveGraph<C>] [C,] [C] [
+				<C::Graph as $crate::core::Graph>::EdgeWeight: Default,
+			] ]
+			[ [RootedGraph<C>] [C,] [$crate::core::property::VertexInGraph<C>] [] ]
+			[ [SemiEulerianGraph<C>] [C,] [C] [] ]
+			[ [SimpleGraph<C>] [C,] [C] [
+				C::Graph: $crate::core::Graph<
+					EdgeWeight = (),
+					Directedness = $crate::core::Undirected,
+				>,
+			] ]
+			[ [TopologicallyOrderedGraph<C>] [C,] [C] [] ]
+			[ [TreeGraph<C>] [C,] [C] [] ]
+			[ [UndirectedGraph<C>] [C,] [C] [] ]
+			[ [UnilateralGraph<C>] [C,] [C] [
+				C::Graph: $crate::core::Graph<Directedness = $crate::core::Directed>,
+			] ]
+			[ [UniqueGraph<C>] [C,] [C] [] ]
+			[ [VertexInGraph<C, V, U>] [C, const V: usize, const U: bool,] [C] [] ]
+			[ [VerticesInGraph<C>] [C,] [C] [] ]
+			[ [WeakGraph<C>] [C,] [C] [
+				C::Graph: $crate::core::Graph<Directedness = $crate::core::Directed>,
+			] ]
+		}
+	};
+	{
+		@delegation $delegation:ident
+		@trait $trait:ident
+		@ensurers $([
+			[ $($struct:tt)* ] [ $($generics:tt)* ] [ $delegate:ty ] [ $($bounds:tt)* ]
+		])*
+	} => {
+		$(
+			$delegation! {
+				@trait [ $trait ]
+				@struct [ $crate::core::property::$($struct)* ]
+				@generics [ $($generics)* ]
+				@delegate [ $delegate ]
+				@delegate_to [ .__ensured() ]
+				@bounds [ C: $crate::core::Ensure, $($bounds)* ]
+			}
+		)*
+	};
+}
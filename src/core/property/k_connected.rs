@@ -0,0 +1,719 @@
+use crate::core::{
+	property::{
+		proxy_remove_edge_where_weight, proxy_remove_vertex, Connected, ConnectedGraph, RemoveEdge,
+		RemoveVertex, Unilateral, Weak,
+	},
+	proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
+	Directedness, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, mem};
+
+/// A graph that stays connected when any fewer than `K` vertices are removed.
+///
+/// Like with [Connected](trait.Connected.html), graphs with too few vertices
+/// to be disconnected count as `K`-connected.
+/// A `K`-connected graph is also `J`-connected for any `J` less than `K`, so
+/// [KConnectedGraph](struct.KConnectedGraph.html) implements both for `K` up
+/// to 8.
+pub trait KConnected<const K: usize>: Connected {}
+
+/// A graph that stays connected when any fewer than `K` edges are removed.
+///
+/// A `K`-edge-connected graph is also `J`-edge-connected for any `J` less
+/// than `K`, so [KEdgeConnectedGraph](struct.KEdgeConnectedGraph.html)
+/// implements both for `K` up to 8.
+pub trait KEdgeConnected<const K: usize>: Connected {}
+
+/// A graph without articulation points, i.e. one that stays connected when any
+/// one vertex is removed.
+pub trait Biconnected: KConnected<2> {}
+
+impl<G: KConnected<2>> Biconnected for G {}
+
+/// Implements the given property for each `J` listed for a `K` of the given
+/// ensurer.
+macro_rules! impl_implied {
+	($property:ident for $ensurer:ident: $($k:literal => [$($j:literal),*]),*) => {
+		$($(
+			impl<C: Ensure> $property<$j> for $ensurer<C, $k> {}
+		)*)*
+	};
+	($property:ident for $ensurer:ident) => {
+		impl_implied! {
+			$property for $ensurer:
+			2 => [1],
+			3 => [1, 2],
+			4 => [1, 2, 3],
+			5 => [1, 2, 3, 4],
+			6 => [1, 2, 3, 4, 5],
+			7 => [1, 2, 3, 4, 5, 6],
+			8 => [1, 2, 3, 4, 5, 6, 7]
+		}
+	};
+}
+
+/// Calls the given function with each set of at most `max` indices below
+/// `count`, stopping when it returns false.
+///
+/// Returns whether the function returned true for all the sets.
+fn all_subsets(count: usize, max: usize, mut f: impl FnMut(&[usize]) -> bool) -> bool
+{
+	let mut subset = Vec::new();
+	loop
+	{
+		if !f(&subset)
+		{
+			return false;
+		}
+
+		// Go to the next set, extending the current one if possible
+		let next = subset.last().map_or(0, |last| last + 1);
+		if subset.len() < max && next < count
+		{
+			subset.push(next);
+			continue;
+		}
+		loop
+		{
+			match subset.pop()
+			{
+				Some(last) if last + 1 < count =>
+				{
+					subset.push(last + 1);
+					break;
+				},
+				Some(_) => (),
+				None => return true,
+			}
+		}
+	}
+}
+
+/// Stands for a missing vertex or edge.
+const NONE: usize = usize::MAX;
+
+/// The vertices of a graph, numbered in the order the graph gives them, with
+/// the edges between them, leaving out loops.
+struct Numbered<V>
+{
+	vertices: Vec<V>,
+
+	/// The ends of each edge, source first.
+	edges: Vec<(usize, usize)>,
+
+	/// The edges sourced in each vertex, with their sinks.
+	/// In undirected graphs, each edge is in the lists of both its ends.
+	sourced: Vec<Vec<(usize, usize)>>,
+
+	/// The edges sinked in each vertex, with their sources.
+	/// Only used for directed graphs.
+	sinked: Vec<Vec<(usize, usize)>>,
+
+	directed: bool,
+}
+
+impl<V: Copy + Eq + Hash> Numbered<V>
+{
+	fn new<G: Graph<Vertex = V> + ?Sized>(graph: &G) -> Self
+	{
+		let vertices: Vec<_> = graph.all_vertices().collect();
+		let indices: HashMap<_, _> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+		let directed = G::Directedness::directed();
+		let mut numbered = Self {
+			edges: Vec::new(),
+			sourced: vec![Vec::new(); vertices.len()],
+			sinked: vec![Vec::new(); vertices.len()],
+			vertices,
+			directed,
+		};
+		for (source, sink, _) in graph.all_edges()
+		{
+			let (x, y) = (indices[&source], indices[&sink]);
+			if x == y
+			{
+				continue;
+			}
+			let e = numbered.edges.len();
+			numbered.edges.push((x, y));
+			numbered.sourced[x].push((e, y));
+			if directed
+			{
+				numbered.sinked[y].push((e, x));
+			}
+			else
+			{
+				numbered.sourced[y].push((e, x));
+			}
+		}
+		numbered
+	}
+
+	/// Gives two vertices that can't reach each other once some vertex, or
+	/// some edge if `by_edge`, is removed.
+	fn validate(&self, by_edge: bool) -> Result<(), Violation<V>>
+	{
+		let witness = self.disconnected().or_else(|| {
+			if self.directed
+			{
+				self.strong_separator(by_edge)
+			}
+			else
+			{
+				self.separator(by_edge)
+			}
+		});
+		witness.map_or(Ok(()), |(x, y)| {
+			Err(Violation::Unreachable(self.vertices[x], self.vertices[y]))
+		})
+	}
+
+	/// Gives the first vertex and one it can't reach or that can't reach it,
+	/// like [ConnectedGraph](struct.ConnectedGraph.html) does.
+	fn disconnected(&self) -> Option<(usize, usize)>
+	{
+		if self.vertices.is_empty()
+		{
+			return None;
+		}
+		let unreached = |reached: Vec<bool>| reached.iter().position(|r| !r);
+		unreached(reach(&self.sourced, 0, NONE))
+			.map(|x| (0, x))
+			.or_else(|| {
+				self.directed
+					.then(|| unreached(reach(&self.sinked, 0, NONE)).map(|x| (x, 0)))
+					.flatten()
+			})
+	}
+
+	/// Finds an articulation point, or a bridge if `by_edge`, of the connected
+	/// undirected graph, by the low-link depth-first search of Hopcroft and
+	/// Tarjan.
+	///
+	/// Gives two vertices it separates, the one found first by the search
+	/// being the latest.
+	fn separator(&self, by_edge: bool) -> Option<(usize, usize)>
+	{
+		let count = self.vertices.len();
+		if count == 0
+		{
+			return None;
+		}
+		let mut found = vec![NONE; count];
+		let mut low = vec![NONE; count];
+		let mut parent = vec![(NONE, NONE); count];
+		let mut root_children = Vec::new();
+		let mut witness: Option<(usize, (usize, usize))> = None;
+
+		found[0] = 0;
+		low[0] = 0;
+		let mut time = 1;
+		// Each vertex on the path from the root with the index of its next edge
+		let mut stack = vec![(0, 0)];
+		while let Some(&(v, idx)) = stack.last()
+		{
+			if let Some(&(e, w)) = self.sourced[v].get(idx)
+			{
+				stack.last_mut().unwrap().1 += 1;
+				if e == parent[v].1
+				{
+					continue;
+				}
+				if found[w] == NONE
+				{
+					found[w] = time;
+					low[w] = time;
+					time += 1;
+					parent[w] = (v, e);
+					stack.push((w, 0));
+				}
+				else
+				{
+					low[v] = low[v].min(found[w]);
+				}
+				continue;
+			}
+
+			stack.pop();
+			let Some(&(u, _)) = stack.last()
+			else
+			{
+				break;
+			};
+			low[u] = low[u].min(low[v]);
+			let separated = if by_edge
+			{
+				(low[v] > found[u]).then_some((u, v))
+			}
+			else if u == 0
+			{
+				root_children.push(v);
+				(root_children.len() > 1).then_some((root_children[0], v))
+			}
+			else
+			{
+				(low[v] >= found[u]).then_some((parent[u].0, v))
+			};
+			if let Some(pair) = separated.filter(|_| witness.is_none_or(|(t, _)| found[v] < t))
+			{
+				witness = Some((found[v], pair));
+			}
+		}
+		witness.map(|(_, pair)| pair)
+	}
+
+	/// Finds a strong articulation point, or a strong bridge if `by_edge`, of
+	/// the strongly connected directed graph.
+	///
+	/// Uses that, for the first vertex `s`, any other vertex is a strong
+	/// articulation point exactly if it dominates some vertex in the graph or
+	/// its reverse, when starting from `s` (Italiano, Laura and Santaroni).
+	/// Bridges are found the same way, by putting a vertex in the middle of
+	/// each edge.
+	fn strong_separator(&self, by_edge: bool) -> Option<(usize, usize)>
+	{
+		let count = self.vertices.len();
+		if count == 0
+		{
+			return None;
+		}
+		let (forward, backward) = if by_edge
+		{
+			// The vertex in the middle of edge 'e' is 'count + e'
+			let mut forward = vec![Vec::new(); count + self.edges.len()];
+			let mut backward = forward.clone();
+			for (e, (x, y)) in self.edges.iter().enumerate()
+			{
+				forward[*x].push(count + e);
+				forward[count + e].push(*y);
+				backward[*y].push(count + e);
+				backward[count + e].push(*x);
+			}
+			(forward, backward)
+		}
+		else
+		{
+			let sinks = |edges: &Vec<Vec<(usize, usize)>>| {
+				edges
+					.iter()
+					.map(|es| es.iter().map(|(_, w)| *w).collect())
+					.collect::<Vec<Vec<_>>>()
+			};
+			(sinks(&self.sourced), sinks(&self.sinked))
+		};
+
+		// An edge dominates only the vertex it leads to, if any
+		let separates = |dominator: usize, v: usize| {
+			v < count && dominator != 0 && (!by_edge || dominator >= count)
+		};
+		let forward_dominators = dominators(&forward, 0);
+		if let Some(v) = (1..count).find(|v| separates(forward_dominators[*v], *v))
+		{
+			return Some((0, v));
+		}
+		let backward_dominators = dominators(&backward, 0);
+		if let Some(v) = (1..count).find(|v| separates(backward_dominators[*v], *v))
+		{
+			return Some((v, 0));
+		}
+
+		// Whether the first vertex is a strong articulation point
+		if !by_edge && count > 2
+		{
+			let unreached = |edges| reach(edges, 1, 0).iter().skip(1).position(|r| !r);
+			if let Some(v) = unreached(&self.sourced)
+			{
+				return Some((1, v + 1));
+			}
+			if let Some(v) = unreached(&self.sinked)
+			{
+				return Some((v + 1, 1));
+			}
+		}
+		None
+	}
+}
+
+/// Returns which vertices can be reached from the given start along the given
+/// edges, without going through the given vertex to avoid.
+fn reach(edges: &[Vec<(usize, usize)>], start: usize, avoid: usize) -> Vec<bool>
+{
+	let mut reached = vec![false; edges.len()];
+	reached[start] = true;
+	if avoid != NONE
+	{
+		reached[avoid] = true;
+	}
+	let mut stack = vec![start];
+	while let Some(v) = stack.pop()
+	{
+		for (_, w) in &edges[v]
+		{
+			if !mem::replace(&mut reached[*w], true)
+			{
+				stack.push(*w);
+			}
+		}
+	}
+	if avoid != NONE
+	{
+		reached[avoid] = false;
+	}
+	reached
+}
+
+/// Returns the immediate dominator of each vertex that can be reached from the
+/// root, using the algorithm of Lengauer and Tarjan with path compression.
+///
+/// The root is its own dominator, and vertices that can't be reached have
+/// none.
+fn dominators(successors: &[Vec<usize>], root: usize) -> Vec<usize>
+{
+	let count = successors.len();
+	let mut predecessors = vec![Vec::new(); count];
+	for (v, ws) in successors.iter().enumerate()
+	{
+		for w in ws
+		{
+			predecessors[*w].push(v);
+		}
+	}
+
+	// Number the vertices in depth-first order, which is their first
+	// semidominator
+	let mut semi = vec![NONE; count];
+	let mut order = Vec::with_capacity(count);
+	let mut parent = vec![NONE; count];
+	let mut stack = vec![(root, NONE)];
+	while let Some((v, p)) = stack.pop()
+	{
+		if semi[v] != NONE
+		{
+			continue;
+		}
+		semi[v] = order.len();
+		order.push(v);
+		parent[v] = p;
+		stack.extend(
+			successors[v]
+				.iter()
+				.rev()
+				.filter(|w| semi[**w] == NONE)
+				.map(|w| (*w, v)),
+		);
+	}
+
+	let mut ancestor = vec![NONE; count];
+	let mut label: Vec<_> = (0..count).collect();
+	let mut dominator = vec![NONE; count];
+	let mut bucket = vec![Vec::new(); count];
+
+	// Returns the vertex with the lowest semidominator on the path from the
+	// given vertex to the root of its tree in the forest, compressing the path
+	let eval = |v: usize, ancestor: &mut [usize], label: &mut [usize], semi: &[usize]| {
+		if ancestor[v] == NONE
+		{
+			return v;
+		}
+		let mut path = Vec::new();
+		let mut u = v;
+		while ancestor[ancestor[u]] != NONE
+		{
+			path.push(u);
+			u = ancestor[u];
+		}
+		while let Some(u) = path.pop()
+		{
+			let a = ancestor[u];
+			if semi[label[a]] < semi[label[u]]
+			{
+				label[u] = label[a];
+			}
+			ancestor[u] = ancestor[a];
+		}
+		label[v]
+	};
+
+	for &w in order.iter().skip(1).rev()
+	{
+		for &v in &predecessors[w]
+		{
+			if semi[v] != NONE
+			{
+				let u = eval(v, &mut ancestor, &mut label, &semi);
+				semi[w] = semi[w].min(semi[u]);
+			}
+		}
+		bucket[order[semi[w]]].push(w);
+		let p = parent[w];
+		ancestor[w] = p;
+		for v in mem::take(&mut bucket[p])
+		{
+			let u = eval(v, &mut ancestor, &mut label, &semi);
+			dominator[v] = if semi[u] < semi[v] { u } else { p };
+		}
+	}
+	for &w in order.iter().skip(1)
+	{
+		if dominator[w] != order[semi[w]]
+		{
+			dominator[w] = dominator[dominator[w]];
+		}
+	}
+	dominator[root] = root;
+	dominator
+}
+
+/// Ensures the underlying graph is `K`-connected.
+///
+/// For `K` up to 2, the check takes time linear in the number of vertices and
+/// edges, on top of the time the graph takes to go through its edges once.
+/// Articulation points of undirected graphs are found by depth-first search,
+/// and those of directed graphs through the dominators of the graph and its
+/// reverse.
+/// For larger `K`, it checks that the graph is connected without each set of
+/// fewer than `K` vertices, which takes `O(n^(K-1) (n + m))` time for `n`
+/// vertices and `m` edges. That is only practical for graphs of up to about a
+/// hundred vertices for `K = 3`, and fewer for larger `K`.
+///
+/// The same check is done when removing vertices or edges, which fails if the
+/// graph would no longer be `K`-connected.
+#[derive(Clone, Debug)]
+pub struct KConnectedGraph<C: Ensure, const K: usize>(C);
+
+/// Ensures the underlying graph is biconnected.
+pub type BiconnectedGraph<C> = KConnectedGraph<C, 2>;
+
+impl<C: Ensure, const K: usize> Ensure for KConnectedGraph<C, K>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	/// Gives the vertices that can't reach each other once some fewer than `K`
+	/// vertices are removed.
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		let g = c.graph();
+		if K <= 2
+		{
+			let numbered = Numbered::new(g);
+			return if K == 2
+			{
+				numbered.validate(false)
+			}
+			else
+			{
+				numbered.disconnected().map_or(Ok(()), |(x, y)| {
+					Err(Violation::Unreachable(
+						numbered.vertices[x],
+						numbered.vertices[y],
+					))
+				})
+			};
+		}
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let mut violation = None;
+
+		all_subsets(vertices.len(), K.saturating_sub(1), |removed| {
+			let mut proxy = VertexProxyGraph::new(g);
+			for idx in removed
+			{
+				proxy
+					.remove_vertex(ProxyVertex::Underlying(vertices[*idx]))
+					.unwrap_or_else(|_| panic!("Couldn't remove a vertex from the proxy"));
+			}
+			ConnectedGraph::validate(&proxy, &())
+				.map_err(|v| {
+					// The proxy only has underlying vertices, as none were added
+					violation = Some(v.map_vertices(|v| {
+						match v
+						{
+							ProxyVertex::Underlying(v) => v,
+							ProxyVertex::New(_) => unreachable!(),
+						}
+					}))
+				})
+				.is_ok()
+		});
+		violation.map_or(Ok(()), Err)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut, const K: usize> RemoveVertex for KConnectedGraph<C, K>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		proxy_remove_vertex::<KConnectedGraph<_, K>, _>(self.0.graph_mut(), v.borrow())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut, const K: usize> RemoveEdge for KConnectedGraph<C, K>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		proxy_remove_edge_where_weight::<KConnectedGraph<_, K>, _, _>(
+			self.0.graph_mut(),
+			source.borrow(),
+			sink.borrow(),
+			f,
+		)
+	}
+}
+
+impl<C: Ensure, const K: usize> Weak for KConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> Unilateral for KConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> Connected for KConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> KConnected<K> for KConnectedGraph<C, K> {}
+impl_implied!(KConnected for KConnectedGraph);
+
+impl_ensurer! {
+	use<C; const K: usize> KConnectedGraph<C, K>: Ensure, KConnected, Connected, Unilateral, Weak,
+	RemoveVertex, RemoveEdge,
+	// A new vertex wouldn't be connected to the rest of the graph
+	NewVertex
+	as (self.0) : C
+}
+
+/// Ensures the underlying graph is `K`-edge-connected.
+///
+/// For `K` up to 2, the check takes time linear in the number of vertices and
+/// edges, like for [KConnectedGraph](struct.KConnectedGraph.html), finding
+/// bridges instead of articulation points.
+/// For larger `K`, it checks that the graph is connected without each set of
+/// fewer than `K` edges, which takes `O(m^(K-1) (n + m))` time.
+///
+/// The same check is done when removing vertices or edges, which fails if the
+/// graph would no longer be `K`-edge-connected.
+#[derive(Clone, Debug)]
+pub struct KEdgeConnectedGraph<C: Ensure, const K: usize>(C);
+
+impl<C: Ensure, const K: usize> Ensure for KEdgeConnectedGraph<C, K>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	/// Gives the vertices that can't reach each other once some fewer than `K`
+	/// edges are removed.
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		let g = c.graph();
+		if K <= 2
+		{
+			let numbered = Numbered::new(g);
+			return if K == 2
+			{
+				numbered.validate(true)
+			}
+			else
+			{
+				numbered.disconnected().map_or(Ok(()), |(x, y)| {
+					Err(Violation::Unreachable(
+						numbered.vertices[x],
+						numbered.vertices[y],
+					))
+				})
+			};
+		}
+		let edges: Vec<_> = g.all_edges().map(|(so, si, _)| (so, si)).collect();
+		let mut violation = None;
+
+		all_subsets(edges.len(), K.saturating_sub(1), |removed| {
+			let mut proxy = EdgeProxyGraph::new(g);
+			for idx in removed
+			{
+				let (source, sink) = edges[*idx];
+				proxy
+					.remove_edge(source, sink)
+					.unwrap_or_else(|_| panic!("Couldn't remove an edge from the proxy"));
+			}
+			ConnectedGraph::validate(&proxy, &())
+				.map_err(|v| violation = Some(v))
+				.is_ok()
+		});
+		violation.map_or(Ok(()), Err)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut, const K: usize> RemoveVertex for KEdgeConnectedGraph<C, K>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		proxy_remove_vertex::<KEdgeConnectedGraph<_, K>, _>(self.0.graph_mut(), v.borrow())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut, const K: usize> RemoveEdge for KEdgeConnectedGraph<C, K>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		proxy_remove_edge_where_weight::<KEdgeConnectedGraph<_, K>, _, _>(
+			self.0.graph_mut(),
+			source.borrow(),
+			sink.borrow(),
+			f,
+		)
+	}
+}
+
+impl<C: Ensure, const K: usize> Weak for KEdgeConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> Unilateral for KEdgeConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> Connected for KEdgeConnectedGraph<C, K> {}
+impl<C: Ensure, const K: usize> KEdgeConnected<K> for KEdgeConnectedGraph<C, K> {}
+impl_implied!(KEdgeConnected for KEdgeConnectedGraph);
+
+impl_ensurer! {
+	use<C; const K: usize> KEdgeConnectedGraph<C, K>: Ensure, KEdgeConnected, Connected, Unilateral,
+	Weak, RemoveVertex, RemoveEdge,
+	// A new vertex wouldn't be connected to the rest of the graph
+	NewVertex
+	as (self.0) : C
+}
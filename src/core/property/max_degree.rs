@@ -0,0 +1,205 @@
+use crate::core::{
+	property::{AddEdge, NewLeafDirected, NewLeafUndirected, Rooted, RootedGraph, Tree, TreeGraph},
+	proxy::EdgeProxyGraph,
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use duplicate::duplicate_item;
+use std::borrow::Borrow;
+
+/// A graph where no vertex is the source of more than `N` edges.
+///
+/// For undirected graphs, this is the same as
+/// [MaxDegree](trait.MaxDegree.html).
+pub trait MaxOutDegree<const N: usize>: Graph {}
+
+/// A graph where no vertex is the sink of more than `N` edges.
+///
+/// For undirected graphs, this is the same as
+/// [MaxDegree](trait.MaxDegree.html).
+pub trait MaxInDegree<const N: usize>: Graph {}
+
+/// A graph where no vertex has more than `N` incident edges.
+pub trait MaxDegree<const N: usize>: Graph {}
+
+/// A directed tree where every vertex has at most one parent and two children.
+///
+/// Edges go from parents to their children.
+/// The first child of a vertex is its left child and the second its right
+/// child, in the order the graph returns the edges sourced in the vertex.
+/// Therefore, a vertex with only one child always has a left child.
+pub trait BinaryTree:
+	Tree<Directedness = Directed> + Rooted + MaxOutDegree<2> + MaxInDegree<1>
+{
+	/// Returns the left child of the given vertex, if it has one.
+	fn left(&self, v: impl Borrow<Self::Vertex>) -> Option<Self::Vertex>
+	{
+		self.edges_sourced_in(v).next().map(|(child, _)| child)
+	}
+
+	/// Returns the right child of the given vertex, if it has one.
+	fn right(&self, v: impl Borrow<Self::Vertex>) -> Option<Self::Vertex>
+	{
+		self.edges_sourced_in(v).nth(1).map(|(child, _)| child)
+	}
+}
+
+impl<G> BinaryTree for G where
+	G: Tree<Directedness = Directed> + Rooted + MaxOutDegree<2> + MaxInDegree<1>
+{
+}
+
+/// Ensures no vertex is the source of more than `N` edges.
+///
+/// Edges can only be added if their source stays within the bound.
+#[derive(Clone, Debug)]
+pub struct MaxOutDegreeGraph<C: Ensure, const N: usize>(C);
+
+/// Ensures no vertex is the sink of more than `N` edges.
+///
+/// Edges can only be added if their sink stays within the bound.
+#[derive(Clone, Debug)]
+pub struct MaxInDegreeGraph<C: Ensure, const N: usize>(C);
+
+/// Ensures no vertex has more than `N` incident edges.
+///
+/// Edges can only be added if both their source and sink stay within the
+/// bound.
+#[derive(Clone, Debug)]
+pub struct MaxDegreeGraph<C: Ensure, const N: usize>(C);
+
+/// Ensures the underlying graph is a [binary tree](trait.BinaryTree.html).
+pub type BinaryTreeGraph<C> = RootedGraph<MaxInDegreeGraph<MaxOutDegreeGraph<TreeGraph<C>, 2>, 1>>;
+
+#[duplicate_item(
+	EnsurerGraph degree; [MaxOutDegreeGraph] [edges_sourced_in];
+	[MaxInDegreeGraph] [edges_sinked_in]; [MaxDegreeGraph] [edges_incident_on];
+)]
+impl<C: Ensure, const N: usize> Ensure for EnsurerGraph<C, N>
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		let g = c.graph();
+		g.all_vertices()
+			.find(|v| g.degree(v).nth(N).is_some())
+			.map_or(Ok(()), |v| Err(Violation::Degree(v)))
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph degree; [MaxOutDegreeGraph] [edges_sourced_in];
+	[MaxInDegreeGraph] [edges_sinked_in]; [MaxDegreeGraph] [edges_incident_on];
+)]
+impl<C: Ensure + GraphDerefMut, const N: usize> AddEdge for EnsurerGraph<C, N>
+where
+	C::Graph: AddEdge,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let mut proxy = EdgeProxyGraph::new(self.0.graph());
+		proxy.add_edge(source.borrow(), sink.borrow())?;
+		if let Some(v) = [*source.borrow(), *sink.borrow()]
+			.into_iter()
+			.find(|v| proxy.degree(v).nth(N).is_some())
+		{
+			Err(GraphError::rejected::<Self>(Violation::Degree(v)))
+		}
+		else
+		{
+			self.0.graph_mut().add_edge_weighted(source, sink, weight)
+		}
+	}
+}
+
+// The direction of the edge to the new leaf isn't known, so the parent must
+// have room for it either way.
+#[duplicate_item(
+	EnsurerGraph degree; [MaxOutDegreeGraph] [edges_sourced_in];
+	[MaxInDegreeGraph] [edges_sinked_in]; [MaxDegreeGraph] [edges_incident_on];
+)]
+impl<C: Ensure + GraphDerefMut, const N: usize> NewLeafUndirected for EnsurerGraph<C, N>
+where
+	C::Graph: NewLeafUndirected,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		if N == 0 || self.0.graph().degree(parent.borrow()).nth(N - 1).is_some()
+		{
+			return Err(GraphError::rejected::<Self>(Violation::Degree(
+				*parent.borrow(),
+			)));
+		}
+		self.0.graph_mut().new_leaf_weighted(parent, w, e)
+	}
+}
+
+#[duplicate_item(
+	EnsurerGraph degree counted_for_parent;
+	[MaxOutDegreeGraph] [edges_sourced_in] [to_new];
+	[MaxInDegreeGraph] [edges_sinked_in] [!to_new];
+	[MaxDegreeGraph] [edges_incident_on] [true];
+)]
+impl<C: Ensure + GraphDerefMut, const N: usize> NewLeafDirected for EnsurerGraph<C, N>
+where
+	C::Graph: NewLeafDirected,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		// Whether the edge to the new leaf counts towards the parent's bound
+		let parent_counted = counted_for_parent;
+		if N == 0 || (parent_counted && self.0.graph().degree(parent.borrow()).nth(N - 1).is_some())
+		{
+			return Err(GraphError::rejected::<Self>(Violation::Degree(
+				*parent.borrow(),
+			)));
+		}
+		self.0.graph_mut().new_leaf_weighted(parent, to_new, w, e)
+	}
+}
+
+impl<C: Ensure, const N: usize> MaxOutDegree<N> for MaxOutDegreeGraph<C, N> {}
+impl<C: Ensure, const N: usize> MaxInDegree<N> for MaxInDegreeGraph<C, N> {}
+impl<C: Ensure, const N: usize> MaxDegree<N> for MaxDegreeGraph<C, N> {}
+
+impl_ensurer! {
+	use<C; const N: usize> MaxOutDegreeGraph<C, N>: Ensure, MaxOutDegree, AddEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+}
+
+impl_ensurer! {
+	use<C; const N: usize> MaxInDegreeGraph<C, N>: Ensure, MaxInDegree, AddEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+}
+
+impl_ensurer! {
+	use<C; const N: usize> MaxDegreeGraph<C, N>: Ensure, MaxDegree, AddEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+}
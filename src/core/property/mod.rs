@@ -2,26 +2,42 @@
 mod impl_ensurer;
 mod acyclic;
 mod base_props;
+mod bipartite;
 mod connected;
 mod directedness_ensurers;
+mod dyn_ensured;
+mod dynamic_connectivity;
+mod edge_indexed;
+mod eulerian;
+mod forest;
 mod has_vertex;
+mod k_connected;
+mod max_degree;
 mod no_loops;
+mod non_negative_weights;
+mod planar;
 mod reflexive;
 mod rooted;
+mod rooted_tree;
 mod simple;
 mod subgraph;
+mod topologically_ordered;
 mod tree;
 mod unilateral;
 mod unique;
 mod weak;
 
+pub(crate) use self::dynamic_connectivity::DynamicConnectivity;
 pub use self::{
-	acyclic::*, base_props::*, connected::*, directedness_ensurers::*, has_vertex::*, no_loops::*,
-	reflexive::*, rooted::*, simple::*, subgraph::*, tree::*, unilateral::*, unique::*, weak::*,
+	acyclic::*, base_props::*, bipartite::*, connected::*, directedness_ensurers::*,
+	dyn_ensured::*, edge_indexed::*, eulerian::*, forest::*, has_vertex::*, k_connected::*,
+	max_degree::*, no_loops::*, non_negative_weights::*, planar::*, reflexive::*, rooted::*,
+	rooted_tree::*, simple::*, subgraph::*, topologically_ordered::*, tree::*, unilateral::*,
+	unique::*, weak::*,
 };
 use crate::core::{
 	proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
-	Ensure,
+	Ensure, Graph, GraphError,
 };
 use std::borrow::Borrow;
 
@@ -36,11 +52,12 @@ pub fn proxy_remove_edge_where_weight<'a, C, G, F>(
 	source: &G::Vertex,
 	sink: &G::Vertex,
 	f: F,
-) -> Result<G::EdgeWeight, ()>
+) -> Result<G::EdgeWeight, GraphError<G::Vertex>>
 where
 	G: RemoveEdge,
 	F: Fn(&G::EdgeWeight) -> bool,
 	C: Ensure<Ensured = EdgeProxyGraph<&'a G>, Base = EdgeProxyGraph<&'a G>, Payload = ()>,
+	C::Graph: Graph<Vertex = G::Vertex>,
 {
 	// We use the unsafe block here to allow us to use 'g' again later.
 	// Currently, the compiler can't see when 'proxy' is no longer used,
@@ -52,16 +69,13 @@ where
 	let mut proxy = EdgeProxyGraph::new(g2);
 	proxy.remove_edge(source, sink)?;
 
-	if C::can_ensure(&proxy, &())
+	match C::validate(&proxy, &())
 	{
 		// 	Here we use 'g' again since 'proxy' is no longer used.
 		// The compiler doesn't recognize that 'proxy' isn't used in this blocks,
 		// and therefore, this wouldn't work when giving 'proxy' 'g' directly.
-		g.remove_edge_where_weight(source, sink, f)
-	}
-	else
-	{
-		Err(())
+		Ok(()) => g.remove_edge_where_weight(source, sink, f),
+		Err(violation) => Err(GraphError::rejected::<C>(violation)),
 	}
 }
 
@@ -71,10 +85,14 @@ where
 /// the vertex isn't removed in the first place.
 ///
 /// Will always need a type annotation for the Ensure 'C'.
-pub fn proxy_remove_vertex<'a, C, G>(g: &'a mut G, v: &G::Vertex) -> Result<G::VertexWeight, ()>
+pub fn proxy_remove_vertex<'a, C, G>(
+	g: &'a mut G,
+	v: &G::Vertex,
+) -> Result<G::VertexWeight, GraphError<G::Vertex>>
 where
 	G: RemoveVertex,
 	C: Ensure<Ensured = VertexProxyGraph<&'a G>, Base = VertexProxyGraph<&'a G>, Payload = ()>,
+	C::Graph: Graph<Vertex = ProxyVertex<G::Vertex>>,
 {
 	// 	We use the unsafe block here to allow us to use 'g' again later.
 	// Currently, the compiler can't see when 'proxy' is no longer used,
@@ -86,14 +104,22 @@ where
 
 	proxy
 		.remove_vertex(&ProxyVertex::Underlying(v.borrow().clone()))
-		.expect("Couldn't remove a vertex from the proxy");
+		.map_err(|_| GraphError::MissingVertex(*v))?;
 
-	if C::can_ensure(&proxy, &())
-	{
-		g.remove_vertex(v)
-	}
-	else
+	match C::validate(&proxy, &())
 	{
-		Err(())
+		Ok(()) => g.remove_vertex(v),
+		Err(violation) =>
+		{
+			// The proxy only removes vertices, so all its vertices are in 'g'
+			let violation = violation.map_vertices(|v| {
+				match v
+				{
+					ProxyVertex::Underlying(v) => v,
+					ProxyVertex::New(_) => unreachable!(),
+				}
+			});
+			Err(GraphError::rejected::<C>(violation))
+		},
 	}
 }
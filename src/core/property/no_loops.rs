@@ -1,4 +1,4 @@
-use crate::core::{property::AddEdge, Ensure, Graph, GraphDerefMut};
+use crate::core::{property::AddEdge, Ensure, Graph, GraphDerefMut, GraphError, Violation};
 use std::borrow::Borrow;
 
 /// A marker trait for graphs containing no graph loops.
@@ -17,11 +17,17 @@ impl<C: Ensure> Ensure for NoLoopsGraph<C>
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		c.graph()
 			.all_vertices()
-			.all(|v| c.graph().edges_between(v, v).next().is_none())
+			.find(|v| c.graph().edges_between(v, v).next().is_some())
+			.map_or(Ok(()), |v| Err(Violation::Loop(v)))
 	}
 }
 
@@ -34,11 +40,13 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if source.borrow() == sink.borrow()
 		{
-			Err(())
+			Err(GraphError::rejected::<Self>(Violation::Loop(
+				*source.borrow(),
+			)))
 		}
 		else
 		{
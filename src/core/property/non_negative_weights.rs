@@ -0,0 +1,216 @@
+use crate::core::{
+	property::{AddEdge, NewLeafDirected, NewLeafUndirected},
+	BaseGraph, Ensure, Graph, GraphDerefMut, GraphError, GraphMut, Violation,
+};
+use delegate::delegate;
+use num_traits::{Unsigned, Zero};
+use std::borrow::Borrow;
+
+/// A graph where no edge weight is less than zero.
+///
+/// Edge weights are compared to their [zero](num_traits::Zero::zero) value, so
+/// weights that can't be compared to it, like floating point `NaN`s, are not
+/// allowed either.
+///
+/// Base graphs with [unsigned](num_traits::Unsigned) edge weights have this
+/// property automatically. Graphs with other weights can be guarded by
+/// [NonNegativeWeightsGraph](struct.NonNegativeWeightsGraph.html).
+pub trait NonNegativeWeights: Graph {}
+
+impl<G: BaseGraph + Graph> NonNegativeWeights for G where G::EdgeWeight: Unsigned {}
+
+/// Ensures no edge weight is less than zero.
+///
+/// Edges with negative weights are rejected when added.
+/// Since the weights handed out by [GraphMut] can be set to anything, it is
+/// only available when the weights are [unsigned](num_traits::Unsigned).
+/// Otherwise, vertex weights can still be mutated, while edge weights must be
+/// changed through [`set_edge_weight`](#method.set_edge_weight), which checks
+/// them.
+#[derive(Clone, Debug)]
+pub struct NonNegativeWeightsGraph<C: Ensure>(C)
+where
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero;
+
+/// Returns whether the given weight is at least zero.
+fn non_negative<W: PartialOrd + Zero>(w: &W) -> bool
+{
+	*w >= W::zero()
+}
+
+impl<C: Ensure + GraphDerefMut> NonNegativeWeightsGraph<C>
+where
+	C::Graph: GraphMut,
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+{
+	/// Returns all vertices with mutable references to their weights.
+	pub fn all_vertices_weighted_mut(
+		&mut self,
+	) -> impl Iterator<
+		Item = (
+			<C::Graph as Graph>::Vertex,
+			&mut <C::Graph as Graph>::VertexWeight,
+		),
+	>
+	{
+		self.0.graph_mut().all_vertices_weighted_mut()
+	}
+
+	/// Returns a mutable reference to the weight of the given vertex, or
+	/// `None` if it isn't in the graph.
+	pub fn vertex_weight_mut(
+		&mut self,
+		v: impl Borrow<<C::Graph as Graph>::Vertex>,
+	) -> Option<&mut <C::Graph as Graph>::VertexWeight>
+	{
+		self.0.graph_mut().vertex_weight_mut(v)
+	}
+
+	/// Sets the weight of an edge between the given vertices, returning its
+	/// previous weight.
+	///
+	/// Fails if there is no such edge or the new weight is negative, in which
+	/// case the edge is left unchanged.
+	pub fn set_edge_weight(
+		&mut self,
+		source: impl Borrow<<C::Graph as Graph>::Vertex>,
+		sink: impl Borrow<<C::Graph as Graph>::Vertex>,
+		weight: <C::Graph as Graph>::EdgeWeight,
+	) -> Result<<C::Graph as Graph>::EdgeWeight, GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if !non_negative(&weight)
+		{
+			return Err(GraphError::rejected::<Self>(Violation::EdgeWeight(
+				source, sink,
+			)));
+		}
+		self.0
+			.graph_mut()
+			.edges_between_mut(source, sink)
+			.next()
+			.map(|w| std::mem::replace(w, weight))
+			.ok_or(GraphError::MissingEdge(source, sink))
+	}
+}
+
+impl<C: Ensure> Ensure for NonNegativeWeightsGraph<C>
+where
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c)
+	}
+
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		c.graph()
+			.all_edges()
+			.find(|(_, _, w)| !non_negative(&**w))
+			.map_or(Ok(()), |(so, si, _)| Err(Violation::EdgeWeight(so, si)))
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for NonNegativeWeightsGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		if !non_negative(&weight)
+		{
+			return Err(GraphError::rejected::<Self>(Violation::EdgeWeight(
+				*source.borrow(),
+				*sink.borrow(),
+			)));
+		}
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for NonNegativeWeightsGraph<C>
+where
+	C::Graph: NewLeafUndirected,
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		if !non_negative(&e)
+		{
+			// The leaf doesn't exist yet, so the edge can't be named
+			return Err(GraphError::rejected::<Self>(Violation::Unspecified));
+		}
+		self.0.graph_mut().new_leaf_weighted(parent, w, e)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafDirected for NonNegativeWeightsGraph<C>
+where
+	C::Graph: NewLeafDirected,
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		if !non_negative(&e)
+		{
+			// The leaf doesn't exist yet, so the edge can't be named
+			return Err(GraphError::rejected::<Self>(Violation::Unspecified));
+		}
+		self.0.graph_mut().new_leaf_weighted(parent, to_new, w, e)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> GraphMut for NonNegativeWeightsGraph<C>
+where
+	C::Graph: GraphMut,
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero + Unsigned,
+{
+	delegate! {
+		to self.0.graph_mut() {
+			fn all_vertices_weighted_mut(
+				&mut self,
+			) -> impl Iterator<Item = (Self::Vertex, &mut Self::VertexWeight)>;
+
+			fn edges_between_mut(
+				&mut self,
+				source: impl Borrow<Self::Vertex>,
+				sink: impl Borrow<Self::Vertex>,
+			) -> impl Iterator<Item = &mut Self::EdgeWeight>;
+		}
+	}
+}
+
+impl<C: Ensure> NonNegativeWeights for NonNegativeWeightsGraph<C> where
+	<C::Graph as Graph>::EdgeWeight: PartialOrd + Zero
+{
+}
+
+impl_ensurer! {
+	use<C> NonNegativeWeightsGraph<C>: Ensure, NonNegativeWeights, GraphMut, AddEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+	where <C::Graph as Graph>::EdgeWeight: PartialOrd + Zero,
+}
@@ -0,0 +1,261 @@
+use crate::{
+	algo::{planar_embedding, planar_embedding_with},
+	core::{
+		property::{
+			AddEdge, NewLeafDirected, NewLeafUndirected, NewVertex, RemoveEdge, RemoveVertex,
+		},
+		Directed, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+	},
+};
+use std::{
+	borrow::Borrow,
+	collections::HashMap,
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
+};
+
+/// A planar graph.
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing.
+/// Such a drawing is described by its combinatorial embedding: the order of
+/// the neighbors around each vertex.
+pub trait Planar: Graph
+{
+	/// Returns the neighbors of the given vertex in clockwise order around it
+	/// in a planar drawing of the graph.
+	///
+	/// Each neighbor is returned once, regardless of the number or direction
+	/// of the edges to it, and loops are ignored.
+	/// Which neighbor is returned first is unspecified.
+	fn clockwise_neighbors(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::Vertex>;
+}
+
+/// Ensures the underlying graph is planar.
+///
+/// The embedding is found when the graph is ensured and kept up to date as
+/// the graph changes.
+/// Adding an edge between vertices that aren't already neighbors runs the
+/// planarity test again, failing if the edge would make the graph non-planar.
+/// Removing a vertex also finds a new embedding, as the other vertices may
+/// change.
+/// Both take the time [planar_embedding](crate::algo::planar_embedding) does.
+/// New vertices and new leaves are added to the embedding directly.
+///
+/// Rejections are [Unspecified](Violation::Unspecified), as the planarity test
+/// only finds that there is no embedding, not a Kuratowski subgraph that would
+/// show why.
+#[derive(Clone)]
+pub struct PlanarGraph<C: Ensure>(
+	C,
+	HashMap<<C::Graph as Graph>::Vertex, Vec<<C::Graph as Graph>::Vertex>>,
+);
+
+impl<C: Ensure> Debug for PlanarGraph<C>
+where
+	C: Debug,
+	<C::Graph as Graph>::Vertex: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("PlanarGraph")
+			.field(&self.0)
+			.field(&self.1)
+			.finish()
+	}
+}
+
+impl<C: Ensure> Ensure for PlanarGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		let embedding = planar_embedding(c.graph()).unwrap_or_default();
+		Self(c, embedding)
+	}
+
+	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	{
+		planar_embedding(c.graph()).is_some()
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for PlanarGraph<C>
+where
+	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_weighted(w)?;
+		self.1.insert(v, Vec::new());
+		Ok(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for PlanarGraph<C>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let w = self.0.graph_mut().remove_vertex(v)?;
+		self.1 = planar_embedding(self.0.graph()).unwrap_or_default();
+		Ok(w)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for PlanarGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let adjacent = source == sink
+			|| self
+				.1
+				.get(&source)
+				.is_some_and(|neighbors| neighbors.contains(&sink));
+
+		if adjacent
+		{
+			self.0.graph_mut().add_edge_weighted(source, sink, weight)
+		}
+		else
+		{
+			GraphError::check_vertices(self.0.graph(), [source, sink])?;
+			let embedding = planar_embedding_with(self.0.graph(), Some((source, sink)))
+				.ok_or_else(|| GraphError::rejected::<Self>(Violation::Unspecified))?;
+			self.0.graph_mut().add_edge_weighted(source, sink, weight)?;
+			self.1 = embedding;
+			Ok(())
+		}
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveEdge for PlanarGraph<C>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_where_weight(source, sink, f)?;
+
+		// Removing an edge keeps the embedding planar, but the vertices may no
+		// longer be neighbors
+		let graph = self.0.graph();
+		if graph.edges_between(source, sink).next().is_none()
+			&& graph.edges_between(sink, source).next().is_none()
+		{
+			for (v, n) in [(source, sink), (sink, source)]
+			{
+				if let Some(neighbors) = self.1.get_mut(&v)
+				{
+					neighbors.retain(|v| *v != n);
+				}
+			}
+		}
+		Ok(w)
+	}
+}
+
+impl<C: Ensure> PlanarGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Adds a new leaf to the embedding, which fits in any face around its
+	/// parent.
+	fn embed_leaf(&mut self, parent: <C::Graph as Graph>::Vertex, leaf: <C::Graph as Graph>::Vertex)
+	{
+		self.1.entry(parent).or_default().push(leaf);
+		self.1.insert(leaf, vec![parent]);
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for PlanarGraph<C>
+where
+	C::Graph: NewLeafUndirected,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let parent = *parent.borrow();
+		let leaf = self.0.graph_mut().new_leaf_weighted(parent, w, e)?;
+		self.embed_leaf(parent, leaf);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafDirected for PlanarGraph<C>
+where
+	C::Graph: NewLeafDirected<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let parent = *parent.borrow();
+		let leaf = self.0.graph_mut().new_leaf_weighted(parent, to_new, w, e)?;
+		self.embed_leaf(parent, leaf);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure> Planar for PlanarGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn clockwise_neighbors(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = Self::Vertex>
+	{
+		self.1.get(v.borrow()).into_iter().flatten().copied()
+	}
+}
+
+impl_ensurer! {
+	use<C> PlanarGraph<C>: Ensure, Planar, NewVertex, RemoveVertex, AddEdge, RemoveEdge,
+		NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+}
@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex},
-	Ensure, Graph, GraphDerefMut,
+	Ensure, Graph, GraphDerefMut, GraphError, Violation,
 };
 use std::borrow::Borrow;
 
@@ -33,20 +33,27 @@ where
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		let g = c.graph();
-		g.all_vertices().all(|v| {
+		for v in g.all_vertices()
+		{
 			let mut between = g.edges_between(v, v);
-			if let Some(_) = between.next()
+			if between.next().is_none()
 			{
-				between.next().is_none()
+				return Err(Violation::MissingLoop(v));
 			}
-			else
+			if between.next().is_some()
 			{
-				false
+				return Err(Violation::DuplicateEdge(v, v));
 			}
-		})
+		}
+		Ok(())
 	}
 }
 
@@ -55,7 +62,10 @@ where
 	C::Graph: NewVertex + AddEdge,
 	<C::Graph as Graph>::EdgeWeight: Default,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let v = self.0.graph_mut().new_vertex_weighted(w)?;
 		self.0.graph_mut().add_edge(v.clone(), v.clone())?;
@@ -68,7 +78,10 @@ where
 	C::Graph: RemoveVertex + RemoveEdge,
 	<C::Graph as Graph>::EdgeWeight: Default,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		self.0.graph_mut().remove_edge(v.borrow(), v.borrow())?;
 		self.0.graph_mut().remove_vertex(v)
@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{HasVertex, VertexInGraph},
-	Ensure, Graph, GraphMut, ReleasePayload,
+	Ensure, Graph, GraphError, GraphMut, ReleasePayload, Violation,
 };
 use std::{
 	borrow::Borrow,
@@ -55,7 +55,7 @@ pub trait RootedMut: Rooted
 	///
 	/// Returns error if it was unable to change the root of the graph.
 	/// E.g. if the given vertex is not in the graph.
-	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), ()>;
+	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), GraphError<Self::Vertex>>;
 }
 
 /// Ensures a specific vertex is the root of the graph.
@@ -106,6 +106,14 @@ impl<C: Ensure> Ensure for RootedGraph<C>
 	{
 		VertexInGraph::<C>::can_ensure(c, &[*p])
 	}
+
+	fn validate(
+		c: &Self::Ensured,
+		p: &<C::Graph as Graph>::Vertex,
+	) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		VertexInGraph::<C>::validate(c, &[*p])
+	}
 }
 
 impl<C: Ensure> Rooted for RootedGraph<C>
@@ -118,14 +126,14 @@ impl<C: Ensure> Rooted for RootedGraph<C>
 
 impl<C: Ensure> RootedMut for RootedGraph<C>
 {
-	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), ()>
+	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), GraphError<Self::Vertex>>
 	{
 		self.0.set_vertex(&[*v.borrow()])
 	}
 }
 
 impl_ensurer! {
-	use<C> RootedGraph<C>: ReleasePayload, Ensure, Rooted, RootedMut
+	use<C> RootedGraph<C>: ReleasePayload, Ensure, Rooted, RootedMut, RootedTree
 	as (self.0) : VertexInGraph<C>
 	where C: Ensure
 }
@@ -0,0 +1,172 @@
+use crate::core::{
+	property::{Rooted, RootedGraph, Tree, TreeGraph},
+	proxy::SubgraphProxy,
+	Ensure,
+};
+use std::borrow::Borrow;
+
+/// A tree with a root, giving every other vertex a parent and the root's
+/// descendants children.
+///
+/// Parents and children are given by the path from the root, ignoring the
+/// direction of edges.
+/// Children are returned in the order the tree returns the vertex's incident
+/// edges.
+///
+/// The default methods search the tree from the root, so they each take linear
+/// time.
+pub trait RootedTree: Tree + Rooted
+{
+	/// Returns the parent of the given vertex, or `None` if the vertex is the
+	/// root or isn't in the tree.
+	fn parent(&self, v: impl Borrow<Self::Vertex>) -> Option<Self::Vertex>
+	{
+		let v = *v.borrow();
+		let order = level_order_with_parents(self);
+		order
+			.iter()
+			.find(|(v2, _)| *v2 == v)
+			.and_then(|(_, p)| p.map(|p| order[p].0))
+	}
+
+	/// Returns the children of the given vertex.
+	fn children(&self, v: impl Borrow<Self::Vertex>) -> impl Iterator<Item = Self::Vertex>
+	{
+		let v = *v.borrow();
+		let parent = self.parent(v);
+		self.edges_incident_on(v)
+			.map(|(n, _)| n)
+			.filter(move |n| Some(*n) != parent)
+	}
+
+	/// Returns the number of edges between the root and the given vertex, or
+	/// `None` if the vertex isn't in the tree.
+	fn depth(&self, v: impl Borrow<Self::Vertex>) -> Option<usize>
+	{
+		self.contains_vertex(v.borrow())
+			.then(|| self.ancestors(v).count())
+	}
+
+	/// Returns the ancestors of the given vertex, starting with its parent
+	/// and ending with the root.
+	fn ancestors(&self, v: impl Borrow<Self::Vertex>) -> impl Iterator<Item = Self::Vertex>
+	{
+		let v = *v.borrow();
+		let order = level_order_with_parents(self);
+		let mut parent = order.iter().find(|(v2, _)| *v2 == v).and_then(|(_, p)| *p);
+		let mut ancestors = Vec::new();
+		while let Some(p) = parent
+		{
+			ancestors.push(order[p].0);
+			parent = order[p].1;
+		}
+		ancestors.into_iter()
+	}
+
+	/// Returns the subtree rooted in the given vertex, containing it and all
+	/// its descendants, or `None` if the vertex isn't in the tree.
+	fn subtree(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Option<RootedGraph<TreeGraph<SubgraphProxy<&Self>>>>
+	where
+		Self: Sized,
+	{
+		let v = *v.borrow();
+		if !self.contains_vertex(v)
+		{
+			return None;
+		}
+
+		let mut subtree = SubgraphProxy::new(self);
+		for u in pre_order_from(self, v, self.parent(v), false)
+		{
+			subtree.expand(u).unwrap();
+		}
+		Some(RootedGraph::ensure_unchecked(
+			TreeGraph::ensure_unchecked(subtree, ()),
+			v,
+		))
+	}
+
+	/// Returns all vertices in pre-order, i.e. each vertex before its
+	/// descendants, starting with the root.
+	fn pre_order(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		pre_order_from(self, self.root(), None, false).into_iter()
+	}
+
+	/// Returns all vertices in post-order, i.e. each vertex after its
+	/// descendants, ending with the root.
+	fn post_order(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		// The reverse of visiting each vertex before its descendants, with
+		// the children in reverse order
+		pre_order_from(self, self.root(), None, true)
+			.into_iter()
+			.rev()
+	}
+
+	/// Returns all vertices in level-order, i.e. ordered by their depth,
+	/// starting with the root.
+	fn level_order(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		level_order_with_parents(self).into_iter().map(|(v, _)| v)
+	}
+}
+
+/// Returns the given vertex and its descendants in pre-order.
+///
+/// The parent of the vertex must be given, such that the search doesn't
+/// continue through it. If `reverse` is true, the children of each vertex are
+/// visited in reverse order.
+fn pre_order_from<G: Tree + ?Sized>(
+	tree: &G,
+	v: G::Vertex,
+	parent: Option<G::Vertex>,
+	reverse: bool,
+) -> Vec<G::Vertex>
+{
+	let mut order = Vec::new();
+	let mut stack = vec![(v, parent)];
+	while let Some((v, parent)) = stack.pop()
+	{
+		order.push(v);
+		let children = tree
+			.edges_incident_on(v)
+			.map(|(n, _)| n)
+			.filter(|n| Some(*n) != parent)
+			.map(|n| (n, Some(v)));
+		let start = stack.len();
+		stack.extend(children);
+		if !reverse
+		{
+			// The stack must pop the first child first
+			stack[start..].reverse();
+		}
+	}
+	order
+}
+
+/// Returns all vertices of the tree in level-order, each with the index of
+/// its parent in the order.
+fn level_order_with_parents<G: Tree + Rooted + ?Sized>(tree: &G)
+	-> Vec<(G::Vertex, Option<usize>)>
+{
+	let mut order: Vec<(G::Vertex, Option<usize>)> = vec![(tree.root(), None)];
+	let mut idx = 0;
+	while let Some(&(v, parent)) = order.get(idx)
+	{
+		let parent = parent.map(|p| order[p].0);
+		order.extend(
+			tree.edges_incident_on(v)
+				.map(|(n, _)| n)
+				.filter(|n| Some(*n) != parent)
+				.map(|n| (n, Some(idx))),
+		);
+		idx += 1;
+	}
+	order
+}
+
+impl<C: Ensure> RootedTree for RootedGraph<C> where Self: Tree {}
@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, NoLoops, NoLoopsGraph, Unique, UniqueGraph},
-	Ensure, Graph, GraphDerefMut, Undirected,
+	Ensure, Graph, GraphDerefMut, GraphError, Undirected, Violation,
 };
 use duplicate::duplicate_item;
 use std::borrow::Borrow;
@@ -33,9 +33,15 @@ where
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
 	{
-		NoLoopsGraph::<C>::can_ensure(c, &()) && UniqueGraph::can_ensure(c, &())
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		NoLoopsGraph::<C>::validate(c, &())?;
+		UniqueGraph::<C>::validate(c, &())
 	}
 }
 
@@ -48,12 +54,18 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
-		if !(source.borrow() != sink.borrow()
-			&& Self::can_add_edge(self, source.borrow(), sink.borrow()))
+		let (so, si) = (*source.borrow(), *sink.borrow());
+		if so == si
+		{
+			Err(GraphError::rejected::<Self>(Violation::Loop(so)))
+		}
+		else if !Self::can_add_edge(self, so, si)
 		{
-			Err(())
+			Err(GraphError::rejected::<Self>(Violation::DuplicateEdge(
+				so, si,
+			)))
 		}
 		else
 		{
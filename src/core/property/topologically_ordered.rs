@@ -0,0 +1,489 @@
+use crate::core::{
+	property::{
+		Acyclic, AcyclicGraph, AddEdge, NewLeafDirected, NewLeafUndirected, NewVertex, NoLoops,
+		RemoveEdge, RemoveVertex, StableRemoveVertex,
+	},
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use std::{
+	borrow::Borrow,
+	collections::{HashMap, HashSet},
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
+};
+
+/// A directed acyclic graph with a topological order of its vertices.
+///
+/// In a topological order, the source of every edge comes before its sink.
+pub trait TopologicallyOrdered: Acyclic
+{
+	/// Returns the vertices of the graph in topological order.
+	fn topological_order(&self) -> impl Iterator<Item = Self::Vertex>;
+
+	/// Returns the position of the given vertex in the topological order, or
+	/// `None` if the vertex isn't in the graph.
+	fn order_of(&self, v: impl Borrow<Self::Vertex>) -> Option<usize>;
+}
+
+/// Ensures the underlying directed graph is acyclic, maintaining a
+/// topological order of its vertices.
+///
+/// The order is found when the graph is ensured.
+/// Adding an edge whose sink comes before its source in the order uses the
+/// Pearce-Kelly algorithm, which only searches and reorders the vertices
+/// between the two, so adding edges one at a time stays fast in large graphs.
+/// New vertices are added to the end of the order.
+///
+/// The ensurer keeps its own index of the neighbors of each vertex, so that
+/// these searches take time linear in the number of edges they follow,
+/// whatever the underlying graph.
+///
+/// Vertices can only be removed if the underlying graph implements
+/// [StableRemoveVertex](trait.StableRemoveVertex.html), as the order would
+/// otherwise be lost when vertices change.
+#[derive(Clone)]
+pub struct TopologicallyOrderedGraph<C: Ensure>(
+	C,
+	Vec<<C::Graph as Graph>::Vertex>,
+	HashMap<<C::Graph as Graph>::Vertex, usize>,
+	Adjacency<<C::Graph as Graph>::Vertex>,
+);
+
+/// The sinks and the sources of the edges of each vertex, once per edge.
+type Adjacency<V> = HashMap<V, (Vec<V>, Vec<V>)>;
+
+impl<C: Ensure> TopologicallyOrderedGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Returns the sinks and sources of the edges of each vertex of the given
+	/// graph.
+	fn adjacency<G: Graph>(graph: &G) -> Adjacency<G::Vertex>
+	where
+		G::Vertex: Hash,
+	{
+		let mut adjacency: Adjacency<_> = graph
+			.all_vertices()
+			.map(|v| (v, Default::default()))
+			.collect();
+		for (source, sink, _) in graph.all_edges()
+		{
+			Self::insert_edge(&mut adjacency, source, sink);
+		}
+		adjacency
+	}
+
+	fn insert_edge<V: Copy + Eq + Hash>(adjacency: &mut Adjacency<V>, source: V, sink: V)
+	{
+		adjacency.entry(source).or_default().0.push(sink);
+		adjacency.entry(sink).or_default().1.push(source);
+	}
+
+	fn remove_edge<V: Copy + Eq + Hash>(adjacency: &mut Adjacency<V>, source: V, sink: V)
+	{
+		let remove_one = |vs: &mut Vec<V>, v| {
+			if let Some(idx) = vs.iter().position(|u| *u == v)
+			{
+				vs.swap_remove(idx);
+			}
+		};
+		if let Some((sinks, _)) = adjacency.get_mut(&source)
+		{
+			remove_one(sinks, sink);
+		}
+		if let Some((_, sources)) = adjacency.get_mut(&sink)
+		{
+			remove_one(sources, source);
+		}
+	}
+
+	/// Returns the vertices of the given graph in topological order, or `None`
+	/// if the graph has a cycle.
+	fn order_vertices<G: Graph>(
+		graph: &G,
+		adjacency: &Adjacency<G::Vertex>,
+	) -> Option<Vec<G::Vertex>>
+	where
+		G::Vertex: Hash,
+	{
+		let mut in_degree: HashMap<_, _> = adjacency
+			.iter()
+			.map(|(v, (_, sources))| (*v, sources.len()))
+			.collect();
+		let mut order: Vec<_> = graph.all_vertices().filter(|v| in_degree[v] == 0).collect();
+		let mut next = 0;
+		while let Some(&v) = order.get(next)
+		{
+			next += 1;
+			for sink in &adjacency[&v].0
+			{
+				let degree = in_degree.get_mut(sink)?;
+				*degree -= 1;
+				if *degree == 0
+				{
+					order.push(*sink);
+				}
+			}
+		}
+
+		(order.len() == in_degree.len()).then_some(order)
+	}
+
+	/// Returns the vertices that can be reached from the given vertex while
+	/// staying strictly between the given positions in the order.
+	///
+	/// Searches along edges if `forward`, otherwise against them.
+	/// Fails if the vertex at the `forward` end of the bounds is reached,
+	/// giving the cycle through it and the given vertex.
+	fn reachable_within<V: Copy + Eq + Hash>(
+		adjacency: &Adjacency<V>,
+		order: &HashMap<V, usize>,
+		v: V,
+		(lower, upper): (usize, usize),
+		forward: bool,
+	) -> Result<Vec<V>, GraphError<V>>
+	{
+		let mut found = vec![v];
+		// The index in 'found' of the vertex each was found from
+		let mut found_from = vec![0];
+		let mut visited: HashSet<_> = found.iter().copied().collect();
+		let mut next = 0;
+		while let Some(&v) = found.get(next)
+		{
+			next += 1;
+			let (sinks, sources) = &adjacency[&v];
+			for &n in if forward { sinks } else { sources }
+			{
+				let position = order[&n];
+				if forward && position == upper
+				{
+					let mut cycle = vec![v];
+					let mut idx = next - 1;
+					while idx != 0
+					{
+						idx = found_from[idx];
+						cycle.push(found[idx]);
+					}
+					cycle.push(n);
+					cycle.reverse();
+					return Err(GraphError::rejected::<Self>(Violation::Cycle(cycle)));
+				}
+				if lower < position && position < upper && visited.insert(n)
+				{
+					found.push(n);
+					found_from.push(next - 1);
+				}
+			}
+		}
+		Ok(found)
+	}
+}
+
+impl<C: Ensure> Debug for TopologicallyOrderedGraph<C>
+where
+	C: Debug,
+	<C::Graph as Graph>::Vertex: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("TopologicallyOrderedGraph")
+			.field(&self.0)
+			.field(&self.1)
+			.finish()
+	}
+}
+
+impl<C: Ensure> Ensure for TopologicallyOrderedGraph<C>
+where
+	C::Graph: Graph<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		let adjacency = Self::adjacency(c.graph());
+		let order = Self::order_vertices(c.graph(), &adjacency).unwrap_or_default();
+		let positions = order.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+		Self(c, order, positions, adjacency)
+	}
+
+	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	{
+		Self::order_vertices(c.graph(), &Self::adjacency(c.graph())).is_some()
+	}
+
+	fn validate(c: &Self::Ensured, p: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
+	{
+		if Self::can_ensure(c, p)
+		{
+			Ok(())
+		}
+		else
+		{
+			// Only cycles prevent a topological order
+			AcyclicGraph::<C>::validate(c, p).and(Err(Violation::Unspecified))
+		}
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for TopologicallyOrderedGraph<C>
+where
+	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Adds the new vertex to the end of the order.
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_weighted(w)?;
+		self.push_vertex(v);
+		Ok(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for TopologicallyOrderedGraph<C>
+where
+	C::Graph: StableRemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let w = self.0.graph_mut().remove_vertex(v.borrow())?;
+		if let Some(order) = self.2.remove(v.borrow())
+		{
+			self.1.remove(order);
+			for (i, v) in self.1.iter().enumerate().skip(order)
+			{
+				self.2.insert(*v, i);
+			}
+		}
+		let (sinks, sources) = self.3.remove(v.borrow()).unwrap_or_default();
+		for sink in sinks
+		{
+			if let Some((_, sink_sources)) = self.3.get_mut(&sink)
+			{
+				sink_sources.retain(|u| u != v.borrow());
+			}
+		}
+		for source in sources
+		{
+			if let Some((source_sinks, _)) = self.3.get_mut(&source)
+			{
+				source_sinks.retain(|u| u != v.borrow());
+			}
+		}
+		Ok(w)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for TopologicallyOrderedGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		GraphError::check_vertices(self.0.graph(), [source, sink])?;
+		let moved = Self::reorder_for(&self.2, &self.3, source, sink)?;
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)?;
+		self.apply_reorder(moved);
+		Self::insert_edge(&mut self.3, source, sink);
+		Ok(())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveEdge for TopologicallyOrderedGraph<C>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_where_weight(source, sink, f)?;
+		Self::remove_edge(&mut self.3, source, sink);
+		Ok(w)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for TopologicallyOrderedGraph<C>
+where
+	C::Graph: NewLeafUndirected,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let leaf = self
+			.0
+			.graph_mut()
+			.new_leaf_weighted(parent.borrow(), w, e)?;
+		// The tree decides which way the edge goes
+		let to_new = self
+			.0
+			.graph()
+			.edges_between(parent.borrow(), leaf)
+			.next()
+			.is_some();
+		self.push_leaf(*parent.borrow(), leaf, to_new);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafDirected for TopologicallyOrderedGraph<C>
+where
+	C::Graph: NewLeafDirected,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let leaf = self
+			.0
+			.graph_mut()
+			.new_leaf_weighted(parent.borrow(), to_new, w, e)?;
+		self.push_leaf(*parent.borrow(), leaf, to_new);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure> TopologicallyOrderedGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Adds a new vertex to the end of the order.
+	fn push_vertex(&mut self, v: <C::Graph as Graph>::Vertex)
+	{
+		self.2.insert(v, self.1.len());
+		self.1.push(v);
+		self.3.insert(v, Default::default());
+	}
+
+	/// Adds a new leaf, and its edge to or from the given parent, to the order.
+	fn push_leaf(
+		&mut self,
+		parent: <C::Graph as Graph>::Vertex,
+		leaf: <C::Graph as Graph>::Vertex,
+		to_new: bool,
+	)
+	{
+		self.push_vertex(leaf);
+		let (source, sink) = if to_new
+		{
+			(parent, leaf)
+		}
+		else
+		{
+			(leaf, parent)
+		};
+		// A new leaf can't close a cycle
+		if let Ok(moved) = Self::reorder_for(&self.2, &self.3, source, sink)
+		{
+			self.apply_reorder(moved);
+		}
+		Self::insert_edge(&mut self.3, source, sink);
+	}
+
+	/// Returns the vertices to reorder, in their new order, for an edge from
+	/// the given source to the given sink to be added.
+	///
+	/// Fails if the edge would close a cycle.
+	fn reorder_for<V: Copy + Eq + Hash>(
+		positions: &HashMap<V, usize>,
+		adjacency: &Adjacency<V>,
+		source: V,
+		sink: V,
+	) -> Result<Vec<V>, GraphError<V>>
+	{
+		let (lower, upper) = (positions[&sink], positions[&source]);
+		if lower == upper
+		{
+			return Err(GraphError::rejected::<Self>(Violation::Loop(source)));
+		}
+		if upper < lower
+		{
+			return Ok(Vec::new());
+		}
+
+		// The sink comes first, so the vertices reachable from it must be moved
+		// after the vertices that reach the source
+		// If the source is reachable from the sink, the edge would close a cycle
+		let forward = Self::reachable_within(adjacency, positions, sink, (lower, upper), true)?;
+		let backward = Self::reachable_within(adjacency, positions, source, (lower, upper), false)?;
+
+		let by_order = |mut vs: Vec<_>| {
+			vs.sort_by_key(|v| positions[v]);
+			vs
+		};
+		Ok(by_order(backward)
+			.into_iter()
+			.chain(by_order(forward))
+			.collect())
+	}
+
+	/// Puts the given vertices in the positions they take up, in the given
+	/// order.
+	fn apply_reorder(&mut self, moved: Vec<<C::Graph as Graph>::Vertex>)
+	{
+		let mut positions: Vec<_> = moved.iter().map(|v| self.2[v]).collect();
+		positions.sort_unstable();
+		for (v, order) in moved.into_iter().zip(positions)
+		{
+			self.1[order] = v;
+			self.2.insert(v, order);
+		}
+	}
+}
+
+impl<C: Ensure> NoLoops for TopologicallyOrderedGraph<C> {}
+impl<C: Ensure> Acyclic for TopologicallyOrderedGraph<C> {}
+
+impl<C: Ensure> TopologicallyOrdered for TopologicallyOrderedGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn topological_order(&self) -> impl Iterator<Item = Self::Vertex>
+	{
+		self.1.iter().copied()
+	}
+
+	fn order_of(&self, v: impl Borrow<Self::Vertex>) -> Option<usize>
+	{
+		self.2.get(v.borrow()).copied()
+	}
+}
+
+impl_ensurer! {
+	use<C> TopologicallyOrderedGraph<C>: Ensure, TopologicallyOrdered, Acyclic, NoLoops, NewVertex,
+		RemoveVertex, AddEdge, RemoveEdge, NewLeafUndirected, NewLeafDirected
+	as (self.0) : C
+}
@@ -1,13 +1,13 @@
 use crate::core::{
 	property::{
 		Acyclic, AcyclicGraph, AddEdge, Connected, ConnectedGraph, DirectedGraph, EdgeCount,
-		HasVertex, HasVertexGraph, NewVertex, NoLoops, RemoveVertex, Unique, UniqueGraph, Weak,
-		WeakGraph,
+		Forest, HasVertex, HasVertexGraph, NewVertex, NoLoops, NonNegativeWeights, RemoveVertex,
+		Unique, UniqueGraph, Weak, WeakGraph,
 	},
-	Directed, Ensure, Graph, GraphDerefMut, Guard,
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, Guard, Violation,
 };
 use duplicate::duplicate_item;
-use num_traits::{PrimInt, Unsigned};
+use num_traits::Zero;
 use std::borrow::Borrow;
 
 /// A marker trait for [weighted tree graphs](https://mathworld.wolfram.com/WeightedTree.html).
@@ -63,8 +63,8 @@ pub trait Tree: HasVertex + Unique + Weak + Acyclic
 	fn is_centered(&self) -> bool
 	where
 		Self: Connected,
-		Self: EdgeCount + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		self.centers().count() == 1
 	}
@@ -75,8 +75,8 @@ pub trait Tree: HasVertex + Unique + Weak + Acyclic
 	fn is_bicentered(&self) -> bool
 	where
 		Self: Connected,
-		Self: EdgeCount + Sized,
-		Self::EdgeWeight: PrimInt + Unsigned,
+		Self: EdgeCount + NonNegativeWeights + Sized,
+		Self::EdgeWeight: Copy + PartialOrd + Zero,
 	{
 		self.centers().count() == 2
 	}
@@ -96,12 +96,15 @@ pub trait NewLeafUndirected: Tree
 		parent: impl Borrow<Self::Vertex>,
 		w: Self::VertexWeight,
 		e: Self::EdgeWeight,
-	) -> Result<Self::Vertex, ()>;
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>;
 
 	/// Adds a new leaf to the tree with the given parent and default weights.
 	///
 	/// If the tree is directed, the weight is implicit.
-	fn new_leaf(&mut self, parent: impl Borrow<Self::Vertex>) -> Result<Self::Vertex, ()>
+	fn new_leaf(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	where
 		Self::VertexWeight: Default,
 		Self::EdgeWeight: Default,
@@ -121,7 +124,7 @@ pub trait NewLeafDirected: Tree<Directedness = Directed>
 		to_new: bool,
 		w: Self::VertexWeight,
 		e: Self::EdgeWeight,
-	) -> Result<Self::Vertex, ()>;
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>;
 
 	/// Adds a new leaf to the tree with the given parent, direction, and
 	/// default weights.
@@ -129,7 +132,7 @@ pub trait NewLeafDirected: Tree<Directedness = Directed>
 		&mut self,
 		parent: impl Borrow<Self::Vertex>,
 		to_new: bool,
-	) -> Result<Self::Vertex, ()>
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	where
 		Self::VertexWeight: Default,
 		Self::EdgeWeight: Default,
@@ -148,19 +151,24 @@ impl<C: Ensure> Ensure for TreeGraph<C>
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
-		UniqueGraph::can_guard(c)
-			&& AcyclicGraph::can_guard(c)
-			&& HasVertexGraph::can_guard(c)
-			&& if let Ok(g) = DirectedGraph::guard(c.graph())
-			{
-				WeakGraph::can_guard(&g)
-			}
-			else
-			{
-				ConnectedGraph::can_guard(c)
-			}
+		UniqueGraph::<C>::validate(c, &())?;
+		AcyclicGraph::<C>::validate(c, &())?;
+		HasVertexGraph::<C>::validate(c, &())?;
+		if let Ok(g) = DirectedGraph::guard(c.graph())
+		{
+			WeakGraph::validate(&g, &())
+		}
+		else
+		{
+			ConnectedGraph::<C>::validate(c, &())
+		}
 	}
 }
 
@@ -168,11 +176,15 @@ impl<C: Ensure + GraphDerefMut> RemoveVertex for TreeGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
+		GraphError::check_vertices(self, [*v.borrow()])?;
 		if !self.is_leaf(v.borrow())
 		{
-			return Err(());
+			return Err(GraphError::rejected::<Self>(Violation::Degree(*v.borrow())));
 		}
 		self.0.graph_mut().remove_vertex(v)
 	}
@@ -187,12 +199,9 @@ where
 		parent: impl Borrow<Self::Vertex>,
 		w: Self::VertexWeight,
 		e: Self::EdgeWeight,
-	) -> Result<Self::Vertex, ()>
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
-		if !self.0.graph().contains_vertex(parent.borrow())
-		{
-			return Err(());
-		}
+		GraphError::check_vertices(self, [*parent.borrow()])?;
 		let v = self.0.graph_mut().new_vertex_weighted(w)?;
 		self.0.graph_mut().add_edge_weighted(parent, v, e)?;
 		Ok(v)
@@ -209,12 +218,9 @@ where
 		to_new: bool,
 		w: Self::VertexWeight,
 		e: Self::EdgeWeight,
-	) -> Result<Self::Vertex, ()>
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
-		if !self.0.graph().contains_vertex(parent.borrow())
-		{
-			return Err(());
-		}
+		GraphError::check_vertices(self, [*parent.borrow()])?;
 		let v = self.0.graph_mut().new_vertex_weighted(w)?;
 
 		if to_new
@@ -230,13 +236,13 @@ where
 }
 
 #[duplicate_item(
-	Prop; [Tree]; [HasVertex]; [Weak]; [Acyclic]; [NoLoops]; [Unique];
+	Prop; [Tree]; [Forest]; [HasVertex]; [Weak]; [Acyclic]; [NoLoops]; [Unique];
 )]
 impl<C: Ensure> Prop for TreeGraph<C> {}
 
 impl_ensurer! {
-	use<C> TreeGraph<C>: Ensure, Tree, HasVertex, Acyclic, NoLoops, Weak, Unique, RemoveVertex,
-		NewLeafUndirected, NewLeafDirected,
+	use<C> TreeGraph<C>: Ensure, Tree, Forest, HasVertex, Acyclic, NoLoops, Weak, Unique,
+		RemoveVertex, NewLeafUndirected, NewLeafDirected,
 		// cannot add vertices without edges or add or remove edges alone
 		NewVertex, AddEdge, RemoveEdge
 	as (self.0) : C
@@ -5,7 +5,7 @@ use crate::{
 			proxy_remove_edge_where_weight, proxy_remove_vertex, HasVertexGraph, RemoveEdge,
 			RemoveVertex, Subgraph, Weak,
 		},
-		Directed, Ensure, Graph, GraphDerefMut,
+		Directed, Ensure, Graph, GraphDerefMut, GraphError, Violation,
 	},
 };
 use std::borrow::Borrow;
@@ -23,6 +23,12 @@ use std::borrow::Borrow;
 /// For undirected graph, simply use `ConnectedGraph`.
 pub trait Unilateral: Weak {}
 
+/// Ensures the underlying directed graph is unilaterally connected.
+///
+/// Removing edges or vertices searches the whole graph to check that it is
+/// still unilaterally connected, as no structure is known that keeps track of
+/// unilateral connectivity in polylogarithmic time like
+/// [ConnectedGraph](struct.ConnectedGraph.html) does for undirected graphs.
 #[derive(Clone, Debug)]
 pub struct UnilateralGraph<C: Ensure>(C)
 where
@@ -37,7 +43,12 @@ where
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		if let Ok(graph) = HasVertexGraph::ensure(c.graph(), ())
 		{
@@ -59,13 +70,16 @@ where
 				{
 					if scc2.reaches(scc1).is_none()
 					{
-						return false;
+						// Neither component can reach the other
+						let v1 = scc1.all_vertices().next().unwrap();
+						let v2 = scc2.all_vertices().next().unwrap();
+						return Err(Violation::Unreachable(v2, v1));
 					}
 				}
 				scc_current = scc_next;
 			}
 		}
-		true
+		Ok(())
 	}
 }
 
@@ -73,7 +87,10 @@ impl<C: Ensure + GraphDerefMut> RemoveVertex for UnilateralGraph<C>
 where
 	C::Graph: RemoveVertex<Directedness = Directed>,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		proxy_remove_vertex::<UnilateralGraph<_>, _>(self.0.graph_mut(), v.borrow())
 	}
@@ -88,7 +105,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
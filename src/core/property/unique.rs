@@ -1,4 +1,6 @@
-use crate::core::{property::AddEdge, Directedness, Edge, Ensure, Graph, GraphDerefMut};
+use crate::core::{
+	property::AddEdge, Directedness, Edge, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
 use std::borrow::Borrow;
 
 /// A marker trait for graphs containing only unique edges.
@@ -55,7 +57,12 @@ impl<C: Ensure> Ensure for UniqueGraph<C>
 		Self(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		let edges: Vec<_> = c.graph().all_edges().collect();
 		let mut iter = edges.iter();
@@ -68,11 +75,11 @@ impl<C: Ensure> Ensure for UniqueGraph<C>
 						&& e.sink() == e2.source()
 						&& !<C::Graph as Graph>::Directedness::directed())
 				{
-					return false;
+					return Err(Violation::DuplicateEdge(e.source(), e.sink()));
 				}
 			}
 		}
-		true
+		Ok(())
 	}
 }
 
@@ -85,11 +92,14 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if !Self::can_add_edge(self, source.borrow(), sink.borrow())
 		{
-			return Err(());
+			return Err(GraphError::rejected::<Self>(Violation::DuplicateEdge(
+				*source.borrow(),
+				*sink.borrow(),
+			)));
 		}
 		self.0.graph_mut().add_edge_weighted(source, sink, weight)
 	}
@@ -1,12 +1,16 @@
 use crate::core::{
 	property::{
-		proxy_remove_edge_where_weight, proxy_remove_vertex, ConnectedGraph, RemoveEdge,
-		RemoveVertex,
+		AddEdge, ConnectedGraph, DynamicConnectivity, NewLeafDirected, NewLeafUndirected,
+		RemoveEdge, RemoveVertex,
 	},
 	proxy::UndirectedProxy,
-	Directed, Ensure, Graph, GraphDerefMut,
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, Violation,
+};
+use std::{
+	borrow::Borrow,
+	fmt::{Debug, Error, Formatter},
+	hash::Hash,
 };
-use std::borrow::Borrow;
 
 /// A marker trait for graphs that are weakly connected.
 ///
@@ -19,8 +23,15 @@ use std::borrow::Borrow;
 /// undirected graph, simply use `ConnectedGraph`.
 pub trait Weak: Graph {}
 
-#[derive(Clone, Debug)]
-pub struct WeakGraph<C: Ensure>(C)
+/// Ensures the underlying directed graph is weakly connected.
+///
+/// Like undirected [ConnectedGraph](struct.ConnectedGraph.html), which
+/// vertices are connected is kept track of as edges are added and removed,
+/// which requires the vertices to be hashable.
+/// See [ConnectedMutation](trait.ConnectedMutation.html) for the time this
+/// takes.
+#[derive(Clone)]
+pub struct WeakGraph<C: Ensure>(C, DynamicConnectivity<<C::Graph as Graph>::Vertex>)
 where
 	C::Graph: Graph<Directedness = Directed>;
 
@@ -33,7 +44,17 @@ where
 	/// This method does not check for this!!
 	pub fn new(c: C) -> Self
 	{
-		Self(c)
+		Self(c, DynamicConnectivity::new())
+	}
+}
+
+impl<C: Ensure + Debug> Debug for WeakGraph<C>
+where
+	C::Graph: Graph<Directedness = Directed>,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>
+	{
+		f.debug_tuple("WeakGraph").field(&self.0).finish()
 	}
 }
 
@@ -43,53 +64,125 @@ where
 {
 	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
 	{
-		Self(c)
+		Self::new(c)
 	}
 
-	fn can_ensure(c: &Self::Ensured, _: &()) -> bool
+	fn can_ensure(c: &Self::Ensured, p: &()) -> bool
+	{
+		Self::validate(c, p).is_ok()
+	}
+
+	fn validate(c: &Self::Ensured, _: &()) -> Result<(), Violation<<C::Graph as Graph>::Vertex>>
 	{
 		let undirected = UndirectedProxy::new(c.graph());
 
-		ConnectedGraph::can_ensure(&undirected, &())
+		ConnectedGraph::validate(&undirected, &())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for WeakGraph<C>
+where
+	C::Graph: AddEdge<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		self.1
+			.add_edge_weighted(self.0.graph_mut(), *source.borrow(), *sink.borrow(), weight)
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for WeakGraph<C>
 where
 	C::Graph: RemoveVertex<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
-		proxy_remove_vertex::<WeakGraph<_>, _>(self.0.graph_mut(), v.borrow())
+		self.1
+			.remove_vertex::<Self, _>(self.0.graph_mut(), *v.borrow())
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveEdge for WeakGraph<C>
 where
 	C::Graph: RemoveEdge<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
-		proxy_remove_edge_where_weight::<WeakGraph<_>, _, _>(
+		self.1.remove_edge_where_weight::<Self, _, _>(
 			self.0.graph_mut(),
-			source.borrow(),
-			sink.borrow(),
+			*source.borrow(),
+			*sink.borrow(),
 			f,
 		)
 	}
 }
 
+impl<C: Ensure + GraphDerefMut> NewLeafUndirected for WeakGraph<C>
+where
+	C::Graph: NewLeafUndirected<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let leaf = self
+			.0
+			.graph_mut()
+			.new_leaf_weighted(parent.borrow(), w, e)?;
+		self.1.add_leaf(*parent.borrow(), leaf);
+		Ok(leaf)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewLeafDirected for WeakGraph<C>
+where
+	C::Graph: NewLeafDirected,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_leaf_weighted(
+		&mut self,
+		parent: impl Borrow<Self::Vertex>,
+		to_new: bool,
+		w: Self::VertexWeight,
+		e: Self::EdgeWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let leaf = self
+			.0
+			.graph_mut()
+			.new_leaf_weighted(parent.borrow(), to_new, w, e)?;
+		self.1.add_leaf(*parent.borrow(), leaf);
+		Ok(leaf)
+	}
+}
+
 impl<C: Ensure> Weak for WeakGraph<C> where C::Graph: Graph<Directedness = Directed> {}
 
 impl_ensurer! {
-	use<C> WeakGraph<C>: Ensure, Weak, RemoveVertex, RemoveEdge, Simple,
+	use<C> WeakGraph<C>: Ensure, Weak, AddEdge, RemoveVertex, RemoveEdge, NewLeafUndirected,
+	NewLeafDirected, Simple,
 	// A new vertex wouldn't be connected to the rest of the graph
 	NewVertex
 	as (self.0) : C
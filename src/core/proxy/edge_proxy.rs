@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, RemoveEdge, RemoveVertex},
-	Directedness, Edge, Ensure, Graph, GraphDerefMut, GraphMut,
+	Directedness, Edge, Ensure, Graph, GraphDerefMut, GraphError, GraphMut,
 };
 use delegate::delegate;
 use std::{borrow::Borrow, cell::UnsafeCell};
@@ -124,18 +124,12 @@ impl<C: Ensure> AddEdge for EdgeProxyGraph<C>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		_: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
-		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
-		{
-			self.new
-				.push((source.borrow().clone(), sink.borrow().clone()));
-			Ok(())
-		}
-		else
-		{
-			Err(())
-		}
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		self.new
+			.push((source.borrow().clone(), sink.borrow().clone()));
+		Ok(())
 	}
 }
 
@@ -146,7 +140,7 @@ impl<C: Ensure> RemoveEdge for EdgeProxyGraph<C>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		_: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -177,7 +171,8 @@ impl<C: Ensure> RemoveEdge for EdgeProxyGraph<C>
 			}
 			else
 			{
-				Err(())
+				GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+				Err(GraphError::MissingEdge(*source.borrow(), *sink.borrow()))
 			}
 		}
 	}
@@ -187,7 +182,10 @@ impl<C: Ensure + GraphDerefMut> RemoveVertex for EdgeProxyGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		self.new
 			.retain(|e| e.source() != *v.borrow() && e.sink() != *v.borrow());
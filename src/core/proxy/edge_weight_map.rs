@@ -1,5 +1,6 @@
-use crate::core::{Ensure, Graph, Owned, ReleasePayload};
+use crate::core::{property::NonNegativeWeights, Ensure, Graph, Owned, ReleasePayload};
 use delegate::delegate;
+use num_traits::Unsigned;
 use std::borrow::Borrow;
 
 /// Wraps a graph, mapping its edge weights to `Ew`.
@@ -9,7 +10,8 @@ use std::borrow::Borrow;
 /// struct, whatever edge weights a graph has can be mapped to integers and
 /// provided to
 /// [DijkstraShortestPaths](../../algo/struct.DijkstraShortestPaths.html), which
-/// requires unsigned integers as weights.
+/// requires [non-negative weights](../property/trait.NonNegativeWeights.html).
+/// Mapping to unsigned integers gives that property automatically.
 #[derive(Clone, Debug)]
 pub struct EdgeWeightMap<C: Ensure, Ew>(
 	C,
@@ -93,8 +95,11 @@ impl<C: Ensure, Ew> Graph for EdgeWeightMap<C, Ew>
 	}
 }
 
+impl<C: Ensure, Ew: Unsigned> NonNegativeWeights for EdgeWeightMap<C, Ew> {}
+
 impl_ensurer! {
-	use<C,Ew> EdgeWeightMap<C, Ew>: Ensure, Graph, GraphMut, Reflexive, AddEdge, RemoveEdge, Simple, NewLeafUndirected, NewLeafDirected
+	use<C,Ew> EdgeWeightMap<C, Ew>: Ensure, Graph, GraphMut, Reflexive, AddEdge, RemoveEdge, Simple, NewLeafUndirected, NewLeafDirected, EdgeIndexed,
+	NonNegativeWeights
 	as (self.0) : C
 	as (self.1) : fn(
 		<C::Graph as Graph>::Vertex,
@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, RemoveEdge},
-	Directed, Ensure, Graph, GraphDerefMut, GraphMut,
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, GraphMut,
 };
 use delegate::delegate;
 use std::borrow::Borrow;
@@ -38,6 +38,14 @@ where
 		to self.0.graph() {
 			fn all_vertices_weighted(&self) ->
 				impl Iterator<Item = (Self::Vertex, &Self::VertexWeight)>;
+			fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>;
+			fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool;
+			fn edges_incident_on(&self, v: impl Borrow<Self::Vertex>)
+				-> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>;
+			fn vertex_neighbors(&self, v: impl Borrow<Self::Vertex>)
+				-> impl Iterator<Item = Self::Vertex>;
+			fn neighbors(&self, v1: impl Borrow<Self::Vertex>, v2: impl Borrow<Self::Vertex>)
+				-> bool;
 		}
 	}
 
@@ -49,6 +57,32 @@ where
 	{
 		self.0.graph().edges_between(sink, source)
 	}
+
+	fn all_edges(
+		&self,
+	) -> impl Iterator<Item = (Self::Vertex, Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.0
+			.graph()
+			.all_edges()
+			.map(|(source, sink, w)| (sink, source, w))
+	}
+
+	fn edges_sourced_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.0.graph().edges_sinked_in(v)
+	}
+
+	fn edges_sinked_in(
+		&self,
+		v: impl Borrow<Self::Vertex>,
+	) -> impl Iterator<Item = (Self::Vertex, Self::EdgeWeightRef<'_>)>
+	{
+		self.0.graph().edges_sourced_in(v)
+	}
 }
 
 impl<C: Ensure + GraphDerefMut> GraphMut for ReverseGraph<C>
@@ -81,7 +115,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		self.0.graph_mut().add_edge_weighted(sink, source, weight)
 	}
@@ -96,7 +130,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
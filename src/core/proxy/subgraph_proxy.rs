@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex, Subgraph},
-	Edge, Ensure, Graph, GraphDerefMut, GraphMut,
+	Edge, Ensure, Graph, GraphDerefMut, GraphError, GraphMut,
 };
 use std::borrow::Borrow;
 
@@ -136,18 +136,12 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
-		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
-		{
-			self.graph
-				.graph_mut()
-				.add_edge_weighted(source, sink, weight)
-		}
-		else
-		{
-			Err(())
-		}
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		self.graph
+			.graph_mut()
+			.add_edge_weighted(source, sink, weight)
 	}
 }
 
@@ -160,20 +154,14 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
-		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
-		{
-			self.graph
-				.graph_mut()
-				.remove_edge_where_weight(source, sink, f)
-		}
-		else
-		{
-			Err(())
-		}
+		GraphError::check_vertices(self, [*source.borrow(), *sink.borrow()])?;
+		self.graph
+			.graph_mut()
+			.remove_edge_where_weight(source, sink, f)
 	}
 }
 
@@ -181,7 +169,10 @@ impl<C: Ensure + GraphDerefMut> NewVertex for SubgraphProxy<C>
 where
 	C::Graph: NewVertex,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let v = self.graph.graph_mut().new_vertex_weighted(w)?;
 		self.verts.push(v);
@@ -193,7 +184,10 @@ impl<C: Ensure + GraphDerefMut> RemoveVertex for SubgraphProxy<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.contains_vertex(v.borrow())
 		{
@@ -208,7 +202,7 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingVertex(*v.borrow()))
 		}
 	}
 }
@@ -1,10 +1,10 @@
 use crate::core::{
 	property::{NewVertex, RemoveVertex},
-	Ensure, Graph,
+	Ensure, Graph, GraphError,
 };
 use std::borrow::Borrow;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ProxyVertex<V: Copy + Eq>
 {
 	Underlying(V),
@@ -86,7 +86,10 @@ impl<C: Ensure> Graph for VertexProxyGraph<C>
 
 impl<C: Ensure> NewVertex for VertexProxyGraph<C>
 {
-	fn new_vertex_weighted(&mut self, _: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		_: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let new_id = self.new_count;
 		self.new_count += 1;
@@ -96,7 +99,10 @@ impl<C: Ensure> NewVertex for VertexProxyGraph<C>
 
 impl<C: Ensure> RemoveVertex for VertexProxyGraph<C>
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.contains_vertex(v.borrow())
 		{
@@ -116,7 +122,7 @@ impl<C: Ensure> RemoveVertex for VertexProxyGraph<C>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingVertex(*v.borrow()))
 		}
 	}
 }
@@ -232,3 +232,14 @@
 pub mod core;
 pub mod algo;
 pub mod common;
+
+/// Dependencies used by the exported macros, so crates using them don't need
+/// to depend on them too.
+#[doc(hidden)]
+pub mod __private
+{
+	pub use delegate;
+	pub use num_traits;
+	pub use tt_call;
+	pub use tt_equal;
+}
@@ -3,7 +3,7 @@ use duplicate::duplicate_item;
 use graphene::{
 	algo::{Bfs, Retainable, UnretainedIterator},
 	core::{
-		property::{ConnectedGraph, Rooted, VertexCount, VertexIn, VertexInGraph},
+		property::{ConnectedGraph, Rooted, RootedTree, VertexCount, VertexIn, VertexInGraph},
 		Directed, Graph, Undirected,
 	},
 };
@@ -186,6 +186,14 @@ mod __
 			}
 		}
 
+		// Tests the parents in the tree are the predecessors
+		for v in tree.all_vertices()
+		{
+			if tree.parent(v) != bfs.predecessor(v)
+			{
+				return false;
+			}
+		}
 		true
 	}
 }
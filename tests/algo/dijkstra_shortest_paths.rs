@@ -8,9 +8,9 @@ use duplicate::duplicate_item;
 use graphene::{
 	algo::{DijkstraShortestPaths, Retainable},
 	core::{
-		property::{AddEdge, ConnectedGraph, VertexIn, VertexInGraph},
+		property::{AddEdge, ConnectedGraph, NonNegativeWeightsGraph, VertexIn, VertexInGraph},
 		proxy::EdgeWeightMap,
-		Directed, Ensure, Graph, GraphDeref, ReleasePayload, Undirected,
+		Directed, Ensure, Graph, GraphDeref, Guard, ReleasePayload, Undirected,
 	},
 };
 use std::collections::{HashMap, HashSet};
@@ -88,6 +88,24 @@ mod __
 		true
 	}
 
+	/// Tests that floating point weights give the same distances as unsigned
+	/// ones when they are guaranteed non-negative.
+	#[quickcheck]
+	fn float_weights(Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let unsigned = EdgeWeightMap::new(g.graph(), |_, _, w| w.value as u64);
+		let unsigned = VertexInGraph::ensure_unchecked(&unsigned, [g.vertex_at::<0>()]);
+		let float = EdgeWeightMap::new(g.graph(), |_, _, w| w.value as f64);
+		let float = VertexInGraph::ensure_unchecked(
+			NonNegativeWeightsGraph::guard(float).unwrap(),
+			[g.vertex_at::<0>()],
+		);
+
+		let expected: HashMap<_, _> = DijkstraShortestPaths::distances(&unsigned).collect();
+		let distances: HashMap<_, _> = DijkstraShortestPaths::distances(&float).collect();
+		expected.len() == distances.len() && distances.iter().all(|(v, d)| expected[v] as f64 == *d)
+	}
+
 	/// Next path must be sourced in a previously produced vertex
 	#[quickcheck]
 	fn path_source_already_seen(Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
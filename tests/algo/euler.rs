@@ -0,0 +1,156 @@
+//! Tests `euler_circuit` and `euler_trail`
+
+use crate::{
+	common::adj_list_from_mock,
+	mock_graph::{arbitrary::Arb, MockGraph},
+};
+use duplicate::duplicate_item;
+use graphene::{
+	algo::{euler_circuit, euler_trail},
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, EdgeIndexed, EulerianGraph, NewVertex, RemoveEdgeIndexed, SemiEulerianGraph,
+		},
+		Directed, Directedness, Guard, Undirected,
+	},
+};
+use std::collections::HashSet;
+
+/// Returns whether the given walk uses every edge of the graph exactly once,
+/// with each edge between the vertices before and after it.
+fn valid_walk<G: EdgeIndexed>(g: &G, (vertices, edges): &(Vec<G::Vertex>, Vec<G::EdgeId>)) -> bool
+where
+	G::EdgeId: std::hash::Hash,
+{
+	let unique: HashSet<_> = edges.iter().collect();
+	edges.len() == g.all_edge_ids().count()
+		&& unique.len() == edges.len()
+		&& vertices.len() == edges.len() + (!edges.is_empty()) as usize
+		&& edges.iter().enumerate().all(|(i, e)| {
+			let (so, si) = g.edge_endpoints(e).unwrap();
+			(so, si) == (vertices[i], vertices[i + 1])
+				|| (!G::Directedness::directed() && (si, so) == (vertices[i], vertices[i + 1]))
+		})
+}
+
+/// Returns a graph with the given number of vertices and the edges of the
+/// closed walk through the given vertex indices.
+fn closed_walk<D: Directedness>(vertex_count: u8, walk: Vec<u8>) -> AdjListGraph<(), (), D>
+{
+	let mut g = AdjListGraph::new();
+	let vertices: Vec<_> = (0..=vertex_count)
+		.map(|_| g.new_vertex().unwrap())
+		.collect();
+	let walk: Vec<_> = walk
+		.into_iter()
+		.map(|idx| vertices[idx as usize % vertices.len()])
+		.collect();
+	for (i, v) in walk.iter().enumerate()
+	{
+		g.add_edge(v, walk[(i + 1) % walk.len()]).unwrap();
+	}
+	g
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that a circuit is found exactly for Eulerian graphs and that it
+	/// is valid and closed.
+	#[quickcheck]
+	fn circuit(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = adj_list_from_mock(&mock);
+		match euler_circuit(&g)
+		{
+			Some(walk) =>
+			{
+				EulerianGraph::can_guard(&g)
+					&& valid_walk(&g, &walk)
+					&& walk.0.first() == walk.0.last()
+			},
+			None => !EulerianGraph::can_guard(&g),
+		}
+	}
+
+	/// Tests that a trail is found exactly for semi-Eulerian graphs and that
+	/// it is valid.
+	#[quickcheck]
+	fn trail(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = adj_list_from_mock(&mock);
+		match euler_trail(&g)
+		{
+			Some(walk) => SemiEulerianGraph::can_guard(&g) && valid_walk(&g, &walk),
+			None => !SemiEulerianGraph::can_guard(&g),
+		}
+	}
+
+	/// Tests that a circuit is found for graphs made from a closed walk.
+	#[quickcheck]
+	fn circuit_of_walk(vertex_count: u8, walk: Vec<u8>) -> bool
+	{
+		let g = closed_walk::<directedness>(vertex_count, walk);
+		euler_circuit(&g)
+			.is_some_and(|walk| valid_walk(&g, &walk) && walk.0.first() == walk.0.last())
+	}
+
+	/// Tests that a trail is found for graphs made from a closed walk missing
+	/// an edge.
+	#[quickcheck]
+	fn trail_of_walk(vertex_count: u8, walk: Vec<u8>) -> bool
+	{
+		let mut g = closed_walk::<directedness>(vertex_count, walk);
+		let first = g.all_edge_ids().next();
+		if let Some(id) = first
+		{
+			g.remove_edge_by_id(id).unwrap();
+		}
+		euler_trail(&g).is_some_and(|walk| valid_walk(&g, &walk))
+	}
+}
+
+/// Tests small graphs with and without circuits and trails.
+#[test]
+fn small_graphs()
+{
+	// A path has a trail from one end to the other, but no circuit
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let vs: Vec<_> = (0..3).map(|_| g.new_vertex().unwrap()).collect();
+	g.add_edge(vs[1], vs[2]).unwrap();
+	g.add_edge(vs[0], vs[1]).unwrap();
+	assert!(euler_circuit(&g).is_none());
+	assert_eq!(euler_trail(&g).unwrap().0, vs);
+
+	// Closing the path gives a circuit
+	g.add_edge(vs[2], vs[0]).unwrap();
+	assert!(euler_circuit(&g).is_some());
+
+	// Two disjoint cycles have neither
+	let w: Vec<_> = (0..2).map(|_| g.new_vertex().unwrap()).collect();
+	g.add_edge(w[0], w[1]).unwrap();
+	g.add_edge(w[1], w[0]).unwrap();
+	assert!(euler_circuit(&g).is_none());
+	assert!(euler_trail(&g).is_none());
+
+	// The complete graph with 4 vertices has 4 vertices of odd degree
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let vs: Vec<_> = (0..4).map(|_| g.new_vertex().unwrap()).collect();
+	for i in 0..4
+	{
+		for j in (i + 1)..4
+		{
+			g.add_edge(vs[i], vs[j]).unwrap();
+		}
+	}
+	assert!(euler_trail(&g).is_none());
+
+	// A graph without edges has an empty circuit
+	let g = AdjListGraph::<(), (), Undirected>::new();
+	assert_eq!(euler_circuit(&g), Some((Vec::new(), Vec::new())));
+}
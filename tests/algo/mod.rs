@@ -1,5 +1,6 @@
 mod bfs;
 mod dfs;
 mod dijkstra_shortest_paths;
+mod euler;
 mod search;
 mod tarjan_scc;
@@ -0,0 +1,130 @@
+//! Tests the `Graph` implementation of `CsrGraph`
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockEdgeWeight, MockGraph,
+	MockVertex, MockVertexWeight,
+};
+use duplicate::duplicate_item;
+use graphene::{
+	common::CsrGraph,
+	core::{
+		property::{EdgeCount, VertexCount, VertexIn, VertexInGraph},
+		Directed, Graph, GraphMut, Release, Undirected,
+	},
+};
+use std::collections::HashMap;
+
+fn csr_from_mock<G>(
+	mock: &G,
+) -> (
+	CsrGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let map = mock
+		.all_vertices()
+		.enumerate()
+		.map(|(i, v)| (v, i))
+		.collect();
+	(CsrGraph::from_graph(mock), map)
+}
+
+#[duplicate_item(
+	directedness; [Directed]; [Undirected];
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the graph has the same vertices and vertex weights as the
+	/// original.
+	#[quickcheck]
+	fn same_vertices(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, v_map) = csr_from_mock(&mock);
+
+		g.vertex_count() == mock.all_vertices().count()
+			&& unordered_equivalent_lists_equal(
+				&mock
+					.all_vertices()
+					.map(|v| (v_map[&v], mock.vertex_weight(v)))
+					.collect(),
+				&g.all_vertices().map(|v| (v, g.vertex_weight(v))).collect(),
+			)
+	}
+
+	/// Tests that the graph has the same edges as the original.
+	#[quickcheck]
+	fn same_edges(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, v_map) = csr_from_mock(&mock);
+
+		g.edge_count() == mock.all_edges().count()
+			&& unordered_equivalent_lists_equal(
+				&mock
+					.all_edges()
+					.map(|(so, si, w)| (v_map[&so], v_map[&si], w))
+					.collect(),
+				&g.all_edges().collect(),
+			)
+	}
+
+	/// Tests that `edges_between` returns the same edges as the original.
+	#[quickcheck]
+	fn edges_between(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>, 2, false>>) -> bool
+	{
+		let v1 = mock.vertex_at::<0>();
+		let v2 = mock.vertex_at::<1>();
+		let mock = mock.0.release_all();
+		let (g, v_map) = csr_from_mock(&mock);
+
+		unordered_equivalent_lists_equal(
+			&mock.edges_between(&v1, &v2).collect(),
+			&g.edges_between(&v_map[&v1], &v_map[&v2]).collect(),
+		)
+	}
+
+	/// Tests that `edges_between_mut` returns the same edges as its
+	/// immutable version
+	#[quickcheck]
+	fn edges_between_mut(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>, 2, false>>) -> bool
+	{
+		let v1 = mock.vertex_at::<0>();
+		let v2 = mock.vertex_at::<1>();
+		let mock = mock.0.release_all();
+		let (mut g, v_map) = csr_from_mock(&mock);
+
+		unordered_equivalent_lists_equal(
+			&g.edges_between(&v_map[&v1], &v_map[&v2]).cloned().collect(),
+			&g.edges_between_mut(&v_map[&v1], &v_map[&v2])
+				.map(|w| w.clone())
+				.collect(),
+		)
+	}
+
+	/// Tests that `edges_sourced_in` and `edges_sinked_in` return the same
+	/// edges as the original.
+	#[quickcheck]
+	fn edges_sourced_sinked_in(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = mock.vertex_at::<0>();
+		let mock = mock.release_all();
+		let (g, v_map) = csr_from_mock(&mock);
+
+		unordered_equivalent_lists_equal(
+			&mock
+				.edges_sourced_in(&v)
+				.map(|(si, w)| (v_map[&si], w))
+				.collect(),
+			&g.edges_sourced_in(&v_map[&v]).collect(),
+		) && unordered_equivalent_lists_equal(
+			&mock
+				.edges_sinked_in(&v)
+				.map(|(so, w)| (v_map[&so], w))
+				.collect(),
+			&g.edges_sinked_in(&v_map[&v]).collect(),
+		)
+	}
+}
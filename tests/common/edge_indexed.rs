@@ -0,0 +1,167 @@
+//! Tests the `EdgeIndexed`, `AddEdgeIndexed` and `RemoveEdgeIndexed`
+//! implementations of `AdjListGraph`
+
+use crate::{
+	common::adj_list_from_mock,
+	mock_graph::{
+		arbitrary::{Arb, EdgeIn},
+		utilities::unordered_equivalent_lists_equal,
+		MockGraph,
+	},
+};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, AddEdgeIndexed, EdgeCount, EdgeIndexed, HasVertexGraph, NewVertex,
+			RemoveEdgeIndexed, RemoveVertex, VertexIn,
+		},
+		Directed, Directedness, Ensure, Graph, GraphError, Undirected,
+	},
+};
+
+#[duplicate_item(
+	directedness; [Directed]; [Undirected];
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the edges given by `all_edge_ids` are the edges of the
+	/// graph.
+	#[quickcheck]
+	fn all_edge_ids(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = adj_list_from_mock(&mock);
+		// Undirected edges may be returned with their vertices in either order
+		let ordered = |(so, si, w): (usize, usize, _)| {
+			if directedness::directed() || so <= si
+			{
+				(so, si, w)
+			}
+			else
+			{
+				(si, so, w)
+			}
+		};
+
+		unordered_equivalent_lists_equal(
+			&g.all_edges().map(ordered).collect(),
+			&g.all_edge_ids()
+				.map(|id| {
+					let (so, si) = g.edge_endpoints(id).unwrap();
+					ordered((so, si, g.edge_weight(id).unwrap()))
+				})
+				.collect(),
+		)
+	}
+
+	/// Tests that `edge_ids_between` gives the ids of the edges between the
+	/// vertices.
+	#[quickcheck]
+	fn edge_ids_between(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let (g, v_map) = adj_list_from_mock(&mock);
+		let source = v_map[&mock.vertex_at::<0>()];
+		let sink = v_map[&mock.1];
+
+		unordered_equivalent_lists_equal(
+			&g.edges_between(source, sink).collect(),
+			&g.edge_ids_between(source, sink)
+				.map(|id| g.edge_weight(id).unwrap())
+				.collect(),
+		)
+	}
+
+	/// Tests that an edge can be removed by its id.
+	#[quickcheck]
+	fn remove_edge_by_id(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+		let source = mock.vertex_at::<0>();
+		let EdgeIn(mock, sink, _) = mock;
+		let id = g
+			.edge_ids_between(v_map[&source], v_map[&sink])
+			.next()
+			.unwrap();
+		let weight = g.edge_weight(id).unwrap().clone();
+
+		g.remove_edge_by_id(id) == Ok(weight)
+			&& g.edge_count() == mock.edge_count() - 1
+			&& g.edges_between(v_map[&source], v_map[&sink]).count()
+				== mock.edges_between(source, sink).count() - 1
+	}
+}
+
+/// Tests that parallel edges with identical weights can be told apart.
+#[test]
+fn parallel_edges()
+{
+	let mut g = AdjListGraph::<(), u32, Undirected>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge_weighted(v1, v2, 1).unwrap();
+	let id = g.add_edge_weighted_indexed(v2, v1, 1).unwrap();
+	let id2 = g.add_edge_weighted_indexed(v1, v2, 1).unwrap();
+
+	assert_eq!(g.edge_endpoints(id), Some((v2, v1)));
+	assert_eq!(g.edge_ids_between(v1, v2).count(), 3);
+	assert_ne!(id, id2);
+
+	assert_eq!(g.remove_edge_by_id(id), Ok(1));
+	assert_eq!(g.remove_edge_by_id(id), Err(GraphError::MissingEdgeId));
+	assert_eq!(g.edges_between(v1, v2).count(), 2);
+	assert!(g
+		.edge_ids_between(v2, v1)
+		.all(|id| g.edge_endpoints(id) == Some((v1, v2))));
+}
+
+/// Tests that ids stay valid when other edges or vertices are removed, and
+/// that the id of a removed edge isn't given to a later edge.
+#[test]
+fn stable_ids()
+{
+	let mut g = AdjListGraph::<(), u32, Directed>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	let id01 = g.add_edge_weighted_indexed(v0, v1, 1).unwrap();
+	let id12 = g.add_edge_weighted_indexed(v1, v2, 2).unwrap();
+	let id12b = g.add_edge_weighted_indexed(v1, v2, 3).unwrap();
+	let id02 = g.add_edge_weighted_indexed(v0, v2, 4).unwrap();
+
+	assert_eq!(g.remove_edge_by_id(id12), Ok(2));
+	assert_eq!(g.edge_weight(id12b), Some(&3));
+	assert_eq!(g.edge_endpoints(id12b), Some((v1, v2)));
+
+	// Reuses the freed slot without reviving the removed id
+	let id20 = g.add_edge_weighted_indexed(v2, v0, 5).unwrap();
+	assert_ne!(id20, id12);
+	assert_eq!(g.edge_weight(id12), None);
+	assert_eq!(g.remove_edge_by_id(id12), Err(GraphError::MissingEdgeId));
+
+	// Removing a vertex shifts the later vertices' indices
+	g.remove_vertex(v0).unwrap();
+	assert_eq!(g.edge_weight(id01), None);
+	assert_eq!(g.edge_weight(id02), None);
+	assert_eq!(g.edge_weight(id20), None);
+	assert_eq!(g.edge_endpoints(id12b), Some((v0, v1)));
+	assert!(g.all_edge_ids().eq([id12b]));
+	assert_eq!(g.remove_edge_by_id(id12b), Ok(3));
+	assert_eq!(g.edge_count(), 0);
+}
+
+/// Tests that ensured graphs give the ids of the underlying graph.
+#[test]
+fn ensured_edge_ids()
+{
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	let id = g.add_edge_indexed(v1, v2).unwrap();
+	let g = HasVertexGraph::ensure(g, ()).unwrap();
+
+	assert!(g.all_edge_ids().eq([id]));
+	assert_eq!(g.edge_endpoints(id), Some((v1, v2)));
+}
@@ -0,0 +1,133 @@
+//! Tests `GridGraph`
+
+use graphene::{
+	common::{Connectivity, GridGraph},
+	core::{
+		property::{EdgeCount, VertexCount},
+		Graph, GraphError, GraphMut,
+	},
+};
+
+/// Constructs a grid of at most 5x5 from the given values.
+fn grid_from(
+	width: u8,
+	height: u8,
+	full: bool,
+	wrap: bool,
+	blocked: Vec<(u8, u8)>,
+) -> GridGraph<usize>
+{
+	let connectivity = if full
+	{
+		Connectivity::Full
+	}
+	else
+	{
+		Connectivity::Orthogonal
+	};
+	let mut grid = GridGraph::from_fn(
+		[width as usize % 6, height as usize % 6],
+		connectivity,
+		|[x, y]| x * 10 + y,
+	);
+	grid.set_wrap(wrap);
+	for (x, y) in blocked
+	{
+		grid.block([x as usize % 6, y as usize % 6]);
+	}
+	grid
+}
+
+/// Tests that every edge is found from both its ends, and that `all_edges`
+/// returns each edge once.
+#[quickcheck]
+fn edges_consistent(width: u8, height: u8, full: bool, wrap: bool, blocked: Vec<(u8, u8)>) -> bool
+{
+	let grid = grid_from(width, height, full, wrap, blocked);
+
+	let degree_sum: usize = grid
+		.all_vertices()
+		.map(|v| grid.edges_sourced_in(v).count())
+		.sum();
+
+	grid.all_vertices().all(|v| {
+		grid.edges_sourced_in(v).all(|(n, _)| {
+			!grid.is_blocked(n)
+				&& n != v && grid.edges_between(n, v).count() == 1
+				&& grid.edges_between(v, n).count() == 1
+		})
+	}) && degree_sum == grid.edge_count() * 2
+}
+
+/// Tests that the vertices are the unblocked cells, with the right weights.
+#[quickcheck]
+fn vertices(width: u8, height: u8, blocked: Vec<(u8, u8)>) -> bool
+{
+	let mut grid = grid_from(width, height, false, false, blocked);
+	let [w, h] = grid.dimensions();
+
+	let expected: Vec<_> = (0..w)
+		.flat_map(|x| (0..h).map(move |y| [x, y]))
+		.filter(|v| !grid.is_blocked(v))
+		.collect();
+
+	grid.vertex_count() == expected.len()
+		&& grid.all_vertices().eq(expected.iter().cloned())
+		&& grid
+			.all_vertices_weighted()
+			.all(|([x, y], w)| *w == x * 10 + y)
+		&& grid
+			.all_vertices_weighted_mut()
+			.all(|([x, y], w)| *w == x * 10 + y)
+}
+
+#[test]
+fn edge_counts()
+{
+	let mut grid = GridGraph::<()>::new([3, 4], Connectivity::Orthogonal);
+	assert_eq!(grid.edge_count(), 17);
+	grid.set_wrap(true);
+	assert_eq!(grid.edge_count(), 24);
+
+	let mut grid = GridGraph::<()>::new([3, 4], Connectivity::Full);
+	assert_eq!(grid.edge_count(), 17 + 12);
+	grid.set_wrap(true);
+	assert_eq!(grid.edge_count(), 48);
+
+	// A 2-wide torus doesn't get double edges
+	let mut grid = GridGraph::<()>::new([2, 1], Connectivity::Orthogonal);
+	grid.set_wrap(true);
+	assert_eq!(grid.edge_count(), 1);
+
+	let grid = GridGraph::<(), 3>::new([2, 2, 2], Connectivity::Orthogonal);
+	assert_eq!(grid.vertex_count(), 8);
+	assert_eq!(grid.edge_count(), 12);
+}
+
+#[test]
+fn block_unblock()
+{
+	let mut grid = GridGraph::<usize>::from_fn([3, 3], Connectivity::Full, |[x, y]| x + y);
+
+	assert_eq!(grid.block([1, 1]), Some(2));
+	assert_eq!(grid.block([1, 1]), None);
+	assert_eq!(grid.block([3, 1]), None);
+	assert!(grid.is_blocked([1, 1]));
+	assert_eq!(grid.vertex_weight([1, 1]), None);
+	assert_eq!(grid.edges_between([0, 0], [1, 1]).count(), 0);
+	assert_eq!(grid.edge_count(), 12);
+
+	assert_eq!(grid.unblock([1, 1], 5), Ok(()));
+	assert_eq!(
+		grid.unblock([1, 1], 5),
+		Err(GraphError::DuplicateVertex([1, 1]))
+	);
+	assert_eq!(
+		grid.unblock([3, 1], 5),
+		Err(GraphError::MissingVertex([3, 1]))
+	);
+	assert_eq!(grid.vertex_weight([1, 1]), Some(&5));
+	assert_eq!(grid.edge_count(), 20);
+	assert_eq!(grid.edges_between_mut([0, 0], [1, 1]).count(), 1);
+	assert_eq!(grid.edges_between_mut([0, 0], [2, 2]).count(), 0);
+}
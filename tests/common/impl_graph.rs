@@ -1,7 +1,12 @@
-//! Tests the `Graph` and `AutoGraph` implementations of `AdjListGraph`
+//! Tests the `Graph` and `AutoGraph` implementations of `AdjListGraph`,
+//! `AdjMatrixGraph`, `BiAdjListGraph`, `StableAdjListGraph`,
+//! `PersistentGraph` and `VertexMapGraph`
 
 use crate::{
-	common::{adj_list_from_mock, vertex_map_from_mock},
+	common::{
+		adj_list_from_mock, adj_matrix_from_mock, bi_adj_list_from_mock, persistent_from_mock,
+		stable_adj_list_from_mock, stable_vertex_map_from_mock, vertex_map_from_mock,
+	},
 	mock_graph::{
 		arbitrary::{Arb, EdgeIn},
 		utilities::unordered_equivalent_lists_equal,
@@ -11,7 +16,7 @@ use crate::{
 use duplicate::duplicate_item;
 use graphene::core::{
 	property::{EdgeCount, RemoveEdge, RemoveVertex, VertexCount, VertexIn, VertexInGraph},
-	Directed, Graph, GraphMut, Release, Undirected,
+	Directed, Graph, GraphError, GraphMut, Release, Undirected,
 };
 
 #[duplicate_item(
@@ -24,6 +29,10 @@ mod __
 	#[duplicate_item(
 		graph_impl 			from_mock;
 		[AdjListGraph] 		[adj_list_from_mock];
+		[AdjMatrixGraph] 	[adj_matrix_from_mock];
+		[BiAdjListGraph] 	[bi_adj_list_from_mock];
+		[StableAdjListGraph] [stable_adj_list_from_mock];
+		[PersistentGraph] 	[persistent_from_mock];
 		[VertexMapGraph] 	[vertex_map_from_mock];
 	)]
 	mod __
@@ -115,6 +124,40 @@ mod __
 			)
 		}
 
+		/// Tests that the edges sourced in, sinked in and incident on a vertex,
+		/// and its neighbors, are the same as in the original graph.
+		#[quickcheck]
+		fn neighborhood(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+		{
+			let v = mock.vertex_at::<0>();
+			let mock = mock.release_all();
+			let (g, v_map) = from_mock(&mock);
+			let mapped = v_map[&v];
+
+			unordered_equivalent_lists_equal(
+				&mock
+					.edges_sourced_in(v)
+					.map(|(v2, w)| (v_map[&v2], w))
+					.collect(),
+				&g.edges_sourced_in(mapped).collect(),
+			) && unordered_equivalent_lists_equal(
+				&mock
+					.edges_sinked_in(v)
+					.map(|(v2, w)| (v_map[&v2], w))
+					.collect(),
+				&g.edges_sinked_in(mapped).collect(),
+			) && unordered_equivalent_lists_equal(
+				&mock
+					.edges_incident_on(v)
+					.map(|(v2, w)| (v_map[&v2], w))
+					.collect(),
+				&g.edges_incident_on(mapped).collect(),
+			) && unordered_equivalent_lists_equal(
+				&mock.vertex_neighbors(v).map(|v2| v_map[&v2]).collect(),
+				&g.vertex_neighbors(mapped).collect(),
+			)
+		}
+
 		/// Tests removing an edge
 		#[quickcheck]
 		fn remove_edge(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
@@ -142,15 +185,39 @@ mod __
 				false
 			}
 		}
+
+		/// Tests that removing an edge no weight matches reports the missing
+		/// edge
+		#[quickcheck]
+		fn remove_missing_edge(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+		{
+			let (mut g, v_map) = from_mock(&mock);
+			let source = v_map[&mock.vertex_at::<0>()];
+			let sink = v_map[&mock.1];
+			let edge_count = g.edge_count();
+
+			g.remove_edge_where_weight(&source, &sink, |_| false)
+				== Err(GraphError::MissingEdge(source, sink))
+				&& g.edge_count() == edge_count
+		}
 	}
 
-	/// Tests that removing a vertex works as expected for AdjListGraph
+	/// Tests that removing a vertex works as expected
+	#[duplicate_item(
+		test_name					from_mock;
+		[adj_list_remove_vertex] 	[adj_list_from_mock];
+		[adj_matrix_remove_vertex] 	[adj_matrix_from_mock];
+		[bi_adj_list_remove_vertex] [bi_adj_list_from_mock];
+		[stable_adj_list_remove_vertex] [stable_adj_list_from_mock];
+		[stable_vertex_map_remove_vertex] [stable_vertex_map_from_mock];
+		[persistent_remove_vertex] 	[persistent_from_mock];
+	)]
 	#[quickcheck]
-	fn adj_list_remove_vertex(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	fn test_name(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
 	{
 		let v_remove = mock.vertex_at::<0>().clone();
 		let mock = mock.release_all();
-		let (mut g, v_map) = adj_list_from_mock(&mock);
+		let (mut g, v_map) = from_mock(&mock);
 		let v_removed = v_map[&v_remove];
 
 		if g.remove_vertex(&v_removed).is_err()
@@ -181,4 +248,99 @@ mod __
 			// TODO: Test that the right edges were removed?
 		}
 	}
+
+	/// Tests that removing a vertex from a `StableAdjListGraph` doesn't change
+	/// the ids of the other vertices.
+	#[quickcheck]
+	fn stable_adj_list_remove_vertex_keeps_ids(
+		Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>,
+	) -> bool
+	{
+		let v_remove = mock.vertex_at::<0>();
+		let mock = mock.release_all();
+		let (mut g, v_map) = stable_adj_list_from_mock(&mock);
+		g.remove_vertex(v_map[&v_remove]).unwrap();
+
+		mock.all_vertices().filter(|v| *v != v_remove).all(|v| {
+			g.vertex_weight(v_map[&v]) == mock.vertex_weight(v)
+				&& mock.all_vertices().filter(|v2| *v2 != v_remove).all(|v2| {
+					unordered_equivalent_lists_equal(
+						&mock.edges_between(v, v2).collect(),
+						&g.edges_between(v_map[&v], v_map[&v2]).collect(),
+					)
+				})
+		}) && !g.contains_vertex(v_map[&v_remove])
+	}
+
+	/// Tests that `StableAdjListGraph::compact` returns a remapping under which
+	/// the graph is unchanged.
+	#[quickcheck]
+	fn stable_adj_list_compact(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v_remove = mock.vertex_at::<0>();
+		let mock = mock.release_all();
+		let (mut g, v_map) = stable_adj_list_from_mock(&mock);
+		g.remove_vertex(v_map[&v_remove]).unwrap();
+		let before = g.clone();
+		let remap = g.compact();
+
+		remap[v_map[&v_remove]].is_none()
+			&& g.all_vertices().eq(0..g.vertex_count())
+			&& before.all_vertices().all(|v| {
+				g.vertex_weight(remap[v].unwrap()) == before.vertex_weight(v)
+					&& before.all_vertices().all(|v2| {
+						unordered_equivalent_lists_equal(
+							&before.edges_between(v, v2).collect(),
+							&g.edges_between(remap[v].unwrap(), remap[v2].unwrap())
+								.collect(),
+						)
+					})
+			})
+	}
+}
+
+/// Tests that an `AdjMatrixGraph` without multiplicity rejects a second edge
+/// between the same vertices.
+#[test]
+fn adj_matrix_unique()
+{
+	use graphene::{
+		common::AdjMatrixGraph,
+		core::property::{AddEdge, NewVertex},
+	};
+
+	let mut g = AdjMatrixGraph::<(), (), Undirected>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+
+	assert!(g.add_edge(&v1, &v2).is_ok());
+	assert!(g.add_edge(&v2, &v1).is_err());
+	assert!(g.add_edge(&v1, &v1).is_ok());
+	assert!(g.add_edge(&v1, &v1).is_err());
+	assert_eq!(g.edge_count(), 2);
+}
+
+/// Tests that reversing a graph swaps the edges sourced and sinked in each
+/// vertex.
+#[quickcheck]
+fn reverse_graph_neighborhood(Arb(mock): Arb<VertexInGraph<MockGraph<Directed>>>) -> bool
+{
+	use graphene::core::proxy::ReverseGraph;
+
+	let v = mock.vertex_at::<0>();
+	let mock = mock.release_all();
+	let (g, v_map) = bi_adj_list_from_mock(&mock);
+	let mapped = v_map[&v];
+	let reversed = ReverseGraph::new(&g);
+
+	unordered_equivalent_lists_equal(
+		&g.edges_sinked_in(mapped).collect(),
+		&reversed.edges_sourced_in(mapped).collect(),
+	) && unordered_equivalent_lists_equal(
+		&g.edges_sourced_in(mapped).collect(),
+		&reversed.edges_sinked_in(mapped).collect(),
+	) && unordered_equivalent_lists_equal(
+		&g.all_edges().map(|(so, si, w)| (si, so, w)).collect(),
+		&reversed.all_edges().collect(),
+	)
 }
@@ -0,0 +1,69 @@
+//! Tests `ImplicitGraph`
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use graphene::{
+	algo::{Bfs, DijkstraShortestPaths, Retainable},
+	common::ImplicitGraph,
+	core::{
+		property::{VertexIn, VertexInGraph},
+		Directed, Graph,
+	},
+};
+
+/// Tests that an implicit graph mirroring a mock graph has exactly the
+/// vertices reachable from the root.
+#[quickcheck]
+fn same_vertices_as_reachable(Arb(mock): Arb<VertexInGraph<MockGraph<Directed>>>) -> bool
+{
+	let root = mock.vertex_at::<0>();
+	let implicit = ImplicitGraph::new(root, |v| {
+		mock.edges_sourced_in(v)
+			.map(|(si, w)| (si, w.clone()))
+			.collect::<Vec<_>>()
+	});
+
+	let mut expected: Vec<_> = Bfs::new(&mock).retain(&mock).collect();
+	expected.push(root);
+
+	unordered_equivalent_lists_equal(&expected, &implicit.all_vertices().collect())
+}
+
+/// Tests that an implicit graph mirroring a mock graph has the same edges
+/// sourced in its root and is searched the same way.
+#[quickcheck]
+fn same_edges_and_search(Arb(mock): Arb<VertexInGraph<MockGraph<Directed>>>) -> bool
+{
+	let root = mock.vertex_at::<0>();
+	let implicit = ImplicitGraph::new(root, |v| {
+		mock.edges_sourced_in(v)
+			.map(|(si, w)| (si, w.clone()))
+			.collect::<Vec<_>>()
+	});
+
+	unordered_equivalent_lists_equal(
+		&mock
+			.edges_sourced_in(root)
+			.map(|(si, w)| (si, w.clone()))
+			.collect(),
+		&implicit
+			.edges_sourced_in(root)
+			.map(|(si, w)| (si, w.0))
+			.collect(),
+	) && Bfs::new(&mock)
+		.retain(&mock)
+		.eq(Bfs::new(&implicit).retain(&implicit))
+}
+
+/// Tests that shortest paths can be found in an infinite implicit graph.
+#[test]
+fn dijkstra_infinite()
+{
+	// Adding 1 costs 1, while doubling costs 3
+	let graph = ImplicitGraph::new(1u32, |v: u32| [(v + 1, 1u32), (v * 2, 3u32)]);
+
+	let (_, distance) = DijkstraShortestPaths::distances(&graph)
+		.find(|(v, _)| *v == 8)
+		.unwrap();
+	// Adding 1 up to 4 and then doubling: 1 + 1 + 1 + 3
+	assert_eq!(distance, 6);
+}
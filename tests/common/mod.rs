@@ -1,14 +1,24 @@
 use crate::mock_graph::{utilities::auto_copy_from, MockEdgeWeight, MockVertex, MockVertexWeight};
 use graphene::{
-	common::{AdjListGraph, VertexMapGraph},
+	common::{
+		AdjListGraph, AdjMatrixGraph, BiAdjListGraph, PersistentGraph, StableAdjListGraph,
+		VertexMapGraph,
+	},
 	core::{property::NewVertex, Graph},
 };
 use std::collections::HashMap;
 
+mod csr_graph;
+mod edge_indexed;
 mod ensured;
+mod grid_graph;
 mod impl_graph;
+mod implicit_graph;
+mod persistent_graph;
+mod shared_graph;
+mod vertex_map_graph;
 
-fn adj_list_from_mock<G>(
+pub fn adj_list_from_mock<G>(
 	mock: &G,
 ) -> (
 	AdjListGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
@@ -22,6 +32,62 @@ where
 	(g, map)
 }
 
+fn adj_matrix_from_mock<G>(
+	mock: &G,
+) -> (
+	AdjMatrixGraph<MockVertexWeight, MockEdgeWeight, G::Directedness, true>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = AdjMatrixGraph::new();
+	let map = auto_copy_from(&mut g, mock, |g, _, w| g.new_vertex_weighted(w).unwrap());
+	(g, map)
+}
+
+fn bi_adj_list_from_mock<G>(
+	mock: &G,
+) -> (
+	BiAdjListGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = BiAdjListGraph::new();
+	let map = auto_copy_from(&mut g, mock, |g, _, w| g.new_vertex_weighted(w).unwrap());
+	(g, map)
+}
+
+fn stable_adj_list_from_mock<G>(
+	mock: &G,
+) -> (
+	StableAdjListGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = StableAdjListGraph::new();
+	let map = auto_copy_from(&mut g, mock, |g, _, w| g.new_vertex_weighted(w).unwrap());
+	(g, map)
+}
+
+fn persistent_from_mock<G>(
+	mock: &G,
+) -> (
+	PersistentGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = PersistentGraph::new();
+	let map = auto_copy_from(&mut g, mock, |g, _, w| g.new_vertex_weighted(w).unwrap());
+	(g, map)
+}
+
 pub fn vertex_map_from_mock<G>(
 	mock: &G,
 ) -> (
@@ -38,3 +104,23 @@ where
 	});
 	(g, map)
 }
+
+pub fn stable_vertex_map_from_mock<G>(
+	mock: &G,
+) -> (
+	VertexMapGraph<
+		MockVertex,
+		StableAdjListGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	>,
+	HashMap<MockVertex, MockVertex>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = VertexMapGraph::<MockVertex, StableAdjListGraph<_, _, _>>::new();
+	let map = auto_copy_from(&mut g, mock, |g, v, w| {
+		g.add_vertex_weighted(v.clone(), w).unwrap();
+		v
+	});
+	(g, map)
+}
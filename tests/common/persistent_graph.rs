@@ -0,0 +1,105 @@
+//! Tests `PersistentGraph`
+
+use crate::{
+	common::persistent_from_mock,
+	mock_graph::{arbitrary::Arb, MockGraph},
+};
+use duplicate::duplicate_item;
+use graphene::{
+	common::PersistentGraph,
+	core::{
+		property::{
+			AddEdge, EdgeCount, NewVertex, RemoveVertex, VertexCount, VertexIn, VertexInGraph,
+		},
+		Directed, Graph, GraphMut, Release, Undirected,
+	},
+};
+
+#[duplicate_item(
+	directedness; [Directed]; [Undirected];
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that changing a clone of the graph doesn't change the original.
+	#[quickcheck]
+	fn clone_unchanged(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = mock.vertex_at::<0>();
+		let mock = mock.release_all();
+		let (g, v_map) = persistent_from_mock(&mock);
+		let v = v_map[&v];
+
+		let mut clone = g.clone();
+		let v2 = clone.new_vertex_weighted(Default::default()).unwrap();
+		clone.add_edge(v, v2).unwrap();
+		clone.vertex_weight_mut(v).unwrap().value += 1;
+		clone.remove_vertex(v).unwrap();
+
+		g.vertex_count() == mock.vertex_count()
+			&& g.edge_count() == mock.edge_count()
+			&& mock
+				.all_vertices_weighted()
+				.all(|(v, w)| g.vertex_weight(v_map[&v]) == Some(w))
+			&& !g.contains_vertex(v2)
+			&& clone.vertex_count() == mock.vertex_count()
+			&& !clone.contains_vertex(v)
+	}
+}
+
+/// Tests a graph large enough to need several levels of internal nodes.
+#[test]
+fn many_vertices()
+{
+	let mut g = PersistentGraph::<usize, (), Undirected>::new();
+	for i in 0..2000
+	{
+		assert_eq!(g.new_vertex_weighted(i * 2), Ok(i));
+	}
+	let snapshot = g.clone();
+	for i in (0..2000).step_by(7)
+	{
+		g.remove_vertex(i).unwrap();
+	}
+	g.add_edge(1, 1999).unwrap();
+
+	assert_eq!(snapshot.vertex_count(), 2000);
+	assert!(snapshot
+		.all_vertices_weighted()
+		.map(|(v, w)| (v, *w))
+		.eq((0..2000).map(|i| (i, i * 2))));
+	assert_eq!(g.vertex_count(), 2000 - 286);
+	assert!(g.all_vertices().all(|v| v % 7 != 0));
+	assert_eq!(g.edges_between(1999, 1).count(), 1);
+	assert_eq!(snapshot.edge_count(), 0);
+	assert_eq!(g.new_vertex(), Ok(2000));
+}
+
+#[test]
+fn versions()
+{
+	let mut base = PersistentGraph::<u32, u32>::new();
+	let v1 = base.new_vertex_weighted(1).unwrap();
+	let v2 = base.new_vertex_weighted(2).unwrap();
+	base.add_edge_weighted(v1, v2, 3).unwrap();
+
+	let (with_v3, v3) = base.with_vertex(4);
+	let with_edge = with_v3.with_edge(v3, v1, 5).unwrap();
+	let without_edge = with_edge.without_edge(v1, v2).unwrap();
+	let without_v1 = with_edge.without_vertex(v1).unwrap();
+
+	assert!(base.all_vertices().eq([v1, v2]));
+	assert_eq!(base.edge_count(), 1);
+	assert!(with_v3.all_vertices().eq([v1, v2, v3]));
+	assert_eq!(with_v3.edge_count(), 1);
+	assert_eq!(with_edge.edge_count(), 2);
+	assert!(without_edge.edges_between(v3, v1).eq([&5]));
+	assert_eq!(without_edge.edge_count(), 1);
+	assert!(without_v1.all_vertices().eq([v2, v3]));
+	assert_eq!(without_v1.edge_count(), 0);
+
+	assert!(base.with_edge(v1, v3, 0).is_err());
+	assert!(base.without_edge(v2, v1).is_err());
+	assert!(without_v1.without_vertex(v1).is_err());
+}
@@ -0,0 +1,108 @@
+//! Tests `SharedGraph`
+
+use graphene::{
+	common::{AdjListGraph, PersistentGraph, SharedGraph},
+	core::{
+		property::{
+			AddEdge, ConnectedGraph, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, UniqueGraph,
+		},
+		Directed, Ensure, Graph, Undirected,
+	},
+};
+use static_assertions::assert_impl_all;
+
+assert_impl_all!(SharedGraph<AdjListGraph<(), ()>>: Send, Sync, Clone, AddEdge, RemoveEdge);
+assert_impl_all!(SharedGraph<PersistentGraph<(), ()>>: Send, Sync, NewVertex, RemoveVertex);
+assert_impl_all!(SharedGraph<ConnectedGraph<PersistentGraph<(), (), Undirected>>>: Send, Sync);
+
+/// Tests that readers only see the graph before or after each change, while
+/// another thread changes it.
+#[test]
+fn concurrent_readers()
+{
+	let mut g = PersistentGraph::<(), (), Directed>::new();
+	let vertices: Vec<_> = (0..10).map(|_| g.new_vertex().unwrap()).collect();
+	let shared = SharedGraph::new(UniqueGraph::ensure(g, ()).unwrap());
+
+	std::thread::scope(|s| {
+		let mut writer = shared.clone();
+		let vertices = &vertices;
+		s.spawn(move || {
+			for &v1 in vertices
+			{
+				for &v2 in vertices
+				{
+					writer.add_edge(v1, v2).unwrap();
+					// Adding the edge again breaks uniqueness
+					assert!(writer.add_edge(v1, v2).is_err());
+				}
+			}
+		});
+		for _ in 0..4
+		{
+			let mut reader = shared.clone();
+			s.spawn(move || {
+				let mut last = 0;
+				for _ in 0..100
+				{
+					reader.refresh();
+					let count = reader.edge_count();
+					let sourced: usize = vertices
+						.iter()
+						.map(|v| reader.edges_sourced_in(v).count())
+						.sum();
+					assert_eq!(count, sourced);
+					assert!(count >= last);
+					assert!(vertices
+						.iter()
+						.all(|&v| reader.edges_between(v, v).count() <= 1));
+					last = count;
+				}
+			});
+		}
+	});
+
+	let mut reader = shared.clone();
+	assert_eq!(reader.edge_count(), 0);
+	reader.refresh();
+	assert_eq!(reader.edge_count(), 100);
+}
+
+/// Tests that changes by several threads are all made to the latest version,
+/// through the ensurer.
+#[test]
+fn concurrent_writers()
+{
+	let mut g = PersistentGraph::<(), (), Undirected>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	let v3 = g.new_vertex().unwrap();
+	let leaf = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+	g.add_edge(v2, v3).unwrap();
+	g.add_edge(v1, leaf).unwrap();
+	let shared = SharedGraph::new(ConnectedGraph::ensure(g, ()).unwrap());
+
+	std::thread::scope(|s| {
+		for _ in 0..4
+		{
+			let mut writer = shared.clone();
+			s.spawn(move || {
+				for _ in 0..50
+				{
+					writer.add_edge(v1, v3).unwrap();
+					assert!(writer.remove_edge(v1, leaf).is_err());
+					// If another thread's change were lost, this edge could be
+					// missing
+					writer.remove_edge(v1, v3).unwrap();
+				}
+			});
+		}
+	});
+
+	let mut g = shared.clone();
+	g.refresh();
+	assert_eq!(g.edge_count(), 3);
+	assert_eq!(g.edges_between(v1, v3).count(), 0);
+	assert!(g.remove_edge(v2, v3).is_err());
+}
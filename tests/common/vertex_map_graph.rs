@@ -0,0 +1,98 @@
+//! Tests `VertexMapGraph`
+
+use graphene::{
+	common::{AdjListGraph, StableAdjListGraph, VertexMapGraph},
+	core::{
+		property::{AddEdge, EdgeCount, RemoveVertex, VertexCount},
+		Graph, GraphError, GraphMut,
+	},
+};
+use std::collections::HashSet;
+
+/// Tests that vertices are returned in the order they were added, both by
+/// `all_vertices_weighted` and `all_vertices_weighted_mut`.
+#[quickcheck]
+fn insertion_order(vertices: Vec<u32>) -> bool
+{
+	let mut seen = HashSet::new();
+	let vertices: Vec<_> = vertices.into_iter().filter(|v| seen.insert(*v)).collect();
+
+	let mut g = VertexMapGraph::<u32, AdjListGraph<u32, ()>>::new();
+	for v in vertices.iter()
+	{
+		g.add_vertex_weighted(*v, v + 1).unwrap();
+	}
+
+	g.all_vertices_weighted()
+		.map(|(v, w)| (v, *w))
+		.eq(vertices.iter().map(|v| (*v, v + 1)))
+		&& g.all_vertices_weighted_mut()
+			.map(|(v, w)| (v, *w))
+			.eq(vertices.iter().map(|v| (*v, v + 1)))
+}
+
+#[test]
+fn add_existing_vertex()
+{
+	let mut g = VertexMapGraph::<char, AdjListGraph<u32, ()>>::new();
+	assert!(g.add_vertex_weighted('a', 1).is_ok());
+	assert_eq!(
+		g.add_vertex_weighted('a', 2),
+		Err(GraphError::DuplicateVertex('a'))
+	);
+	assert_eq!(g.vertex_count(), 1);
+	assert_eq!(g.vertex_weight('a'), Some(&1));
+}
+
+/// Tests that removing a vertex keeps the remaining vertices, their order and
+/// the maps between them and the underlying graph.
+#[test]
+fn remove_vertex()
+{
+	let mut g = VertexMapGraph::<char, StableAdjListGraph<u32, ()>>::new();
+	for (v, w) in [('a', 1), ('b', 2), ('c', 3), ('d', 4)]
+	{
+		g.add_vertex_weighted(v, w).unwrap();
+	}
+	g.add_edge(&'a', &'b').unwrap();
+	g.add_edge(&'b', &'c').unwrap();
+	g.add_edge(&'c', &'d').unwrap();
+	let backing_c = g.backing_vertex('c').unwrap();
+
+	assert_eq!(g.remove_vertex('b'), Ok(2));
+	assert_eq!(g.remove_vertex('b'), Err(GraphError::MissingVertex('b')));
+
+	assert!(g.all_vertices().eq(['a', 'c', 'd']));
+	assert_eq!(g.edge_count(), 1);
+	assert_eq!(g.edges_between('c', 'd').count(), 1);
+	assert_eq!(g.backing_vertex('b'), None);
+	assert_eq!(g.backing_vertex('c'), Some(backing_c));
+	assert_eq!(g.mapped_vertex(backing_c), Some('c'));
+	assert!(g
+		.all_vertices_weighted_mut()
+		.map(|(v, w)| (v, *w))
+		.eq([('a', 1), ('c', 3), ('d', 4)]));
+
+	// The removed vertex can be added again
+	g.add_vertex_weighted('b', 5).unwrap();
+	assert!(g.all_vertices().eq(['a', 'c', 'd', 'b']));
+	assert_eq!(g.vertex_weight('b'), Some(&5));
+}
+
+/// Tests that edges and weights changed in the underlying graph show up in the
+/// mapped graph.
+#[test]
+fn change_backing_graph()
+{
+	let mut g = VertexMapGraph::<char, AdjListGraph<u32, u32>>::new();
+	g.add_vertex_weighted('a', 1).unwrap();
+	g.add_vertex_weighted('b', 2).unwrap();
+	let [a, b] = ['a', 'b'].map(|v| g.map()[&v]);
+
+	g.backing_graph_mut().add_edge_weighted(a, b, 3).unwrap();
+	*g.backing_graph_mut().vertex_weight_mut(a).unwrap() = 4;
+
+	assert!(g.edges_between('a', 'b').eq([&3]));
+	assert_eq!(g.vertex_weight('a'), Some(&4));
+	assert_eq!(g.map().len(), 2);
+}
@@ -0,0 +1,259 @@
+//! Tests deriving `Ensurer` and delegating properties defined outside
+//! graphene.
+
+use crate::mock_graph::{MockGraph, MockVertex, MockVertexWeight};
+use graphene::core::{
+	delegate_properties,
+	property::{
+		AddEdge, ConnectedGraph, KConnectedGraph, NewVertex, RemoveVertex, RootedGraph, Unique,
+		UniqueGraph, VertexCount, VertexInGraph,
+	},
+	Directed, Ensure, Ensurer, Graph, GraphDeref, GraphDerefMut, GraphError, ReleasePayload,
+	Undirected, Violation,
+};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use std::borrow::Borrow;
+
+/// A property outside graphene: The graph has at least some number of
+/// vertices.
+#[delegate_properties]
+pub trait MinVertices: Graph
+{
+	/// The least number of vertices the graph may have.
+	fn min_vertices(&self) -> usize;
+
+	/// Changes the least number of vertices, if the graph has enough.
+	fn set_min_vertices(&mut self, min: usize) -> bool;
+
+	/// Whether removing the given number of vertices is allowed.
+	fn can_remove(&self, count: usize) -> bool
+	{
+		self.all_vertices().count() >= self.min_vertices() + count
+	}
+}
+
+/// Ensures `MinVertices`, with the number of vertices as payload.
+#[derive(Ensurer)]
+#[ensurer(exclude(Ensure, RemoveVertex))]
+pub struct MinVerticesGraph<C: Ensure>(C, #[ensurer(payload)] usize);
+
+impl<C: Ensure> Ensure for MinVerticesGraph<C>
+{
+	fn ensure_unchecked(c: Self::Ensured, min: usize) -> Self
+	{
+		Self(c, min)
+	}
+
+	fn can_ensure(c: &Self::Ensured, min: &usize) -> bool
+	{
+		c.graph().all_vertices().count() >= *min
+	}
+}
+
+impl<C: Ensure> MinVertices for MinVerticesGraph<C>
+{
+	fn min_vertices(&self) -> usize
+	{
+		self.1
+	}
+
+	fn set_min_vertices(&mut self, min: usize) -> bool
+	{
+		let allowed = Self::can_ensure(&self.0, &min);
+		if allowed
+		{
+			self.1 = min;
+		}
+		allowed
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for MinVerticesGraph<C>
+where
+	C::Graph: RemoveVertex,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		if self.can_remove(1)
+		{
+			self.0.graph_mut().remove_vertex(v)
+		}
+		else
+		{
+			Err(GraphError::rejected::<Self>(Violation::Unspecified))
+		}
+	}
+}
+
+/// An ensurer that ensures nothing, but passes on `MinVertices`.
+#[derive(Ensurer)]
+#[ensurer(delegate(MinVertices))]
+pub struct PassThrough<C: Ensure>(C);
+
+/// An ensurer with named fields.
+#[derive(Ensurer)]
+#[ensurer(exclude(Ensure), delegate(MinVertices))]
+pub struct Named<C: Ensure>
+{
+	#[ensurer(ensured)]
+	inner: C,
+	ensured_count: usize,
+}
+
+impl<C: Ensure> Ensure for Named<C>
+{
+	fn ensure_unchecked(c: Self::Ensured, _: ()) -> Self
+	{
+		Self {
+			inner: c,
+			ensured_count: 1,
+		}
+	}
+
+	fn can_ensure(_: &Self::Ensured, _: &()) -> bool
+	{
+		true
+	}
+}
+
+/// A property outside graphene, without methods that change the graph: The
+/// graph has a label.
+#[delegate_properties]
+pub trait Labeled: Graph
+{
+	fn label(&self) -> &str;
+}
+
+/// Ensures `Labeled`, with the label as payload.
+#[derive(Ensurer)]
+#[ensurer(exclude(Ensure))]
+pub struct LabeledGraph<C: Ensure>(C, #[ensurer(payload)] &'static str);
+
+impl<C: Ensure> Ensure for LabeledGraph<C>
+{
+	fn ensure_unchecked(c: Self::Ensured, label: &'static str) -> Self
+	{
+		Self(c, label)
+	}
+
+	fn can_ensure(_: &Self::Ensured, _: &&'static str) -> bool
+	{
+		true
+	}
+}
+
+impl<C: Ensure> Labeled for LabeledGraph<C>
+{
+	fn label(&self) -> &str
+	{
+		self.1
+	}
+}
+
+type Graph3 = PassThrough<MinVerticesGraph<UniqueGraph<MockGraph<Directed>>>>;
+
+/// Creates a graph with the given number of vertices.
+fn graph_with(count: usize) -> (MockGraph<Directed>, Vec<usize>)
+{
+	let mut g = MockGraph::empty();
+	let vertices = (0..count)
+		.map(|i| {
+			g.new_vertex_weighted(MockVertexWeight { value: i as u32 })
+				.unwrap()
+				.value
+		})
+		.collect();
+	(g, vertices)
+}
+
+/// Tests that the derived ensurers implement graphene's properties and the
+/// ones delegated to them.
+#[test]
+fn delegates_properties()
+{
+	let (g, _) = graph_with(3);
+	let mut g = Graph3::ensure_all(g, (2, ())).unwrap();
+
+	assert_eq!(g.min_vertices(), 2);
+	assert!(g.can_remove(1));
+	assert!(!g.set_min_vertices(4));
+	assert!(g.set_min_vertices(3));
+	assert_eq!(g.min_vertices(), 3);
+	assert_eq!(g.vertex_count(), 3);
+}
+
+/// Tests that removing a vertex goes through the ensurer that is delegated to.
+#[test]
+fn delegates_mutation()
+{
+	let (g, vertices) = graph_with(3);
+	let mut g = Graph3::ensure_all(g, (2, ())).unwrap();
+
+	assert!(g.remove_vertex(MockVertex { value: vertices[0] }).is_ok());
+	assert!(matches!(
+		g.remove_vertex(MockVertex { value: vertices[1] }),
+		Err(GraphError::Rejected(_))
+	));
+	assert_eq!(g.vertex_count(), 2);
+}
+
+/// Tests that the payload of derived ensurers is released.
+#[test]
+fn releases_payload()
+{
+	let (g, _) = graph_with(2);
+	let ensured = Graph3::ensure_all(g, (1, ())).unwrap();
+
+	let (inner, ()) = ensured.release();
+	let (_, min) = inner.release();
+	assert_eq!(min, 1);
+
+	let (g, _) = graph_with(1);
+	assert!(Graph3::ensure_all(g, (2, ())).is_err());
+}
+
+/// Tests that an ensurer with named fields delegates to the marked field.
+#[test]
+fn named_fields()
+{
+	let (g, _) = graph_with(2);
+	let g = MinVerticesGraph::<MockGraph<Directed>>::ensure_all(g, (1, ())).unwrap();
+	let named = Named::ensure(g, ()).unwrap();
+
+	assert_eq!(named.min_vertices(), 1);
+	assert_eq!(named.ensured_count, 1);
+	assert_eq!(named.graph().vertex_count(), 2);
+}
+
+/// Tests that graphene's ensurers delegate properties defined outside
+/// graphene, unless they could change the graph.
+#[test]
+fn builtin_ensurers()
+{
+	let (mut g, vertices) = graph_with(2);
+	g.add_edge(
+		MockVertex { value: vertices[0] },
+		MockVertex { value: vertices[1] },
+	)
+	.unwrap();
+	let g = LabeledGraph::ensure(g, "labeled").unwrap();
+	let g = RootedGraph::ensure(
+		UniqueGraph::ensure(g, ()).unwrap(),
+		MockVertex { value: vertices[0] },
+	)
+	.unwrap();
+
+	assert_eq!(g.label(), "labeled");
+	assert_eq!(g.graph().vertex_count(), 2);
+}
+
+assert_impl_all!(ConnectedGraph<LabeledGraph<MockGraph<Undirected>>>: Labeled, RemoveVertex);
+assert_impl_all!(KConnectedGraph<LabeledGraph<MockGraph<Undirected>>, 2>: Labeled);
+assert_impl_all!(VertexInGraph<LabeledGraph<MockGraph<Directed>>, 2, false>: Labeled);
+assert_not_impl_any!(ConnectedGraph<MinVerticesGraph<MockGraph<Undirected>>>: MinVertices);
+
+assert_impl_all!(Graph3: MinVertices, Unique, RemoveVertex, NewVertex);
+assert_impl_all!(MinVerticesGraph<UniqueGraph<MockGraph<Directed>>>: Unique, GraphDeref);
@@ -2,7 +2,14 @@
 
 use crate::mock_graph::MockVertex;
 use delegate::delegate;
-use graphene::core::{Ensure, Graph, GraphDeref, GraphDerefMut, GraphMut, ReleasePayload};
+use graphene::{
+	common::{AdjListGraph, PersistentGraph},
+	core::{
+		property::{ConnectedGraph, HasVertexGraph, SimpleGraph, UniqueGraph, VertexInGraph},
+		Ensure, Graph, GraphDeref, GraphDerefMut, GraphMut, ReleasePayload, Undirected,
+	},
+};
+use static_assertions::assert_impl_all;
 use std::borrow::Borrow;
 
 /// A mock property that doesn't use mutability.
@@ -265,7 +272,7 @@ mod test_with_payload
 		core::ensure::{MockEnsurer, MockProperty, MockPropertyMut, MockUnloadedEnsurer},
 		mock_graph::{MockDirectedness, MockGraph, MockVertex, MockVertexWeight},
 	};
-	use graphene::core::{BaseGraph, Ensure, ReleasePayload};
+	use graphene::core::{BaseGraph, Ensure, ReleasePayload, Violation};
 
 	/// Test that defining a type alias allows for easy ensuring of a base graph
 	#[test]
@@ -293,6 +300,31 @@ mod test_with_payload
 		assert_eq!(released_payload, (payload, (payload, ())));
 	}
 
+	/// Test that failing to ensure reports which layer failed
+	#[test]
+	fn failing_layer()
+	{
+		type EnsuredGraph = MockEnsurer<MockEnsurer<MockGraph<MockDirectedness>>>;
+		let ok = MockVertex { value: 1 };
+		let wrong = MockVertex { value: 2 };
+
+		let Err(err) = EnsuredGraph::ensure_all(ensurable_graph!(), (wrong, (ok, ())))
+		else
+		{
+			panic!("Ensured graph with wrong outer payload")
+		};
+		assert_eq!(err.layer, 0);
+		assert!(err.ensurer.contains("MockEnsurer"));
+		assert_eq!(err.violation, Violation::Unspecified);
+
+		let Err(err) = EnsuredGraph::ensure_all(ensurable_graph!(), (ok, (wrong, ())))
+		else
+		{
+			panic!("Ensured graph with wrong inner payload")
+		};
+		assert_eq!(err.layer, 1);
+	}
+
 	/// Test that can define properties inline
 	#[test]
 	fn inline_ensuring()
@@ -633,3 +665,11 @@ mod test_no_payload
 		c_owned_unc.release_all().validate_is_graph()
 	}
 }
+
+// Ensurers can be shared between threads whenever their underlying graph can
+assert_impl_all!(ConnectedGraph<AdjListGraph<(), ()>>: Send, Sync);
+assert_impl_all!(ConnectedGraph<PersistentGraph<(), ()>>: Send, Sync);
+assert_impl_all!(UniqueGraph<AdjListGraph<(), ()>>: Send, Sync);
+assert_impl_all!(HasVertexGraph<AdjListGraph<(), ()>>: Send, Sync);
+assert_impl_all!(VertexInGraph<AdjListGraph<(), ()>, 2>: Send, Sync);
+assert_impl_all!(SimpleGraph<AdjListGraph<(), (), Undirected>>: Send, Sync);
@@ -1,5 +1,6 @@
 //! Testing for graphene::core.
 
+mod derive;
 mod edge;
 mod ensure;
 mod graph;
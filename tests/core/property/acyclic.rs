@@ -7,9 +7,10 @@ use crate::mock_graph::{
 use duplicate::duplicate_item;
 use graphene::core::{
 	property::{Acyclic, AcyclicGraph, AddEdge, NoLoops, RemoveEdge, VertexIn},
-	Directed, Graph, Guard, Release, Undirected,
+	Directed, Directedness, EnsureError, Graph, GraphError, Guard, Release, Undirected, Violation,
 };
 use static_assertions::assert_impl_all;
+use std::collections::HashSet;
 
 #[duplicate_item(
 	directedness; [ Directed ]; [ Undirected ]
@@ -33,6 +34,38 @@ mod __
 		!AcyclicGraph::can_guard(&g.0)
 	}
 
+	/// Tests that AcyclicGraph reports a cycle that is in the graph when
+	/// rejecting.
+	#[quickcheck]
+	fn report_cycle(g: Arb<CyclicGraph<directedness, MockEdgeWeight>>) -> bool
+	{
+		match AcyclicGraph::guard(g.0.clone())
+		{
+			Err(EnsureError {
+				violation: Violation::Cycle(cycle),
+				..
+			}) =>
+			{
+				let unique: HashSet<_> = cycle.iter().collect();
+				unique.len() == cycle.len()
+					&& cycle.iter().enumerate().all(|(idx, v)| {
+						let next = cycle[(idx + 1) % cycle.len()];
+						// An undirected cycle between 2 vertices needs 2 edges
+						let needed = if !directedness::directed() && cycle.len() == 2
+						{
+							2
+						}
+						else
+						{
+							1
+						};
+						g.0.edges_between(v, next).count() >= needed
+					})
+			},
+			_ => false,
+		}
+	}
+
 	/// Tests that a AcyclicGraph accepts adding an edge that doesn't
 	/// result in a cycle
 	#[quickcheck]
@@ -52,7 +85,8 @@ mod __
 			&& g.edges_between(source, sink).count() == edge_count
 	}
 
-	/// Tests that a AcyclicGraph rejects adding an edge that results in a cycle
+	/// Tests that a AcyclicGraph rejects adding an edge that results in a
+	/// cycle, reporting a cycle through the edge.
 	#[quickcheck]
 	fn reject_add_edge(
 		Arb(graph): Arb<TwoReachableVerticesIn<AcyclicGraph<MockGraph<directedness>>>>,
@@ -65,7 +99,15 @@ mod __
 
 		let mut g = AcyclicGraph::guard_unchecked(graph.release_all());
 
-		g.add_edge_weighted(v2, v1, weight).is_err() && g.all_edges().count() == edge_count
+		let rejected = match g.add_edge_weighted(v2, v1, weight)
+		{
+			Err(GraphError::Rejected(EnsureError {
+				violation: Violation::Cycle(cycle),
+				..
+			})) => cycle.contains(&v1) && cycle.contains(&v2),
+			_ => false,
+		};
+		rejected && g.all_edges().count() == edge_count
 	}
 
 	assert_impl_all!(AcyclicGraph<MockGraph<directedness>>: Acyclic, NoLoops);
@@ -0,0 +1,161 @@
+//! Tests the `core::property::Bipartite` trait and its ensurer
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate_item;
+use graphene::{
+	common::StableAdjListGraph,
+	core::{
+		property::{
+			AddEdge, Bipartite, BipartiteGraph, NewVertex, NoLoops, RemoveEdge, RemoveVertex,
+		},
+		Directed, Directedness, Graph, GraphError, Guard, Release, Undirected, Violation,
+	},
+};
+use static_assertions::assert_impl_all;
+
+/// Removes all edges between vertices whose values have the same parity,
+/// which makes the graph bipartite.
+fn split_by_parity<D: Directedness>(mut g: MockGraph<D>) -> MockGraph<D>
+{
+	let same_parity: Vec<_> = g
+		.all_edges()
+		.filter(|(so, si, _)| so.value % 2 == si.value % 2)
+		.map(|(so, si, _)| (so, si))
+		.collect();
+	for (so, si) in same_parity
+	{
+		g.remove_edge(so, si).unwrap();
+	}
+	g
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that BipartiteGraph accepts bipartite graphs and finds sides that
+	/// every edge goes between.
+	#[quickcheck]
+	fn accept_bipartite(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let g = split_by_parity(g);
+		if !BipartiteGraph::can_guard(&g)
+		{
+			return false;
+		}
+		let g = BipartiteGraph::guard_unchecked(g);
+
+		g.all_edges()
+			.all(|(so, si, _)| g.is_left(so) != g.is_left(si))
+			&& g.left_vertices().count() + g.right_vertices().count() == g.all_vertices().count()
+	}
+
+	/// Tests that BipartiteGraph rejects graphs with an odd cycle.
+	#[quickcheck]
+	fn reject_odd_cycle(Arb(mut g): Arb<MockGraph<directedness>>, length: u8) -> bool
+	{
+		let cycle: Vec<_> = (0..(length % 4) * 2 + 1)
+			.map(|_| g.new_vertex().unwrap())
+			.collect();
+		for (idx, v) in cycle.iter().enumerate()
+		{
+			g.add_edge(v, cycle[(idx + 1) % cycle.len()]).unwrap();
+		}
+
+		!BipartiteGraph::can_guard(&g)
+	}
+
+	/// Tests that BipartiteGraph reports an odd cycle exactly when it rejects a
+	/// graph, ignoring edge directions.
+	#[quickcheck]
+	fn report_odd_cycle(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		match BipartiteGraph::guard(g.clone()).map_err(|e| e.violation)
+		{
+			Ok(_) => true,
+			Err(Violation::Cycle(cycle)) =>
+			{
+				cycle.len() % 2 == 1
+					&& cycle.iter().enumerate().all(|(idx, v)| {
+						let next = cycle[(idx + 1) % cycle.len()];
+						g.edges_between(v, next).next().is_some()
+							|| g.edges_between(next, v).next().is_some()
+					})
+			},
+			_ => false,
+		}
+	}
+
+	/// Tests that an edge is added exactly if the graph stays bipartite, and
+	/// that the sides are updated such that every edge goes between them.
+	#[quickcheck]
+	fn add_edge(Arb(g): Arb<MockGraph<directedness>>, idx1: usize, idx2: usize) -> bool
+	{
+		let mut g = BipartiteGraph::guard(split_by_parity(g)).unwrap();
+		let vertices: Vec<_> = g.all_vertices().collect();
+		if vertices.is_empty()
+		{
+			return true;
+		}
+		let v1 = vertices[idx1 % vertices.len()];
+		let v2 = vertices[idx2 % vertices.len()];
+		let edge_count = g.edges_between(v1, v2).count();
+		let mut expected = g.clone().release_all();
+		expected.add_edge(v1, v2).unwrap();
+		let accepted = BipartiteGraph::can_guard(&expected);
+
+		g.add_edge(v1, v2).is_ok() == accepted
+			&& g.edges_between(v1, v2).count() == edge_count + accepted as usize
+			&& g.all_edges()
+				.all(|(so, si, _)| g.is_left(so) != g.is_left(si))
+	}
+
+	/// Tests that new vertices are added to the requested side.
+	#[quickcheck]
+	fn new_vertex(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let mut g = BipartiteGraph::guard(split_by_parity(g)).unwrap();
+		let left = g.new_vertex().unwrap();
+		let right = g.new_right_vertex_weighted(Default::default()).unwrap();
+
+		g.is_left(left)
+			&& !g.is_left(right)
+			&& g.add_edge(left, right).is_ok()
+			&& g.add_edge(left, left).is_err()
+	}
+
+	assert_impl_all!(BipartiteGraph<MockGraph<directedness>>: Bipartite, NoLoops);
+}
+
+/// Tests that removing a vertex keeps the sides of the other vertices, and
+/// that joining two components switches the sides of the sink's component.
+#[test]
+fn remove_vertex()
+{
+	let mut g = StableAdjListGraph::<(), (), Undirected>::new();
+	let vs: Vec<_> = (0..4).map(|_| g.new_vertex().unwrap()).collect();
+	g.add_edge(vs[0], vs[1]).unwrap();
+	g.add_edge(vs[1], vs[2]).unwrap();
+	g.add_edge(vs[2], vs[3]).unwrap();
+	let mut g = BipartiteGraph::guard(g).unwrap();
+	let sides: Vec<_> = vs.iter().map(|v| g.is_left(v)).collect();
+
+	assert_eq!(sides, [true, false, true, false]);
+	assert!(g.remove_vertex(vs[1]).is_ok());
+	assert!(g.left_vertices().eq([vs[0], vs[2]]));
+	assert!(g.right_vertices().eq([vs[3]]));
+	assert!(g.add_edge(vs[0], vs[2]).is_ok());
+	assert!(g.left_vertices().eq([vs[0], vs[3]]));
+	assert!(g.right_vertices().eq([vs[2]]));
+	match g.add_edge(vs[0], vs[3])
+	{
+		Err(GraphError::Rejected(err)) =>
+		{
+			assert!(matches!(err.violation, Violation::Cycle(cycle) if cycle.len() == 3))
+		},
+		_ => panic!("closing an odd cycle should be rejected"),
+	}
+}
@@ -6,13 +6,15 @@ use crate::mock_graph::{
 };
 use duplicate::duplicate_item;
 use graphene::{
-	algo::DijkstraShortestPaths,
+	algo::{Bfs, DijkstraShortestPaths, Retainable},
+	common::AdjListGraph,
 	core::{
 		property::{
-			AddEdge, Connected, ConnectedGraph, NewVertex, RemoveEdge, RemoveVertex, Unilateral,
-			UnilateralGraph, VertexIn, VertexInGraph, Weak, WeakGraph,
+			AddEdge, Connected, ConnectedGraph, NewLeafUndirected, NewVertex, RemoveEdge,
+			RemoveVertex, TreeGraph, Unilateral, UnilateralGraph, VertexIn, VertexInGraph, Weak,
+			WeakGraph,
 		},
-		Directed, Graph, Undirected,
+		Directed, Ensure, EnsureError, Graph, GraphError, Guard, Undirected, Violation,
 	},
 };
 use static_assertions::assert_impl_all;
@@ -66,6 +68,24 @@ mod module
 		!connected_graph::can_guard(&g.release_all())
 	}
 
+	/// Tests that the graph reports a pair of vertices where the second can't
+	/// be reached from the first when rejecting.
+	#[quickcheck]
+	fn report_unreachable(Arb(g): Arb<arb_unconnected>) -> bool
+	{
+		let g = g.release_all();
+		match connected_graph::guard(g.clone()).map_err(|e| e.violation)
+		{
+			Err(Violation::Unreachable(v1, v2)) =>
+			{
+				let start = VertexInGraph::ensure_unchecked(&g, [v1]);
+				let mut bfs = Bfs::new(&start).retain(&start);
+				!bfs.any(|v| v == v2)
+			},
+			_ => false,
+		}
+	}
+
 	/// Tests that a graph always accepts adding an edge.
 	#[quickcheck]
 	fn accept_add_edge_weighted(
@@ -131,9 +151,13 @@ mod module
 		let mut connected = connected_graph::guard_unchecked(graph);
 
 		// We now try to remove the added edge
-		connected
-			.remove_edge_where_weight(&v1, &v_map[&v2], |_| true)
-			.is_err()
+		matches!(
+			connected.remove_edge_where_weight(&v1, &v_map[&v2], |_| true),
+			Err(GraphError::Rejected(EnsureError {
+				violation: Violation::Unreachable(..),
+				..
+			}))
+		)
 	}
 
 	/// Tests that a graph accepts removing a vertex if the remaining
@@ -285,6 +309,144 @@ mod __
 	}
 }
 
+/// Tests the ensurers that keep track of connectivity as edges are added and
+/// removed.
+#[duplicate_item(
+	module						connected_graph		directedness;
+	[dynamic_connected]			[ConnectedGraph]	[Undirected];
+	[dynamic_weak]				[WeakGraph]			[Directed];
+)]
+mod module
+{
+	use super::*;
+	use graphene::core::{Guard, Release};
+
+	/// Tests that a graph keeps accepting exactly the edge removals that don't
+	/// break its connectedness as edges are removed one after the other.
+	#[quickcheck]
+	fn remove_edges_in_sequence(Arb(g): Arb<connected_graph<MockGraph<directedness>>>) -> bool
+	{
+		let mut g = connected_graph::guard_unchecked(g.release_all());
+		let edges: Vec<_> = g
+			.all_edges()
+			.map(|(so, si, w)| (so, si, w.clone()))
+			.collect();
+
+		edges.into_iter().all(|(so, si, weight)| {
+			let mut removed = g.clone().release_all();
+			removed
+				.remove_edge_where_weight(so, si, |w| *w == weight)
+				.unwrap();
+			let expected = connected_graph::can_guard(&removed);
+
+			g.remove_edge_where_weight(so, si, |w| *w == weight).is_ok() == expected
+		})
+	}
+
+	/// Tests that a graph accepts exactly the changes that don't break its
+	/// connectedness as edges are added and removed and vertices are removed
+	/// in any order.
+	#[quickcheck]
+	fn mutate_in_sequence(
+		Arb(g): Arb<connected_graph<MockGraph<directedness>>>,
+		ops: Vec<(u8, usize, usize)>,
+	) -> bool
+	{
+		let mut g = connected_graph::guard_unchecked(g.release_all());
+
+		ops.into_iter().all(|(op, idx1, idx2)| {
+			let before = g.clone().release_all();
+			let mut after = before.clone();
+			let vertices: Vec<_> = before.all_vertices().collect();
+			let edges: Vec<_> = before.all_edges().map(|(so, si, _)| (so, si)).collect();
+			if vertices.is_empty()
+			{
+				return true;
+			}
+			let v1 = vertices[idx1 % vertices.len()];
+			let v2 = vertices[idx2 % vertices.len()];
+
+			let done = match op % 5
+			{
+				0 | 1 =>
+				{
+					after.add_edge(v1, v2).unwrap();
+					g.add_edge(v1, v2).is_ok()
+				},
+				2 | 3 if !edges.is_empty() =>
+				{
+					let (so, si) = edges[idx1 % edges.len()];
+					after.remove_edge(so, si).unwrap();
+					g.remove_edge(so, si).is_ok()
+				},
+				_ =>
+				{
+					after.remove_vertex(v1).unwrap();
+					g.remove_vertex(v1).is_ok()
+				},
+			};
+			let accepted = connected_graph::can_guard(&after);
+			let expected = if accepted { after } else { before };
+
+			done == accepted
+				&& g.all_vertices().count() == expected.all_vertices().count()
+				&& g.all_edges().count() == expected.all_edges().count()
+		})
+	}
+}
+
+/// Tests that connectivity is still kept track of correctly after removing a
+/// vertex gives other vertices new ids.
+#[test]
+fn renumbered_vertices()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let vs: Vec<_> = (0..5).map(|_| g.new_vertex().unwrap()).collect();
+	for i in 0..5
+	{
+		g.add_edge(vs[i], vs[(i + 1) % 5]).unwrap();
+	}
+	g.add_edge(vs[1], vs[3]).unwrap();
+	let mut g = ConnectedGraph::guard(g).unwrap();
+
+	// Removing the first vertex moves the others down by one, leaving the
+	// triangle 0-1-2 with 3 hanging off 2
+	g.remove_vertex(vs[0]).unwrap();
+	assert!(matches!(
+		g.remove_edge(vs[2], vs[3]),
+		Err(GraphError::Rejected(EnsureError {
+			violation: Violation::Unreachable(..),
+			..
+		}))
+	));
+	g.remove_edge(vs[0], vs[1]).unwrap();
+	assert!(g.remove_edge(vs[1], vs[2]).is_err());
+	assert!(g.remove_vertex(vs[2]).is_err());
+	g.add_edge(vs[1], vs[3]).unwrap();
+	g.remove_edge(vs[1], vs[2]).unwrap();
+	assert_eq!(g.all_edges().count(), 3);
+}
+
+/// Tests that a leaf added to a connected tree is kept track of.
+#[test]
+fn new_leaf()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let vs: Vec<_> = (0..3).map(|_| g.new_vertex().unwrap()).collect();
+	g.add_edge(vs[0], vs[1]).unwrap();
+	g.add_edge(vs[1], vs[2]).unwrap();
+	let mut g = ConnectedGraph::guard(TreeGraph::ensure(g, ()).unwrap()).unwrap();
+
+	// Builds the structure without changing the graph
+	assert!(g.remove_vertex(vs[1]).is_err());
+	let leaf = g.new_leaf(vs[2]).unwrap();
+	assert!(g.remove_vertex(vs[2]).is_err());
+	g.remove_vertex(leaf).unwrap();
+	assert!(g.remove_vertex(vs[1]).is_err());
+	assert_eq!(g.all_vertices().count(), 3);
+	assert_eq!(g.all_edges().count(), 2);
+}
+
 // Test that all Connected graphs are also unilateral and weak.
 assert_impl_all!(ConnectedGraph<MockGraph<MockDirectedness>>: Connected, Unilateral, Weak);
 
@@ -0,0 +1,211 @@
+//! Tests the `core::property::DynEnsuredGraph` ensurer
+
+use crate::mock_graph::{
+	arbitrary::{Arb, CyclicGraph},
+	MockEdgeWeight, MockGraph,
+};
+use duplicate::duplicate_item;
+use graphene::core::{
+	property::{
+		AcyclicGraph, AddEdge, BipartiteGraph, ConnectedGraph, DynEnsuredGraph, DynProperties,
+		DynProperty, ForestGraph, HasVertexGraph, NewLeafDirected, NewLeafUndirected, NewVertex,
+		NoLoopsGraph, RemoveEdge, RemoveVertex, TreeGraph, UniqueGraph,
+	},
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, Guard, Release, Undirected,
+};
+use static_assertions::assert_impl_all;
+
+/// Returns the properties whose index in `DynProperty::ALL` has its bit set.
+fn properties(bits: u8) -> DynProperties
+{
+	DynProperty::ALL
+		.into_iter()
+		.enumerate()
+		.filter(|(i, _)| bits & (1 << i) != 0)
+		.map(|(_, p)| p)
+		.collect()
+}
+
+/// Whether the ensurer for the given property accepts the graph.
+fn can_guard(p: DynProperty, g: &MockGraph<impl graphene::core::Directedness>) -> bool
+{
+	match p
+	{
+		DynProperty::Unique => UniqueGraph::can_guard(g),
+		DynProperty::NoLoops => NoLoopsGraph::can_guard(g),
+		DynProperty::HasVertex => HasVertexGraph::can_guard(g),
+		DynProperty::Acyclic => AcyclicGraph::can_guard(g),
+		DynProperty::Bipartite => BipartiteGraph::can_guard(g),
+		DynProperty::Connected => ConnectedGraph::can_guard(g),
+		DynProperty::Forest => ForestGraph::can_guard(g),
+		DynProperty::Tree => TreeGraph::can_guard(g),
+	}
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that a graph is accepted exactly if all the ensurers of the
+	/// properties accept it.
+	#[quickcheck]
+	fn accept_if_all_accept(Arb(g): Arb<MockGraph<directedness>>, bits: u8) -> bool
+	{
+		let props = properties(bits);
+		DynEnsuredGraph::can_ensure(&g, &props) == props.iter().all(|p| can_guard(p, &g))
+	}
+
+	/// Tests that a rejection names the ensurer of the first property that
+	/// doesn't hold.
+	#[quickcheck]
+	fn reject_names_ensurer(Arb(g): Arb<CyclicGraph<directedness, MockEdgeWeight>>) -> bool
+	{
+		let props = DynProperty::HasVertex | DynProperty::Acyclic | DynProperty::Tree;
+		match DynEnsuredGraph::ensure(g.0, props)
+		{
+			Err(err) => err.ensurer.contains("AcyclicGraph") && err.layer == 0,
+			Ok(_) => false,
+		}
+	}
+
+	/// Tests that a mutation is done exactly if the graph still has all the
+	/// properties afterwards, and otherwise leaves the graph unchanged.
+	///
+	/// Starts from a tree, so that any set of properties may be ensured.
+	#[quickcheck]
+	fn mutation(
+		g: Arb<TreeGraph<MockGraph<directedness>>>,
+		bits: u8,
+		op: u8,
+		idx1: usize,
+		idx2: usize,
+		weight: MockEdgeWeight,
+	) -> bool
+	{
+		let g = g.0.release_all();
+		let props = properties(bits);
+		let Ok(mut ensured) = DynEnsuredGraph::ensure(g.clone(), props)
+		else
+		{
+			return true;
+		};
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let edges: Vec<_> = g.all_edges().map(|(so, si, _)| (so, si)).collect();
+		let v1 = vertices[idx1 % vertices.len()];
+		let v2 = vertices[idx2 % vertices.len()];
+
+		let mut expected = g.clone();
+		let (done, expected_done) = match op % 4
+		{
+			0 => (ensured.new_vertex().is_ok(), expected.new_vertex().is_ok()),
+			1 =>
+			{
+				(
+					ensured.remove_vertex(v1).is_ok(),
+					expected.remove_vertex(v1).is_ok(),
+				)
+			},
+			2 =>
+			{
+				(
+					ensured.add_edge_weighted(v1, v2, weight.clone()).is_ok(),
+					expected.add_edge_weighted(v1, v2, weight).is_ok(),
+				)
+			},
+			_ =>
+			{
+				if edges.is_empty()
+				{
+					return true;
+				}
+				let (so, si) = edges[idx1 % edges.len()];
+				(
+					ensured.remove_edge(so, si).is_ok(),
+					expected.remove_edge(so, si).is_ok(),
+				)
+			},
+		};
+		let accepted = expected_done && DynEnsuredGraph::can_ensure(&expected, &props);
+		let result = if accepted { expected } else { g };
+
+		done == accepted
+			&& ensured.all_vertices().count() == result.all_vertices().count()
+			&& ensured.all_edges().count() == result.all_edges().count()
+			&& DynEnsuredGraph::can_ensure(&ensured.release(), &props)
+	}
+
+	assert_impl_all!(DynEnsuredGraph<MockGraph<directedness>>:
+		NewVertex, RemoveVertex, AddEdge, RemoveEdge);
+}
+
+/// Tests building a set of properties and the errors of rejected mutations.
+#[test]
+fn reject_mutation()
+{
+	let mut g = MockGraph::<Undirected>::empty();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+
+	let mut props = DynProperty::NoLoops | DynProperty::Unique;
+	props |= DynProperty::Connected;
+	assert_eq!(
+		props.iter().collect::<Vec<_>>(),
+		[
+			DynProperty::Unique,
+			DynProperty::NoLoops,
+			DynProperty::Connected
+		]
+	);
+	let mut g = DynEnsuredGraph::ensure(g, props).unwrap();
+	assert_eq!(g.properties(), props);
+
+	match g.add_edge(v1, v1)
+	{
+		Err(GraphError::Rejected(err)) => assert!(err.ensurer.contains("NoLoopsGraph")),
+		_ => panic!("adding a loop should be rejected"),
+	}
+	match g.remove_edge(v1, v2)
+	{
+		Err(GraphError::Rejected(err)) => assert!(err.ensurer.contains("ConnectedGraph")),
+		_ => panic!("removing the only edge should be rejected"),
+	}
+	assert!(matches!(g.new_vertex(), Err(GraphError::Rejected(_))));
+	assert_eq!(g.all_edges().count(), 1);
+	assert_eq!(g.all_vertices().count(), 2);
+}
+
+/// Tests that leaves are added to a wrapped tree through the ensurer, and that
+/// mutations through `graph_mut` are checked.
+#[test]
+fn wrapped_tree()
+{
+	let mut g = MockGraph::<Directed>::empty();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+	let props = DynProperty::Tree | DynProperty::Unique;
+	let mut tree = DynEnsuredGraph::ensure(TreeGraph::guard(g.clone()).unwrap(), props).unwrap();
+
+	let leaf = NewLeafDirected::new_leaf(&mut tree, v2, false).unwrap();
+	assert_eq!(tree.edges_between(leaf, v2).count(), 1);
+	assert!(NewLeafUndirected::new_leaf(&mut tree, leaf).is_ok());
+	assert_eq!(tree.all_vertices().count(), 4);
+
+	let mut g = DynEnsuredGraph::ensure(g, props).unwrap();
+	assert!(matches!(
+		g.graph_mut().remove_edge(v1, v2),
+		Err(GraphError::Rejected(_))
+	));
+	assert!(matches!(
+		g.graph_mut().new_vertex(),
+		Err(GraphError::Rejected(_))
+	));
+	assert_eq!(g.all_vertices().count(), 2);
+}
+
+assert_impl_all!(DynEnsuredGraph<TreeGraph<MockGraph<Directed>>>:
+	NewLeafUndirected, NewLeafDirected, GraphDerefMut);
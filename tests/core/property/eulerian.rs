@@ -0,0 +1,101 @@
+//! Tests the `core::property::SemiEulerian` and `core::property::Eulerian`
+//! traits and their ensurers
+
+use crate::mock_graph::{arbitrary::Arb, MockEdgeWeight, MockGraph};
+use duplicate::duplicate_item;
+use graphene::core::{
+	property::{
+		AddEdge, Eulerian, EulerianGraph, RemoveEdge, RemoveVertex, SemiEulerian, SemiEulerianGraph,
+	},
+	Directed, Graph, Guard, Undirected,
+};
+use static_assertions::assert_impl_all;
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that every Eulerian graph is also semi-Eulerian.
+	#[quickcheck]
+	fn eulerian_is_semi_eulerian(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		!EulerianGraph::can_guard(&g) || SemiEulerianGraph::can_guard(&g)
+	}
+
+	#[duplicate_item(
+		module EnsurerGraph; [semi_eulerian] [SemiEulerianGraph]; [eulerian] [EulerianGraph];
+	)]
+	mod module
+	{
+		use super::*;
+
+		/// Tests that an edge can be added exactly if the graph keeps the
+		/// property.
+		#[quickcheck]
+		fn add_edge(
+			Arb(g): Arb<MockGraph<directedness>>,
+			idx1: usize,
+			idx2: usize,
+			weight: MockEdgeWeight,
+		) -> bool
+		{
+			let vertices: Vec<_> = g.all_vertices().collect();
+			if vertices.is_empty() || !EnsurerGraph::can_guard(&g)
+			{
+				return true;
+			}
+			let v1 = vertices[idx1 % vertices.len()];
+			let v2 = vertices[idx2 % vertices.len()];
+			let mut expected = g.clone();
+			expected.add_edge_weighted(v1, v2, weight.clone()).unwrap();
+			let expected = EnsurerGraph::can_guard(&expected);
+
+			let mut g = EnsurerGraph::guard(g).unwrap();
+			g.add_edge_weighted(v1, v2, weight).is_ok() == expected
+		}
+
+		/// Tests that an edge can be removed exactly if the graph keeps the
+		/// property.
+		#[quickcheck]
+		fn remove_edge(Arb(g): Arb<MockGraph<directedness>>, idx: usize) -> bool
+		{
+			let edges: Vec<_> = g.all_edges().map(|(so, si, _)| (so, si)).collect();
+			if edges.is_empty() || !EnsurerGraph::can_guard(&g)
+			{
+				return true;
+			}
+			let (so, si) = edges[idx % edges.len()];
+			let mut expected = g.clone();
+			expected.remove_edge(so, si).unwrap();
+			let expected = EnsurerGraph::can_guard(&expected);
+
+			let mut g = EnsurerGraph::guard(g).unwrap();
+			g.remove_edge(so, si).is_ok() == expected
+		}
+
+		/// Tests that a vertex can be removed exactly if the graph keeps the
+		/// property.
+		#[quickcheck]
+		fn remove_vertex(Arb(g): Arb<MockGraph<directedness>>, idx: usize) -> bool
+		{
+			let vertices: Vec<_> = g.all_vertices().collect();
+			if vertices.is_empty() || !EnsurerGraph::can_guard(&g)
+			{
+				return true;
+			}
+			let v = vertices[idx % vertices.len()];
+			let mut expected = g.clone();
+			expected.remove_vertex(v).unwrap();
+			let expected = EnsurerGraph::can_guard(&expected);
+
+			let mut g = EnsurerGraph::guard(g).unwrap();
+			g.remove_vertex(v).is_ok() == expected
+		}
+	}
+
+	assert_impl_all!(SemiEulerianGraph<MockGraph<directedness>>: SemiEulerian);
+	assert_impl_all!(EulerianGraph<MockGraph<directedness>>: Eulerian, SemiEulerian);
+}
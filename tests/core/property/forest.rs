@@ -0,0 +1,179 @@
+//! Tests the `core::property::Forest` trait and its ensurer
+
+use crate::mock_graph::{
+	arbitrary::{Arb, CyclicGraph},
+	MockEdgeWeight, MockGraph,
+};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			Acyclic, AddEdge, Forest, ForestGraph, NewVertex, NoLoops, RemoveEdge, TreeGraph,
+			Unique,
+		},
+		Directed, Directedness, Graph, GraphError, Guard, Release, Undirected, Violation,
+	},
+};
+use static_assertions::assert_impl_all;
+
+/// Returns a forest with the vertices of the given graph and the edges that
+/// can be added to it in order.
+fn spanning_forest<D: Directedness>(g: &MockGraph<D>) -> ForestGraph<MockGraph<D>>
+{
+	let mut edgeless = g.clone();
+	let edges: Vec<_> = g.all_edges().map(|(so, si, _)| (so, si)).collect();
+	for (so, si) in edges
+	{
+		edgeless.remove_edge(so, si).unwrap();
+	}
+
+	let mut forest = ForestGraph::guard(edgeless).unwrap();
+	for (so, si, w) in g.all_edges()
+	{
+		let _ = forest.add_edge_weighted(so, si, w.clone());
+	}
+	forest
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that ForestGraph accepts trees.
+	#[quickcheck]
+	fn accept_tree(g: Arb<TreeGraph<MockGraph<directedness>>>) -> bool
+	{
+		ForestGraph::can_guard(&g.0.release_all())
+	}
+
+	/// Tests that ForestGraph rejects cyclic graphs.
+	#[quickcheck]
+	fn reject_cyclic(g: Arb<CyclicGraph<directedness, MockEdgeWeight>>) -> bool
+	{
+		!ForestGraph::can_guard(&g.0)
+	}
+
+	/// Tests that adding edges that are accepted results in a forest.
+	#[quickcheck]
+	fn spanning(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		ForestGraph::can_guard(&spanning_forest(&g).release_all())
+	}
+
+	/// Tests that the trees of a forest are valid trees that together contain
+	/// all vertices and edges once.
+	#[quickcheck]
+	fn trees(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let forest = spanning_forest(&g);
+		let trees: Vec<_> = forest.trees().collect();
+
+		trees
+			.iter()
+			.map(|t| t.all_vertices().count())
+			.sum::<usize>()
+			== forest.all_vertices().count()
+			&& trees.iter().map(|t| t.all_edges().count()).sum::<usize>()
+				== forest.all_edges().count()
+			&& trees
+				.into_iter()
+				.all(|tree| TreeGraph::can_guard(&tree.release_all()))
+	}
+
+	/// Tests that the tree containing a vertex is the one returned by `trees`,
+	/// and that both ends of every edge are in the same tree.
+	#[quickcheck]
+	fn component_of(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let forest = spanning_forest(&g);
+
+		forest.trees().all(|tree| {
+			tree.all_vertices().all(|v| {
+				let component = forest.component_of(v).unwrap();
+				component.all_vertices().count() == tree.all_vertices().count()
+					&& tree.all_vertices().all(|v| component.contains_vertex(v))
+			})
+		}) && forest
+			.all_edges()
+			.all(|(so, si, _)| forest.component_of(so).unwrap().contains_vertex(si))
+	}
+
+	/// Tests that an edge is added exactly if the graph is still a forest
+	/// afterwards, which in undirected graphs means it joins two trees.
+	#[quickcheck]
+	fn add_edge(
+		Arb(g): Arb<MockGraph<directedness>>,
+		idx1: usize,
+		idx2: usize,
+		weight: MockEdgeWeight,
+	) -> bool
+	{
+		let mut forest = spanning_forest(&g);
+		let vertices: Vec<_> = forest.all_vertices().collect();
+		if vertices.is_empty()
+		{
+			return true;
+		}
+		let v1 = vertices[idx1 % vertices.len()];
+		let v2 = vertices[idx2 % vertices.len()];
+		let joins = !forest.component_of(v1).unwrap().contains_vertex(v2);
+		let tree_count = forest.trees().count();
+		let mut expected = forest.clone().release_all();
+		expected.add_edge_weighted(v1, v2, weight.clone()).unwrap();
+		let accepted = ForestGraph::can_guard(&expected);
+
+		forest.add_edge_weighted(v1, v2, weight).is_ok() == accepted
+			&& (directedness::directed() || accepted == joins)
+			&& forest.trees().count() == tree_count - (accepted && joins) as usize
+			&& ForestGraph::can_guard(&forest.release_all())
+	}
+
+	assert_impl_all!(ForestGraph<MockGraph<directedness>>: Forest, Acyclic, NoLoops, Unique);
+	assert_impl_all!(TreeGraph<MockGraph<directedness>>: Forest);
+}
+
+/// Returns the violation an ensurer rejected a mutation for.
+fn violation<V>(result: Result<(), GraphError<V>>) -> Violation<V>
+{
+	match result
+	{
+		Err(GraphError::Rejected(err)) => err.violation,
+		_ => panic!("the mutation should be rejected"),
+	}
+}
+
+/// Tests that trees can be joined and split, and that a vertex's tree follows,
+/// and the reasons for rejecting edges.
+#[test]
+fn join_and_split()
+{
+	let mut g = ForestGraph::guard(AdjListGraph::<(), (), Undirected>::new()).unwrap();
+	let vs: Vec<_> = (0..4).map(|_| g.new_vertex().unwrap()).collect();
+	assert_eq!(g.trees().count(), 4);
+
+	g.add_edge(vs[0], vs[1]).unwrap();
+	g.add_edge(vs[2], vs[3]).unwrap();
+	assert_eq!(g.trees().count(), 2);
+	assert_eq!(
+		violation(g.add_edge(vs[1], vs[0])),
+		Violation::DuplicateEdge(vs[1], vs[0])
+	);
+	assert_eq!(violation(g.add_edge(vs[0], vs[0])), Violation::Loop(vs[0]));
+
+	g.add_edge(vs[1], vs[2]).unwrap();
+	assert_eq!(g.trees().count(), 1);
+	assert_eq!(g.component_of(vs[0]).unwrap().all_vertices().count(), 4);
+	assert_eq!(
+		violation(g.add_edge(vs[0], vs[3])),
+		Violation::Cycle(vec![vs[0], vs[3], vs[2], vs[1]])
+	);
+
+	g.remove_edge(vs[1], vs[2]).unwrap();
+	assert_eq!(g.trees().count(), 2);
+	assert!(!g.component_of(vs[0]).unwrap().contains_vertex(vs[3]));
+	assert!(g.component_of(4).is_none());
+}
@@ -1,13 +1,14 @@
-/// Tests `HasVertexGraph`, VertexInGraph`, and `RootedGraph`
+/// Tests `HasVertexGraph`, VertexInGraph`, `VerticesInGraph`, and `RootedGraph`
 use crate::mock_graph::{arbitrary::Arb, MockGraph, MockVertexWeight};
 use duplicate::duplicate_item;
 use graphene::core::{
 	property::{
 		HasVertex, HasVertexGraph, NewVertex, RemoveVertex, Rooted, RootedGraph, RootedMut,
-		VertexIn, VertexInGraph,
+		UniqueGraph, VertexIn, VertexInGraph, VerticesIn, VerticesInGraph,
 	},
-	Directed, Undirected,
+	Directed, GraphError, Undirected, Violation,
 };
+use static_assertions::assert_impl_all;
 
 #[duplicate_item(
 	directedness; [ Directed ]; [ Undirected ]
@@ -134,7 +135,10 @@ mod __
 			let v = g.any_vertex();
 			let mut g = GraphStruct::ensure_unchecked(g, ensure_wrap([v]));
 
-			g.remove_vertex(v).is_err()
+			matches!(
+				g.remove_vertex(v),
+				Err(GraphError::Rejected(err)) if err.violation == Violation::Designated(v)
+			)
 		}
 
 		/// Tests the graph can get the underlying vertex
@@ -174,6 +178,119 @@ mod __
 		}
 	}
 
+	mod vertices_in
+	{
+		use super::*;
+		use crate::mock_graph::{arbitrary::VertexOutside, MockVertex};
+		use graphene::core::{Ensure, Graph, Release};
+
+		/// Picks vertices from the graph using the given indices.
+		fn pick(g: &MockGraph<directedness>, idxs: &[usize]) -> Vec<MockVertex>
+		{
+			let vertices: Vec<_> = g.all_vertices().collect();
+			idxs.iter()
+				.map(|idx| vertices[idx % vertices.len()])
+				.collect()
+		}
+
+		/// Tests that any number of vertices in the graph are accepted.
+		#[quickcheck]
+		fn accept_in_graph(
+			Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>,
+			idxs: Vec<usize>,
+		) -> bool
+		{
+			let g = g.release_all();
+			let vs = pick(&g, &idxs);
+			VerticesInGraph::can_ensure(&g, &vs)
+		}
+
+		/// Tests that a vertex not in the graph is rejected among others that
+		/// are.
+		#[quickcheck]
+		fn reject_not_in_graph(
+			Arb(g): Arb<VertexOutside<VertexInGraph<MockGraph<directedness>>>>,
+			idxs: Vec<usize>,
+		) -> bool
+		{
+			let outside = g.1;
+			let g = g.0.release_all();
+			let mut vs = pick(&g, &idxs);
+			vs.insert(idxs.len() / 2, outside);
+			!VerticesInGraph::can_ensure(&g, &vs)
+		}
+
+		/// Tests that the vertices are accessible by index and in order.
+		#[quickcheck]
+		fn get_vertices(
+			Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>,
+			idxs: Vec<usize>,
+		) -> bool
+		{
+			let g = g.release_all();
+			let vs = pick(&g, &idxs);
+			let g = VerticesInGraph::ensure(g, vs.clone()).unwrap();
+
+			g.designated_count() == vs.len()
+				&& (0..vs.len()).all(|idx| g.vertex_at_idx(idx) == vs[idx])
+				&& g.designated_vertices().eq(vs.iter().copied())
+				&& g.vertices() == vs.as_slice()
+		}
+
+		/// Tests that exactly the designated vertices cannot be removed.
+		#[quickcheck]
+		fn remove_vertex(
+			Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>,
+			idxs: Vec<usize>,
+			idx: usize,
+		) -> bool
+		{
+			let g = g.release_all();
+			let vs = pick(&g, &idxs);
+			let v = pick(&g, &[idx])[0];
+			let vertex_count = g.all_vertices().count();
+			let mut g = VerticesInGraph::ensure(g, vs.clone()).unwrap();
+
+			let result = g.remove_vertex(v);
+			let removed = g.all_vertices().count() != vertex_count;
+			if vs.contains(&v)
+			{
+				!removed
+					&& matches!(
+						result,
+						Err(GraphError::Rejected(err)) if err.violation == Violation::Designated(v)
+					)
+			}
+			else
+			{
+				removed && result.is_ok()
+			}
+		}
+
+		/// Tests that the designated vertices can only be changed to vertices
+		/// in the graph.
+		#[quickcheck]
+		fn set_vertices(
+			Arb(g): Arb<VertexOutside<VertexInGraph<MockGraph<directedness>>>>,
+			idxs: Vec<usize>,
+		) -> bool
+		{
+			let outside = g.1;
+			let g = g.0.release_all();
+			let vs = pick(&g, &idxs);
+			let mut g = VerticesInGraph::ensure(g, Vec::new()).unwrap();
+
+			g.set_vertices(vs.iter().copied()).is_ok()
+				&& g.push_vertex(outside).is_err()
+				&& g.set_vertices(vs.iter().copied().chain([outside])).is_err()
+				&& g.vertices() == vs.as_slice()
+				&& (vs.is_empty() || g.push_vertex(vs[0]).is_ok())
+				&& g.designated_count() == vs.len() + !vs.is_empty() as usize
+		}
+
+		assert_impl_all!(UniqueGraph<VerticesInGraph<MockGraph<directedness>>>: VerticesIn);
+	}
+
 	/// Tests that RootedGraphs `is_root` returns true if given the root
 	#[quickcheck]
 	fn is_root_true(Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
@@ -0,0 +1,196 @@
+//! Tests the `core::property::KConnected` and `core::property::KEdgeConnected`
+//! traits and their ensurers
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, Biconnected, BiconnectedGraph, Connected, ConnectedGraph, KConnected,
+			KConnectedGraph, KEdgeConnected, KEdgeConnectedGraph, NewVertex, RemoveEdge,
+			RemoveVertex,
+		},
+		Directed, Graph, Guard, Undirected, Violation,
+	},
+};
+use static_assertions::assert_impl_all;
+
+/// Returns a cycle with the given number of vertices.
+fn cycle(vertex_count: usize) -> AdjListGraph<(), (), Undirected>
+{
+	let mut g = AdjListGraph::new();
+	let vs: Vec<_> = (0..vertex_count).map(|_| g.new_vertex().unwrap()).collect();
+	for (idx, v) in vs.iter().enumerate()
+	{
+		g.add_edge(v, vs[(idx + 1) % vertex_count]).unwrap();
+	}
+	g
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that 1-connected graphs are exactly the connected graphs.
+	#[quickcheck]
+	fn one_connected(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let connected = ConnectedGraph::can_guard(&g);
+		KConnectedGraph::<_, 1>::can_guard(&g) == connected
+			&& KEdgeConnectedGraph::<_, 1>::can_guard(&g) == connected
+	}
+
+	/// Tests that BiconnectedGraph accepts connected graphs that stay
+	/// connected without any one vertex.
+	#[quickcheck]
+	fn biconnected(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let expected = ConnectedGraph::can_guard(&g)
+			&& g.all_vertices().all(|v| {
+				let mut g = g.clone();
+				g.remove_vertex(v).unwrap();
+				ConnectedGraph::can_guard(&g)
+			});
+
+		BiconnectedGraph::can_guard(&g) == expected
+	}
+
+	/// Tests that 2-edge-connected graphs are the connected graphs that stay
+	/// connected without any one edge.
+	#[quickcheck]
+	fn two_edge_connected(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let expected = ConnectedGraph::can_guard(&g)
+			&& g.all_edges().all(|(so, si, _)| {
+				let mut g = g.clone();
+				g.remove_edge(so, si).unwrap();
+				ConnectedGraph::can_guard(&g)
+			});
+
+		KEdgeConnectedGraph::<_, 2>::can_guard(&g) == expected
+	}
+
+	assert_impl_all!(BiconnectedGraph<MockGraph<directedness>>:
+		Biconnected, KConnected<2>, Connected);
+	assert_impl_all!(KEdgeConnectedGraph<BiconnectedGraph<MockGraph<directedness>>, 2>:
+		Biconnected, KEdgeConnected<2>, Connected);
+	assert_impl_all!(KConnectedGraph<MockGraph<directedness>, 3>:
+		Biconnected, KConnected<3>, KConnected<2>, KConnected<1>);
+	assert_impl_all!(KEdgeConnectedGraph<KConnectedGraph<MockGraph<directedness>, 4>, 3>:
+		KConnected<3>, KEdgeConnected<3>, KEdgeConnected<2>, KEdgeConnected<1>);
+}
+
+/// Tests that a cycle is biconnected but not 3-connected, and that no vertex
+/// or edge can be removed from it.
+#[test]
+fn cycle_graph()
+{
+	let g = cycle(5);
+	assert!(!KConnectedGraph::<_, 3>::can_guard(&g));
+	assert!(!KEdgeConnectedGraph::<_, 3>::can_guard(&g));
+
+	let mut g = BiconnectedGraph::guard(cycle(5)).unwrap();
+	assert!(g.remove_vertex(0).is_err());
+	assert!(g.add_edge(0, 2).is_ok());
+	assert!(g.remove_vertex(1).is_ok());
+	assert_eq!(g.all_vertices().count(), 4);
+
+	let mut g = KEdgeConnectedGraph::<_, 2>::guard(cycle(5)).unwrap();
+	assert!(g.remove_edge(0, 1).is_err());
+	assert_eq!(g.all_edges().count(), 5);
+}
+
+/// Tests that biconnectivity and 2-edge-connectivity are checked in linear
+/// time, which a check of every vertex or edge wouldn't do for cycles this
+/// large.
+#[test]
+fn large_cycle()
+{
+	let g = cycle(500);
+	assert!(BiconnectedGraph::can_guard(&g));
+	assert!(KEdgeConnectedGraph::<_, 2>::can_guard(&g));
+
+	let mut g = BiconnectedGraph::guard(g).unwrap();
+	assert!(g.add_edge(0, 250).is_ok());
+	assert!(g.remove_edge(0, 1).is_err());
+	assert!(g.remove_vertex(1).is_err());
+	assert!(g.remove_edge(0, 250).is_ok());
+
+	// A directed cycle becomes a path without any of its vertices
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let vs: Vec<_> = (0..500).map(|_| g.new_vertex().unwrap()).collect();
+	for (idx, v) in vs.iter().enumerate()
+	{
+		g.add_edge(v, vs[(idx + 1) % vs.len()]).unwrap();
+	}
+	assert!(!BiconnectedGraph::can_guard(&g));
+	assert!(!KEdgeConnectedGraph::<_, 2>::can_guard(&g));
+	g.add_edge(vs[1], vs[0]).unwrap();
+	assert!(KConnectedGraph::<_, 1>::can_guard(&g));
+	assert!(!BiconnectedGraph::can_guard(&g));
+}
+
+/// Tests that the complete graph with 4 vertices is 3-edge-connected, but not
+/// 4-edge-connected, and that it can't lose edges while 3-connected.
+///
+/// It is 4-connected, as removing any 3 vertices leaves a single vertex.
+#[test]
+fn complete_graph()
+{
+	let mut g = cycle(4);
+	g.add_edge(0, 2).unwrap();
+	g.add_edge(1, 3).unwrap();
+	assert!(KConnectedGraph::<_, 4>::can_guard(&g));
+	assert!(!KEdgeConnectedGraph::<_, 4>::can_guard(&g));
+
+	let mut three = KConnectedGraph::<_, 3>::guard(g.clone()).unwrap();
+	assert!(three.remove_edge(0, 1).is_err());
+	let mut three_edge = KEdgeConnectedGraph::<_, 3>::guard(g.clone()).unwrap();
+	assert!(three_edge.remove_edge(0, 1).is_err());
+	let mut two_edge = KEdgeConnectedGraph::<_, 2>::guard(g).unwrap();
+	assert!(two_edge.remove_edge(0, 1).is_ok());
+}
+
+/// Tests that a single edge is biconnected, as removing either vertex leaves
+/// a single vertex, but not 2-edge-connected.
+#[test]
+fn single_edge()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+
+	assert!(BiconnectedGraph::can_guard(&g));
+	assert!(!KEdgeConnectedGraph::<_, 2>::can_guard(&g));
+	g.add_edge(v1, v2).unwrap();
+	assert!(KEdgeConnectedGraph::<_, 2>::can_guard(&g));
+}
+
+/// Tests that rejections give the vertices that can't reach each other once
+/// vertices or edges are removed.
+#[test]
+fn unreachable_witness()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let vs: Vec<_> = (0..3).map(|_| g.new_vertex().unwrap()).collect();
+	g.add_edge(vs[0], vs[1]).unwrap();
+	g.add_edge(vs[1], vs[2]).unwrap();
+
+	assert_eq!(
+		BiconnectedGraph::guard(g.clone())
+			.err()
+			.map(|e| e.violation),
+		Some(Violation::Unreachable(vs[0], vs[2]))
+	);
+	assert_eq!(
+		KEdgeConnectedGraph::<_, 2>::guard(g)
+			.err()
+			.map(|e| e.violation),
+		Some(Violation::Unreachable(vs[0], vs[1]))
+	);
+}
@@ -0,0 +1,123 @@
+//! Tests the `core::property::MaxOutDegree`, `core::property::MaxInDegree`
+//! and `core::property::MaxDegree` traits and their ensurers, and binary trees
+
+use crate::mock_graph::{arbitrary::Arb, MockEdgeWeight, MockGraph};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, BinaryTree, BinaryTreeGraph, MaxDegree, MaxDegreeGraph, MaxInDegree,
+			MaxInDegreeGraph, MaxOutDegree, MaxOutDegreeGraph, NewLeafDirected, NewVertex,
+			RootedGraph, TreeGraph,
+		},
+		Directed, Ensure, Graph, Guard, Release, Undirected,
+	},
+};
+use static_assertions::assert_impl_all;
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	#[duplicate_item(
+		module EnsurerGraph degree;
+		[max_out_degree] [MaxOutDegreeGraph] [edges_sourced_in];
+		[max_in_degree] [MaxInDegreeGraph] [edges_sinked_in];
+		[max_degree] [MaxDegreeGraph] [edges_incident_on];
+	)]
+	mod module
+	{
+		use super::*;
+
+		/// Returns whether no vertex has more than 2 of the counted edges.
+		fn within_bound(g: &MockGraph<directedness>) -> bool
+		{
+			g.all_vertices().all(|v| g.degree(v).count() <= 2)
+		}
+
+		/// Tests that graphs are accepted exactly if they are within the bound.
+		#[quickcheck]
+		fn accept(Arb(g): Arb<MockGraph<directedness>>) -> bool
+		{
+			EnsurerGraph::<_, 2>::can_guard(&g) == within_bound(&g)
+		}
+
+		/// Tests that an edge can be added exactly if the graph stays within
+		/// the bound.
+		#[quickcheck]
+		fn add_edge(
+			Arb(g): Arb<MockGraph<directedness>>,
+			idx1: usize,
+			idx2: usize,
+			weight: MockEdgeWeight,
+		) -> bool
+		{
+			let vertices: Vec<_> = g.all_vertices().collect();
+			if vertices.is_empty() || !within_bound(&g)
+			{
+				return true;
+			}
+			let v1 = vertices[idx1 % vertices.len()];
+			let v2 = vertices[idx2 % vertices.len()];
+			let mut expected = g.clone();
+			expected.add_edge_weighted(v1, v2, weight.clone()).unwrap();
+			let expected = within_bound(&expected);
+
+			let mut g = EnsurerGraph::<_, 2>::guard(g).unwrap();
+			g.add_edge_weighted(v1, v2, weight).is_ok() == expected
+				&& EnsurerGraph::<_, 2>::can_guard(&g.release())
+		}
+	}
+
+	assert_impl_all!(MaxOutDegreeGraph<MockGraph<directedness>, 2>: MaxOutDegree<2>);
+	assert_impl_all!(MaxInDegreeGraph<MockGraph<directedness>, 2>: MaxInDegree<2>);
+	assert_impl_all!(MaxDegreeGraph<MockGraph<directedness>, 2>: MaxDegree<2>);
+}
+
+/// Tests that a binary tree gets at most two children per vertex, and that
+/// they are its left and right children.
+#[test]
+fn binary_tree()
+{
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let root = g.new_vertex().unwrap();
+	let tree =
+		MaxInDegreeGraph::guard(MaxOutDegreeGraph::guard(TreeGraph::guard(g).unwrap()).unwrap())
+			.unwrap();
+	let mut tree: BinaryTreeGraph<_> = RootedGraph::ensure(tree, root).unwrap();
+	assert_eq!(tree.left(root), None);
+
+	let left = tree.new_leaf(root, true).unwrap();
+	assert_eq!(tree.left(root), Some(left));
+	assert_eq!(tree.right(root), None);
+	let right = tree.new_leaf(root, true).unwrap();
+	assert_eq!(tree.right(root), Some(right));
+	assert!(tree.new_leaf(root, true).is_err());
+
+	// A leaf can't have another parent
+	assert!(tree.new_leaf(left, false).is_err());
+	let left_left = tree.new_leaf(left, true).unwrap();
+	assert_eq!(tree.left(left), Some(left_left));
+	assert_eq!(tree.left(left_left), None);
+	assert_eq!(tree.all_vertices().count(), 4);
+}
+
+/// Tests that MaxDegreeGraph counts both ends of undirected edges, including
+/// loops.
+#[test]
+fn undirected_degree()
+{
+	let mut g = MaxDegreeGraph::<_, 2>::guard(AdjListGraph::<(), (), Undirected>::new()).unwrap();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	let v3 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+	g.add_edge(v2, v3).unwrap();
+	assert!(g.add_edge(v2, v1).is_err());
+	assert!(g.add_edge(v3, v1).is_ok());
+	assert!(g.add_edge(v1, v1).is_err());
+}
@@ -1,7 +1,17 @@
 //! Tests the implementations of properties and ensurers in `core::property::*`;
 
 mod acyclic;
+mod bipartite;
 mod connectedness;
+mod dyn_ensured;
+mod eulerian;
+mod forest;
 mod has_vertex_rooted;
+mod k_connected;
+mod max_degree;
+mod non_negative_weights;
+mod planar;
+mod rooted_tree;
+mod topologically_ordered;
 mod tree;
 mod unique;
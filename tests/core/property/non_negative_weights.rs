@@ -0,0 +1,112 @@
+//! Tests the `core::property::NonNegativeWeights` trait and its ensurer
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex, NonNegativeWeights, NonNegativeWeightsGraph},
+		Directed, Directedness, Graph, GraphError, GraphMut, Guard, Undirected, Violation,
+	},
+};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use std::collections::HashMap;
+
+/// Copies the given graph, subtracting the given offset from every edge
+/// weight.
+fn signed_from_mock<D: Directedness>(mock: &MockGraph<D>, offset: u32) -> AdjListGraph<(), i64, D>
+{
+	let mut g = AdjListGraph::new();
+	let map: HashMap<_, _> = mock
+		.all_vertices()
+		.map(|v| (v, g.new_vertex().unwrap()))
+		.collect();
+	for (so, si, w) in mock.all_edges()
+	{
+		g.add_edge_weighted(map[&so], map[&si], w.value as i64 - offset as i64)
+			.unwrap();
+	}
+	g
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that a graph is accepted exactly if none of its weights are
+	/// negative.
+	#[quickcheck]
+	fn accept(Arb(mock): Arb<MockGraph<directedness>>, offset: u32) -> bool
+	{
+		let g = signed_from_mock(&mock, offset);
+		NonNegativeWeightsGraph::can_guard(&g)
+			== mock.all_edges().all(|(_, _, w)| w.value >= offset)
+	}
+
+	/// Tests that an edge can be added exactly if its weight isn't negative.
+	#[quickcheck]
+	fn add_edge(Arb(mock): Arb<MockGraph<directedness>>, weight: i64) -> bool
+	{
+		let g = signed_from_mock(&mock, 0);
+		let mut g = NonNegativeWeightsGraph::guard(g).unwrap();
+		let v = g.new_vertex().unwrap();
+		g.add_edge_weighted(v, v, weight).is_ok() == (weight >= 0)
+	}
+}
+
+/// Tests that floating point weights that aren't comparable to zero are
+/// rejected.
+#[test]
+fn reject_nan()
+{
+	let mut g = AdjListGraph::<(), f64, Directed>::new();
+	let v = g.new_vertex().unwrap();
+	g.add_edge_weighted(v, v, 0.5).unwrap();
+	let mut g = NonNegativeWeightsGraph::guard(g).unwrap();
+	assert!(g.add_edge_weighted(v, v, f64::NAN).is_err());
+	assert!(g.add_edge_weighted(v, v, -0.5).is_err());
+	assert!(g.add_edge_weighted(v, v, 0.0).is_ok());
+
+	let mut g = AdjListGraph::<(), f64, Undirected>::new();
+	let v = g.new_vertex().unwrap();
+	g.add_edge_weighted(v, v, f64::NAN).unwrap();
+	assert!(!NonNegativeWeightsGraph::can_guard(&g));
+}
+
+assert_impl_all!(AdjListGraph<(), u32, Directed>: NonNegativeWeights);
+assert_not_impl_any!(AdjListGraph<(), i32, Directed>: NonNegativeWeights);
+assert_impl_all!(NonNegativeWeightsGraph<AdjListGraph<(), i32, Directed>>: NonNegativeWeights);
+assert_impl_all!(NonNegativeWeightsGraph<AdjListGraph<(), u32, Directed>>: GraphMut);
+assert_not_impl_any!(NonNegativeWeightsGraph<AdjListGraph<(), i32, Directed>>: GraphMut);
+
+/// Tests that weights can be changed without `GraphMut`, with edge weights
+/// only to ones that aren't negative.
+#[test]
+fn set_weights()
+{
+	let mut g = AdjListGraph::<i32, i32, Directed>::new();
+	let v1 = g.new_vertex_weighted(-1).unwrap();
+	let v2 = g.new_vertex_weighted(-2).unwrap();
+	g.add_edge_weighted(v1, v2, 1).unwrap();
+	let mut g = NonNegativeWeightsGraph::guard(g).unwrap();
+
+	*g.vertex_weight_mut(v1).unwrap() = -3;
+	g.all_vertices_weighted_mut().for_each(|(_, w)| *w *= 2);
+	assert_eq!(g.vertex_weight(v1), Some(&-6));
+	assert_eq!(g.vertex_weight(v2), Some(&-4));
+
+	assert_eq!(g.set_edge_weight(v1, v2, 5), Ok(1));
+	match g.set_edge_weight(v1, v2, -1)
+	{
+		Err(GraphError::Rejected(err)) => assert_eq!(err.violation, Violation::EdgeWeight(v1, v2)),
+		_ => panic!("a negative weight should be rejected"),
+	}
+	assert_eq!(
+		g.set_edge_weight(v2, v1, 1),
+		Err(GraphError::MissingEdge(v2, v1))
+	);
+	assert!(g.edges_between(v1, v2).eq([&5]));
+}
@@ -0,0 +1,314 @@
+//! Tests the `core::property::Planar` trait and its ensurer
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, NewLeafDirected, NewLeafUndirected, NewVertex, Planar, PlanarGraph,
+			RemoveEdge, RemoveVertex, TreeGraph,
+		},
+		Directed, Directedness, Graph, Guard, Undirected,
+	},
+};
+use static_assertions::assert_impl_all;
+use std::{collections::HashSet, hash::Hash};
+
+/// Returns whether the graph's embedding is a valid planar embedding.
+///
+/// The clockwise neighbors of each vertex must be exactly its neighbors, and
+/// the faces traced using the embedding must satisfy Euler's formula.
+fn valid_embedding<G: Planar>(g: &G) -> bool
+where
+	G::Vertex: Hash,
+{
+	let vertices: Vec<_> = g.all_vertices().collect();
+	let embedding: Vec<Vec<_>> = vertices
+		.iter()
+		.map(|v| g.clockwise_neighbors(v).collect())
+		.collect();
+	let idx = |v: G::Vertex| vertices.iter().position(|u| *u == v).unwrap();
+
+	for (v, clockwise) in vertices.iter().zip(embedding.iter())
+	{
+		let mut neighbors = Vec::new();
+		for (n, _) in g.edges_incident_on(v)
+		{
+			if n != *v && !neighbors.contains(&n)
+			{
+				neighbors.push(n);
+			}
+		}
+		if clockwise.len() != neighbors.len() || !neighbors.iter().all(|n| clockwise.contains(n))
+		{
+			return false;
+		}
+	}
+
+	// Trace each face by always turning to the next neighbor around the vertex
+	// arrived at
+	let mut traced = HashSet::new();
+	let mut face_count = 0;
+	for (v, clockwise) in vertices.iter().zip(embedding.iter())
+	{
+		for n in clockwise
+		{
+			if traced.contains(&(*v, *n))
+			{
+				continue;
+			}
+			face_count += 1;
+			let (mut from, mut to) = (*v, *n);
+			while traced.insert((from, to))
+			{
+				let around = &embedding[idx(to)];
+				let pos = around.iter().position(|u| *u == from).unwrap();
+				(from, to) = (to, around[(pos + 1) % around.len()]);
+			}
+		}
+	}
+
+	// Each component with edges has an outer face, isolated vertices have none
+	let mut component = vec![usize::MAX; vertices.len()];
+	let (mut edged_components, mut isolated) = (0, 0);
+	for start in 0..vertices.len()
+	{
+		if component[start] != usize::MAX
+		{
+			continue;
+		}
+		if embedding[start].is_empty()
+		{
+			isolated += 1;
+		}
+		else
+		{
+			edged_components += 1;
+		}
+		component[start] = start;
+		let mut stack = vec![start];
+		while let Some(v) = stack.pop()
+		{
+			for n in embedding[v].iter().map(|n| idx(*n))
+			{
+				if component[n] == usize::MAX
+				{
+					component[n] = start;
+					stack.push(n);
+				}
+			}
+		}
+	}
+
+	vertices.len() + face_count == traced.len() / 2 + 2 * edged_components + isolated
+}
+
+/// Returns a planar graph with the vertices of the given graph, adding as many
+/// of its edges as possible.
+fn planar_subgraph<D: Directedness>(g: &MockGraph<D>) -> PlanarGraph<MockGraph<D>>
+{
+	let mut empty = g.clone();
+	let edges: Vec<_> = g
+		.all_edges()
+		.map(|(so, si, w)| (so, si, w.clone()))
+		.collect();
+	for (so, si, _) in edges.iter()
+	{
+		empty.remove_edge(so, si).unwrap();
+	}
+	let mut planar = PlanarGraph::guard(empty).unwrap();
+	for (so, si, w) in edges
+	{
+		let _ = planar.add_edge_weighted(so, si, w);
+	}
+	planar
+}
+
+/// Returns the complete graph with the given number of vertices.
+fn complete(vertex_count: usize) -> AdjListGraph<(), ()>
+{
+	let mut g = AdjListGraph::new();
+	let vs: Vec<_> = (0..vertex_count).map(|_| g.new_vertex().unwrap()).collect();
+	for (idx, v1) in vs.iter().enumerate()
+	{
+		for v2 in vs[idx + 1..].iter()
+		{
+			g.add_edge(v1, v2).unwrap();
+		}
+	}
+	g
+}
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that PlanarGraph finds a valid embedding for the graphs it
+	/// accepts.
+	#[quickcheck]
+	fn accept_planar(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		!PlanarGraph::can_guard(&g) || valid_embedding(&PlanarGraph::guard(g).unwrap())
+	}
+
+	/// Tests that adding the edges of a planar graph one by one never fails,
+	/// and that adding edges keeps the embedding valid.
+	#[quickcheck]
+	fn add_edge(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let planar = planar_subgraph(&g);
+
+		valid_embedding(&planar)
+			&& (!PlanarGraph::can_guard(&g) || planar.all_edges().count() == g.all_edges().count())
+	}
+
+	/// Tests that removing a vertex keeps the embedding valid.
+	#[quickcheck]
+	fn remove_vertex(Arb(g): Arb<MockGraph<directedness>>, idx: usize) -> bool
+	{
+		let mut g = planar_subgraph(&g);
+		let v = g.all_vertices().nth(idx % (g.all_vertices().count() + 1));
+		if let Some(v) = v
+		{
+			g.remove_vertex(v).unwrap();
+		}
+		valid_embedding(&g)
+	}
+
+	/// Tests that removing an edge keeps the embedding valid.
+	#[quickcheck]
+	fn remove_edge(Arb(g): Arb<MockGraph<directedness>>, idx: usize) -> bool
+	{
+		let mut g = planar_subgraph(&g);
+		let edge = g
+			.all_edges()
+			.nth(idx % (g.all_edges().count() + 1))
+			.map(|(so, si, _)| (so, si));
+		if let Some((so, si)) = edge
+		{
+			g.remove_edge(so, si).unwrap();
+		}
+		valid_embedding(&g)
+	}
+
+	assert_impl_all!(PlanarGraph<MockGraph<directedness>>: Planar);
+}
+
+/// Tests that the complete graphs are planar up to 4 vertices.
+#[test]
+fn complete_graphs()
+{
+	for vertex_count in 0..5
+	{
+		let g = PlanarGraph::guard(complete(vertex_count)).unwrap();
+		assert!(valid_embedding(&g));
+	}
+	assert!(!PlanarGraph::can_guard(&complete(5)));
+	assert!(!PlanarGraph::can_guard(&complete(6)));
+}
+
+/// Tests that the utility graph, K3,3, isn't planar.
+#[test]
+fn utility_graph()
+{
+	let mut g = AdjListGraph::<(), ()>::new();
+	let vs: Vec<_> = (0..6).map(|_| g.new_vertex().unwrap()).collect();
+	for house in vs[..3].iter()
+	{
+		for utility in vs[3..].iter()
+		{
+			g.add_edge(house, utility).unwrap();
+		}
+	}
+	assert!(!PlanarGraph::can_guard(&g));
+
+	g.remove_edge(vs[0], vs[3]).unwrap();
+	let mut g = PlanarGraph::guard(g).unwrap();
+	assert!(valid_embedding(&g));
+	assert!(g.add_edge(vs[0], vs[3]).is_err());
+	assert_eq!(g.all_edges().count(), 8);
+}
+
+/// Tests that the Petersen graph, which has few edges, isn't planar.
+#[test]
+fn petersen_graph()
+{
+	let mut g = AdjListGraph::<(), ()>::new();
+	let vs: Vec<_> = (0..10).map(|_| g.new_vertex().unwrap()).collect();
+	for i in 0..5
+	{
+		g.add_edge(vs[i], vs[(i + 1) % 5]).unwrap();
+		g.add_edge(vs[i], vs[i + 5]).unwrap();
+		g.add_edge(vs[i + 5], vs[(i + 2) % 5 + 5]).unwrap();
+	}
+	assert!(!PlanarGraph::can_guard(&g));
+}
+
+/// Tests that edges between vertices that are already neighbors, and loops,
+/// are always accepted.
+#[test]
+fn add_parallel_edge()
+{
+	let mut g = complete(5);
+	let vs: Vec<_> = g.all_vertices().collect();
+	g.remove_edge(vs[0], vs[1]).unwrap();
+	let mut g = PlanarGraph::guard(g).unwrap();
+
+	assert!(g.add_edge(vs[0], vs[1]).is_err());
+	assert!(g.add_edge(vs[0], vs[2]).is_ok());
+	assert!(g.add_edge(vs[3], vs[3]).is_ok());
+	assert!(valid_embedding(&g));
+	assert_eq!(g.clockwise_neighbors(vs[0]).count(), 3);
+}
+
+/// Tests the embedding of a wheel, where the rim must be in the same order
+/// around the hub as along the rim.
+#[test]
+fn wheel()
+{
+	let mut g = AdjListGraph::<(), ()>::new();
+	let hub = g.new_vertex().unwrap();
+	let rim: Vec<_> = (0..6).map(|_| g.new_vertex().unwrap()).collect();
+	for (idx, v) in rim.iter().enumerate()
+	{
+		g.add_edge(hub, v).unwrap();
+		g.add_edge(v, rim[(idx + 1) % rim.len()]).unwrap();
+	}
+	let g = PlanarGraph::guard(g).unwrap();
+	assert!(valid_embedding(&g));
+
+	let around: Vec<_> = g.clockwise_neighbors(hub).collect();
+	let start = rim.iter().position(|v| *v == around[0]).unwrap();
+	let forward = (0..6).all(|i| around[i] == rim[(start + i) % 6]);
+	let backward = (0..6).all(|i| around[i] == rim[(start + 6 - i) % 6]);
+	assert!(forward || backward);
+}
+
+/// Tests that new leaves of a planar tree are added to the embedding.
+#[test]
+fn new_leaf()
+{
+	let mut g = AdjListGraph::<(), ()>::new();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+	let mut g = PlanarGraph::guard(TreeGraph::guard(g).unwrap()).unwrap();
+
+	let leaf = NewLeafUndirected::new_leaf(&mut g, v1).unwrap();
+	let leaf2 = NewLeafUndirected::new_leaf(&mut g, leaf).unwrap();
+	assert!(valid_embedding(&g));
+	assert_eq!(g.clockwise_neighbors(v1).count(), 2);
+	assert_eq!(g.clockwise_neighbors(leaf2).collect::<Vec<_>>(), [leaf]);
+
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let v1 = g.new_vertex().unwrap();
+	let mut g = PlanarGraph::guard(TreeGraph::guard(g).unwrap()).unwrap();
+	let leaf = NewLeafDirected::new_leaf(&mut g, v1, false).unwrap();
+	assert!(NewLeafUndirected::new_leaf(&mut g, v1).is_ok());
+	assert!(valid_embedding(&g));
+	assert_eq!(g.clockwise_neighbors(leaf).collect::<Vec<_>>(), [v1]);
+}
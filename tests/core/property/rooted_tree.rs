@@ -0,0 +1,168 @@
+//! Tests the `core::property::RootedTree` trait
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph, MockVertex};
+use duplicate::duplicate_item;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AddEdge, NewLeafDirected, NewLeafUndirected, NewVertex, Rooted, RootedGraph,
+			RootedTree, TreeGraph, VertexIn, VertexInGraph,
+		},
+		Directed, Ensure, Graph, Guard, Release, Undirected,
+	},
+};
+use static_assertions::assert_impl_all;
+use std::collections::HashSet;
+
+#[duplicate_item(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	type RootedMock = RootedGraph<TreeGraph<MockGraph<directedness>>>;
+
+	/// Roots the given tree in its first vertex, returning it with the second
+	/// vertex.
+	fn rooted(
+		g: VertexInGraph<TreeGraph<MockGraph<directedness>>, 2, false>,
+	) -> (RootedMock, MockVertex)
+	{
+		let [root, v] = [g.vertex_at::<0>(), g.vertex_at::<1>()];
+		(RootedGraph::ensure(g.release(), root).unwrap(), v)
+	}
+
+	/// Tests that only the root has no parent and that every other vertex is
+	/// adjacent to its parent, one level deeper.
+	#[quickcheck]
+	fn parent_and_depth(
+		Arb(g): Arb<VertexInGraph<TreeGraph<MockGraph<directedness>>, 2, false>>,
+	) -> bool
+	{
+		let (g, v) = rooted(g);
+		match g.parent(v)
+		{
+			None => v == g.root() && g.depth(v) == Some(0),
+			Some(p) =>
+			{
+				g.edges_incident_on(v).filter(|(n, _)| *n == p).count() == 1
+					&& g.depth(v) == g.depth(p).map(|d| d + 1)
+			},
+		}
+	}
+
+	/// Tests that the children of a vertex are exactly its neighbors it is
+	/// the parent of.
+	#[quickcheck]
+	fn children(Arb(g): Arb<VertexInGraph<TreeGraph<MockGraph<directedness>>, 2, false>>) -> bool
+	{
+		let (g, v) = rooted(g);
+		let children: HashSet<_> = g.children(v).collect();
+		let expected: HashSet<_> = g
+			.edges_incident_on(v)
+			.map(|(n, _)| n)
+			.filter(|n| g.parent(n) == Some(v))
+			.collect();
+		children == expected
+	}
+
+	/// Tests that the ancestors of a vertex are its chain of parents, ending
+	/// with the root.
+	#[quickcheck]
+	fn ancestors(Arb(g): Arb<VertexInGraph<TreeGraph<MockGraph<directedness>>, 2, false>>) -> bool
+	{
+		let (g, v) = rooted(g);
+		let ancestors: Vec<_> = g.ancestors(v).collect();
+		let mut expected = Vec::new();
+		let mut current = v;
+		while let Some(p) = g.parent(current)
+		{
+			expected.push(p);
+			current = p;
+		}
+		ancestors == expected && (v == g.root() || ancestors.last() == Some(&g.root()))
+	}
+
+	/// Tests that the subtree of a vertex contains exactly it and its
+	/// descendants, rooted in the vertex.
+	#[quickcheck]
+	fn subtree(Arb(g): Arb<VertexInGraph<TreeGraph<MockGraph<directedness>>, 3, false>>) -> bool
+	{
+		let u = g.vertex_at::<2>();
+		let v = g.vertex_at::<1>();
+		let root = g.vertex_at::<0>();
+		let g = RootedGraph::ensure(g.release(), root).unwrap();
+		let subtree = g.subtree(v).unwrap();
+		subtree.root() == v
+			&& subtree.contains_vertex(u) == (u == v || g.ancestors(u).any(|a| a == v))
+	}
+
+	/// Tests that each traversal visits every vertex once, in the right
+	/// order relative to the parent.
+	#[quickcheck]
+	fn traversals(Arb(g): Arb<VertexInGraph<TreeGraph<MockGraph<directedness>>, 2, false>>)
+		-> bool
+	{
+		let (g, v) = rooted(g);
+		let position = |order: &Vec<_>, v| order.iter().position(|u| *u == v).unwrap();
+		let pre: Vec<_> = g.pre_order().collect();
+		let post: Vec<_> = g.post_order().collect();
+		let level: Vec<_> = g.level_order().collect();
+		let vertices: Vec<_> = g.all_vertices().collect();
+
+		[&pre, &post, &level].iter().all(|order| {
+			order.len() == vertices.len() && vertices.iter().all(|v| order.contains(v))
+		}) && g.parent(v).is_none_or(|p| {
+			position(&pre, p) < position(&pre, v)
+				&& position(&post, v) < position(&post, p)
+				&& position(&level, p) < position(&level, v)
+		})
+	}
+
+	assert_impl_all!(RootedMock: RootedTree);
+}
+
+/// Tests the traversal orders of a small tree.
+#[test]
+fn small_tree()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let root = g.new_vertex().unwrap();
+	let tree = TreeGraph::guard(g).unwrap();
+	let mut tree = RootedGraph::ensure(tree, root).unwrap();
+
+	// root -> (a -> (c, d), b)
+	let a = tree.new_leaf(root).unwrap();
+	let b = tree.new_leaf(root).unwrap();
+	let c = tree.new_leaf(a).unwrap();
+	let d = tree.new_leaf(a).unwrap();
+
+	assert_eq!(tree.parent(c), Some(a));
+	assert_eq!(tree.depth(d), Some(2));
+	assert_eq!(tree.ancestors(d).collect::<Vec<_>>(), vec![a, root]);
+	assert_eq!(tree.subtree(a).unwrap().all_vertices().count(), 3);
+	assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![root, a, c, d, b]);
+	assert_eq!(
+		tree.post_order().collect::<Vec<_>>(),
+		vec![c, d, a, b, root]
+	);
+	assert_eq!(
+		tree.level_order().collect::<Vec<_>>(),
+		vec![root, a, b, c, d]
+	);
+
+	// Directed trees get the same parents regardless of edge direction
+	let mut g = AdjListGraph::<(), (), Directed>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	g.add_edge(v1, v0).unwrap();
+	let tree = TreeGraph::guard(g).unwrap();
+	let mut tree = RootedGraph::ensure(tree, v0).unwrap();
+	let v2 = NewLeafDirected::new_leaf(&mut tree, v1, true).unwrap();
+	assert!(tree.parent(v0).is_none());
+	assert_eq!(tree.parent(v1), Some(v0));
+	assert_eq!(tree.parent(v2), Some(v1));
+	assert_eq!(tree.children(v1).collect::<Vec<_>>(), vec![v2]);
+}
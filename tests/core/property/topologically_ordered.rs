@@ -0,0 +1,156 @@
+//! Tests the `core::property::TopologicallyOrdered` trait and its ensurer
+
+use crate::mock_graph::{
+	arbitrary::{Arb, CyclicGraph},
+	MockEdgeWeight, MockGraph,
+};
+use graphene::{
+	algo::path_exists,
+	common::{AdjListGraph, StableAdjListGraph},
+	core::{
+		property::{
+			Acyclic, AcyclicGraph, AddEdge, NewLeafDirected, NewLeafUndirected, NewVertex, NoLoops,
+			RemoveEdge, RemoveVertex, TopologicallyOrdered, TopologicallyOrderedGraph, TreeGraph,
+		},
+		Directed, Graph, Guard, Release,
+	},
+};
+use static_assertions::assert_impl_all;
+
+/// Returns whether the order of the graph contains each vertex once and puts
+/// the source of every edge before its sink.
+fn valid_order<G: TopologicallyOrdered>(g: &G) -> bool
+{
+	let order: Vec<_> = g.topological_order().collect();
+	order.len() == g.all_vertices().count()
+		&& order
+			.iter()
+			.enumerate()
+			.all(|(i, v)| g.order_of(v) == Some(i))
+		&& g.all_edges()
+			.all(|(so, si, _)| g.order_of(so) < g.order_of(si))
+}
+
+/// Tests that TopologicallyOrderedGraph accepts acyclic graphs and orders
+/// them.
+#[quickcheck]
+fn accept_acyclic(g: Arb<AcyclicGraph<MockGraph<Directed>>>) -> bool
+{
+	let g = g.0.release_all();
+	TopologicallyOrderedGraph::can_guard(&g)
+		&& valid_order(&TopologicallyOrderedGraph::guard(g).unwrap())
+}
+
+/// Tests that TopologicallyOrderedGraph rejects cyclic graphs.
+#[quickcheck]
+fn reject_cyclic(g: Arb<CyclicGraph<Directed, MockEdgeWeight>>) -> bool
+{
+	!TopologicallyOrderedGraph::can_guard(&g.0)
+}
+
+/// Tests that adding an edge is rejected only if it would result in a cycle,
+/// and that the order is kept valid otherwise.
+#[quickcheck]
+fn add_edge(
+	g: Arb<AcyclicGraph<MockGraph<Directed>>>,
+	idx1: usize,
+	idx2: usize,
+	weight: MockEdgeWeight,
+) -> bool
+{
+	let mut g = TopologicallyOrderedGraph::guard(g.0.release_all()).unwrap();
+	let vertices: Vec<_> = g.all_vertices().collect();
+	if vertices.is_empty()
+	{
+		return true;
+	}
+	let v1 = vertices[idx1 % vertices.len()];
+	let v2 = vertices[idx2 % vertices.len()];
+	let cyclic = v1 == v2 || path_exists(&g, v2, v1);
+	let edge_count = g.all_edges().count();
+
+	g.add_edge_weighted(v1, v2, weight).is_ok() != cyclic
+		&& g.all_edges().count() == edge_count + !cyclic as usize
+		&& valid_order(&g)
+}
+
+/// Tests that the order is updated as edges are added and vertices are added
+/// and removed.
+#[test]
+fn change_order()
+{
+	let mut g = TopologicallyOrderedGraph::guard(StableAdjListGraph::<(), ()>::new()).unwrap();
+	let vs: Vec<_> = (0..4).map(|_| g.new_vertex().unwrap()).collect();
+	assert!(g.topological_order().eq(vs.iter().copied()));
+
+	assert!(g.add_edge(vs[3], vs[0]).is_ok());
+	assert!(g.topological_order().eq([vs[3], vs[1], vs[2], vs[0]]));
+	assert!(g.add_edge(vs[2], vs[3]).is_ok());
+	assert!(g.topological_order().eq([vs[2], vs[1], vs[3], vs[0]]));
+	assert!(g.add_edge(vs[0], vs[2]).is_err());
+
+	assert!(g.remove_vertex(vs[1]).is_ok());
+	assert!(g.topological_order().eq([vs[2], vs[3], vs[0]]));
+	assert_eq!(g.order_of(vs[0]), Some(2));
+	assert_eq!(g.order_of(vs[1]), None);
+
+	let v = g.new_vertex().unwrap();
+	assert_eq!(g.order_of(v), Some(3));
+	assert!(valid_order(&g));
+}
+
+/// Tests that an edge in the opposite direction of a removed one can be added.
+#[test]
+fn remove_edge()
+{
+	let mut g = TopologicallyOrderedGraph::guard(AdjListGraph::<(), ()>::new()).unwrap();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge(v1, v2).unwrap();
+	assert!(g.add_edge(v2, v1).is_err());
+
+	g.remove_edge(v1, v2).unwrap();
+	assert!(g.add_edge(v2, v1).is_ok());
+	assert!(g.topological_order().eq([v2, v1]));
+}
+
+/// Tests that leaves added to a tree through the ensurer are put in the
+/// order.
+#[test]
+fn new_leaf()
+{
+	let mut g = StableAdjListGraph::<(), ()>::new();
+	let root = g.new_vertex().unwrap();
+	let mut g = TopologicallyOrderedGraph::guard(TreeGraph::guard(g).unwrap()).unwrap();
+
+	let child = NewLeafDirected::new_leaf(&mut g, root, true).unwrap();
+	let parent = NewLeafDirected::new_leaf(&mut g, root, false).unwrap();
+	let grandchild = NewLeafUndirected::new_leaf(&mut g, child).unwrap();
+	assert!(g.topological_order().eq([parent, root, child, grandchild]));
+	assert!(valid_order(&g));
+}
+
+/// Tests adding edges one at a time to a large graph.
+#[test]
+fn many_vertices()
+{
+	let mut g = TopologicallyOrderedGraph::guard(AdjListGraph::<(), ()>::new()).unwrap();
+	let vs: Vec<_> = (0..10_000).map(|_| g.new_vertex().unwrap()).collect();
+	for pair in vs.windows(2)
+	{
+		assert!(g.add_edge(pair[0], pair[1]).is_ok());
+	}
+
+	// Needs the whole graph to be reordered
+	let first = g.new_vertex().unwrap();
+	assert!(g.add_edge(first, vs[0]).is_ok());
+	assert_eq!(g.order_of(first), Some(0));
+	assert_eq!(g.order_of(vs[9_999]), Some(10_000));
+	assert!(g.add_edge(vs[9_999], first).is_err());
+	// Going through all edges of an AdjListGraph takes quadratic time
+	assert!(vs
+		.windows(2)
+		.all(|pair| g.order_of(pair[0]) < g.order_of(pair[1])));
+}
+
+assert_impl_all!(TopologicallyOrderedGraph<MockGraph<Directed>>: TopologicallyOrdered, Acyclic, NoLoops);
@@ -8,7 +8,7 @@ use graphene::core::{
 		Acyclic, AddEdge, HasVertex, NewVertex, RemoveEdge, RemoveVertex, Tree, TreeGraph, Unique,
 		VertexCount, VertexIn, VertexInGraph, Weak,
 	},
-	Directed, Graph, Guard, Release, Undirected,
+	Directed, Graph, Guard, Release, Undirected, Violation,
 };
 use static_assertions::{assert_impl_all, assert_not_impl_any};
 
@@ -34,6 +34,13 @@ mod __
 		!TreeGraph::can_guard(&g.0.release_all())
 	}
 
+	/// Tests that TreeGraph always says why it rejects non-trees
+	#[quickcheck]
+	fn report_non_tree(g: Arb<NonTreeGraph<directedness, MockEdgeWeight>>) -> bool
+	{
+		TreeGraph::guard(g.0.release_all()).is_err_and(|e| e.violation != Violation::Unspecified)
+	}
+
 	/// Tests can add leaf to existing parent
 	#[quickcheck]
 	fn new_leaf(
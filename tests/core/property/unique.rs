@@ -8,7 +8,7 @@ use graphene::core::{
 	property::{
 		AddEdge, HasVertex, NewVertex, Simple, SimpleGraph, Unique, UniqueGraph, VertexInGraph,
 	},
-	Directed, Graph, Guard, Release, Undirected,
+	Directed, EnsureError, Graph, GraphError, Guard, Release, Undirected, Violation,
 };
 use static_assertions::assert_impl_all;
 
@@ -41,6 +41,19 @@ mod __
 			!test_graph::can_guard(&g.0)
 		}
 
+		/// Tests that test_graph reports an edge that is duplicated, or a loop,
+		/// when rejecting.
+		#[quickcheck]
+		fn report_duplicate(g: Arb<NonUniqueGraph<directedness, edge_type>>) -> bool
+		{
+			match test_graph::guard(g.0.clone()).map_err(|e| e.violation)
+			{
+				Err(Violation::DuplicateEdge(so, si)) => g.0.edges_between(so, si).count() > 1,
+				Err(Violation::Loop(v)) => g.0.edges_between(v, v).count() > 0,
+				_ => false,
+			}
+		}
+
 		/// Tests that a test_graph accepts adding a non-duplicate edge
 		#[quickcheck]
 		fn accept_add_edge(
@@ -57,7 +70,8 @@ mod __
 			accepted && g.edges_between(v, &v2).count() == 1
 		}
 
-		/// Tests that a test_graph rejects adding a duplicate edge
+		/// Tests that a test_graph rejects adding a duplicate edge, reporting
+		/// the duplicated edge.
 		#[quickcheck]
 		fn reject_add_edge(
 			Arb(g): Arb<EdgeIn<test_graph<MockGraph<directedness, edge_type>>>>,
@@ -66,7 +80,14 @@ mod __
 		{
 			let source = g.any_vertex();
 			let EdgeIn(mut g, sink, _) = g;
-			g.add_edge_weighted(source, sink, weight).is_err()
+			match g.add_edge_weighted(source, sink, weight)
+			{
+				Err(GraphError::Rejected(EnsureError {
+					violation: Violation::DuplicateEdge(so, si),
+					..
+				})) => g.edges_between(so, si).count() == 1,
+				_ => false,
+			}
 		}
 	}
 }
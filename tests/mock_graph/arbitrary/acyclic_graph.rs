@@ -75,7 +75,8 @@ impl<D: Directedness> GuidedArbGraph for AcyclicGraph<MockGraph<D>>
 pub struct CyclicGraph<D: Directedness, Ew: MockType>(pub MockGraph<D, Ew>);
 
 impl_ensurer! {
-	use<D,Ew> CyclicGraph<D,Ew>: Acyclic, Tree, NewLeafUndirected, NewLeafDirected,
+	use<D,Ew> CyclicGraph<D,Ew>: Acyclic, Tree, Forest, TopologicallyOrdered, NewLeafUndirected,
+	NewLeafDirected, RootedTree,
 	// Can never impl the following because MockGraph doesn't
 	Reflexive
 	as (self.0) : MockGraph<D,Ew>
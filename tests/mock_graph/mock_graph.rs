@@ -3,7 +3,7 @@ use graphene::{
 	base_graph,
 	core::{
 		property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
-		Directedness, Edge, Graph, GraphMut,
+		Directedness, Edge, Graph, GraphError, GraphMut,
 	},
 };
 use std::{
@@ -254,7 +254,10 @@ impl<D: Directedness, Ew: MockType> GraphMut for MockGraph<D, Ew>
 
 impl<D: Directedness, Ew: MockType> NewVertex for MockGraph<D, Ew>
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		if self.vertices.insert(self.next_id, w).is_some()
 		{
@@ -272,8 +275,10 @@ impl<D: Directedness, Ew: MockType> NewVertex for MockGraph<D, Ew>
 }
 impl<D: Directedness, Ew: MockType> RemoveVertex for MockGraph<D, Ew>
 {
-	fn remove_vertex(&mut self, mock_v: impl Borrow<Self::Vertex>)
-		-> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		mock_v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		let v = mock_v.borrow().value;
 		if let Some(weight) = self.vertices.remove(&v)
@@ -284,7 +289,7 @@ impl<D: Directedness, Ew: MockType> RemoveVertex for MockGraph<D, Ew>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingVertex(*mock_v.borrow()))
 		}
 	}
 }
@@ -296,20 +301,19 @@ impl<D: Directedness, Ew: MockType> AddEdge for MockGraph<D, Ew>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
-		let source = source.borrow().value;
-		let sink = sink.borrow().value;
-		if self.vertices.contains_key(&source) && self.vertices.contains_key(&sink)
-		{
-			self.edges.push((source, sink, weight));
-			self.validate_is_graph();
-			Ok(())
-		}
-		else
+		for v in [source.borrow(), sink.borrow()]
 		{
-			Err(())
+			if !self.vertices.contains_key(&v.value)
+			{
+				return Err(GraphError::MissingVertex(*v));
+			}
 		}
+		self.edges
+			.push((source.borrow().value, sink.borrow().value, weight));
+		self.validate_is_graph();
+		Ok(())
 	}
 }
 
@@ -320,7 +324,7 @@ impl<D: Directedness, Ew: MockType> RemoveEdge for MockGraph<D, Ew>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -338,7 +342,7 @@ impl<D: Directedness, Ew: MockType> RemoveEdge for MockGraph<D, Ew>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::MissingEdge(*source.borrow(), *sink.borrow()))
 		}
 	}
 }
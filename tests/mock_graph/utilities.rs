@@ -1,6 +1,6 @@
 use crate::mock_graph::{MockEdgeWeight, MockVertex, MockVertexWeight};
 use graphene::core::{property::AddEdge, Directedness, Graph};
-use std::{borrow::Borrow, collections::HashMap};
+use std::{borrow::Borrow, collections::HashMap, fmt::Debug};
 #[macro_export]
 macro_rules! holds_if{
 	{
@@ -100,6 +100,7 @@ pub fn unordered_equivalent_lists_equal<L: PartialEq<R>, R: PartialEq<L>>(
 pub fn auto_copy_from<G, M, F>(g: &mut G, mock: &M, new_vertex: F) -> HashMap<MockVertex, G::Vertex>
 where
 	G: Graph<VertexWeight = MockVertexWeight, EdgeWeight = MockEdgeWeight> + AddEdge,
+	G::Vertex: Debug,
 	M: Graph<
 		Vertex = MockVertex,
 		Directedness = G::Directedness,